@@ -53,35 +53,9 @@ func _on_add_singleton():
 
 
 func _create_bevy_app_singleton(path: String):
-	# Create the scene file content with bulk transform optimization methods
-	var scene_content = """[gd_scene load_steps=2 format=3 uid="uid://bjsfwt816j4tp"]
-
-[sub_resource type="GDScript" id="GDScript_1"]
-script/source = "extends BevyApp
-
-
-# Bulk Transform Optimization Methods
-# Automatically detected by godot-bevy library for performance optimization
-
-func bulk_update_transforms_3d(instance_ids: PackedInt64Array, positions: PackedVector3Array, rotations: PackedVector3Array, scales: PackedVector3Array):
-	for i in range(instance_ids.size()):
-		var node = instance_from_id(instance_ids[i]) as Node3D
-		# Trust instance IDs are valid
-		node.position = positions[i]
-		node.rotation = rotations[i]
-		node.scale = scales[i]
-
-func bulk_update_transforms_2d(instance_ids: PackedInt64Array, positions: PackedVector2Array, rotations: PackedFloat32Array, scales: PackedVector2Array):
-	for i in range(instance_ids.size()):
-		var node = instance_from_id(instance_ids[i]) as Node2D
-		# Trust instance IDs are valid
-		node.position = positions[i]
-		node.rotation = rotations[i]
-		node.scale = scales[i]
-"
+	var scene_content = """[gd_scene format=3 uid="uid://bjsfwt816j4tp"]
 
 [node name="BevyApp" type="BevyApp"]
-script = SubResource("GDScript_1")
 """
 
 	# Save the scene file directly
@@ -2,7 +2,6 @@ use bevy::{
     app::{App, Plugin, Update},
     ecs::{
         entity::Entity,
-        event::EventWriter,
         resource::Resource,
         schedule::IntoScheduleConfigs,
         system::{Commands, Query, Res, ResMut},
@@ -13,12 +12,9 @@ use bevy::{
     },
     time::{Time, Timer, TimerMode},
 };
-use godot_bevy::prelude::Groups;
+use godot_bevy::prelude::{GodotCommands, Groups};
 
-use crate::{
-    GameState,
-    commands::{NodeCommand, UICommand},
-};
+use crate::{GameState, main_menu::MenuAssets};
 
 pub struct CountdownPlugin;
 impl Plugin for CountdownPlugin {
@@ -37,34 +33,32 @@ impl Plugin for CountdownPlugin {
 #[derive(Resource)]
 pub struct CountdownTimer(Timer);
 
-fn setup_countdown(mut commands: Commands, mut ui_commands: EventWriter<UICommand>) {
+fn setup_countdown(mut commands: Commands, menu_assets: Res<MenuAssets>, mut godot: GodotCommands) {
     commands.insert_resource(CountdownTimer(Timer::from_seconds(1.0, TimerMode::Once)));
 
-    ui_commands.write(UICommand::ShowMessage {
-        text: "Get Ready".to_string(),
-    });
+    menu_assets.show_message(&mut godot, "Get Ready");
 }
 
 fn update_countdown(
     mut timer: ResMut<CountdownTimer>,
     time: Res<Time>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut ui_commands: EventWriter<UICommand>,
+    menu_assets: Res<MenuAssets>,
+    mut godot: GodotCommands,
 ) {
     timer.0.tick(time.delta());
     if timer.0.just_finished() {
         next_state.set(GameState::InGame);
 
-        ui_commands.write(UICommand::ShowMessage {
-            text: "".to_string(),
-        });
+        menu_assets.show_message(&mut godot, "");
     }
 }
 
-fn kill_all_mobs(entities: Query<(Entity, &Groups)>, mut node_commands: EventWriter<NodeCommand>) {
+fn kill_all_mobs(mut commands: Commands, entities: Query<(Entity, &Groups)>) {
     for (entity, group) in entities.iter() {
         if group.is("mobs") {
-            node_commands.write(NodeCommand::Destroy { entity });
+            // Mobs are spawned with `NodeDespawnPolicy::FreeNode`, so this frees their nodes too
+            commands.entity(entity).despawn();
         }
     }
 }
@@ -1,7 +1,6 @@
 use bevy::{
     app::{Plugin, Update},
     ecs::{
-        event::EventWriter,
         resource::Resource,
         schedule::IntoScheduleConfigs,
         system::{Commands, Res, ResMut},
@@ -13,7 +12,9 @@ use bevy::{
     time::{Time, Timer, TimerMode},
 };
 
-use crate::{GameState, commands::UICommand};
+use godot_bevy::prelude::GodotCommands;
+
+use crate::{GameState, main_menu::MenuAssets};
 
 pub struct GameoverPlugin;
 impl Plugin for GameoverPlugin {
@@ -29,19 +30,18 @@ impl Plugin for GameoverPlugin {
 #[derive(Resource)]
 pub struct GameoverTimer(Timer);
 
-fn setup_gameover(mut commands: Commands, mut ui_commands: EventWriter<UICommand>) {
+fn setup_gameover(mut commands: Commands, menu_assets: Res<MenuAssets>, mut godot: GodotCommands) {
     commands.insert_resource(GameoverTimer(Timer::from_seconds(2.0, TimerMode::Once)));
 
-    ui_commands.write(UICommand::ShowMessage {
-        text: "Game Over".to_string(),
-    });
+    menu_assets.show_message(&mut godot, "Game Over");
 }
 
 fn update_gameover_timer(
     mut timer: ResMut<GameoverTimer>,
     time: Res<Time>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut ui_commands: EventWriter<UICommand>,
+    menu_assets: Res<MenuAssets>,
+    mut godot: GodotCommands,
 ) {
     timer.0.tick(time.delta());
     if !timer.0.just_finished() {
@@ -50,7 +50,5 @@ fn update_gameover_timer(
 
     next_state.set(GameState::MainMenu);
 
-    ui_commands.write(UICommand::ShowMessage {
-        text: "Dodge the Creeps".to_string(),
-    });
+    menu_assets.show_message(&mut godot, "Dodge the Creeps");
 }
@@ -1,8 +1,5 @@
 use crate::gameplay::audio::GameSfxChannel;
-use crate::{
-    GameState,
-    commands::{AnimationState, NodeCommand},
-};
+use crate::{GameState, commands::AnimationState};
 use bevy::math::{Vec3Swizzles, vec3};
 use bevy::transform::components::Transform;
 use bevy::{
@@ -10,7 +7,7 @@ use bevy::{
     asset::Handle,
     ecs::{
         component::Component,
-        event::{EventReader, EventWriter},
+        event::EventReader,
         name::Name,
         query::Added,
         resource::Resource,
@@ -30,7 +27,7 @@ use godot_bevy::{
     interop::GodotNodeHandle,
     prelude::{
         AudioChannel, FindEntityByNameExt, GodotResource, GodotScene, GodotSignal, GodotSignals,
-        NodeTreeView, main_thread_system,
+        NodeDespawnPolicy, NodeTreeView, main_thread_system,
     },
 };
 use std::f32::consts::PI;
@@ -104,6 +101,7 @@ fn spawn_mob(
         .insert(Mob { direction })
         .insert(transform)
         .insert(GodotScene::from_handle(assets.mob_scn.clone()))
+        .insert(NodeDespawnPolicy::FreeNode)
         .insert(AnimationState::default());
 }
 
@@ -162,7 +160,7 @@ fn new_mob(
 }
 
 #[main_thread_system]
-fn kill_mob(mut signals: EventReader<GodotSignal>, _node_commands: EventWriter<NodeCommand>) {
+fn kill_mob(mut signals: EventReader<GodotSignal>) {
     for signal in signals.read() {
         if signal.name == "screen_exited" {
             // Get the parent node and queue it for destruction via command
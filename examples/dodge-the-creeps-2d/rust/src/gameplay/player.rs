@@ -1,6 +1,6 @@
 use crate::{
     GameState,
-    commands::{AnimationState, CachedScreenSize, VisibilityState},
+    commands::{AnimationState, CachedScreenSize},
     nodes::player::Player as GodotPlayerNode,
 };
 use bevy::prelude::{
@@ -78,10 +78,7 @@ fn player_on_ready(
         commands
             .entity(entity)
             .insert(PlayerInitialized)
-            .insert(VisibilityState {
-                visible: false,
-                dirty: true,
-            })
+            .insert(GodotVisibility::Hidden)
             .insert(AnimationState::default())
             .insert(CachedScreenSize { size: screen_size });
     }
@@ -91,12 +88,12 @@ fn player_on_ready(
 
 #[main_thread_system]
 fn setup_player(
-    mut player: Query<(Entity, &mut VisibilityState, &mut Transform), With<Player>>,
+    mut player: Query<(Entity, &mut GodotVisibility, &mut Transform), With<Player>>,
     mut entities: Query<(&Name, &mut GodotNodeHandle), Without<Player>>,
 ) -> Result {
     if let Ok((_entity, mut visibility, mut transform)) = player.single_mut() {
-        // Set player visible using command system
-        visibility.set_visible(true);
+        // Show the player, written to the node by GodotVisibilitySyncPlugin
+        *visibility = GodotVisibility::Inherited;
 
         // Still need main thread access for getting start position
         let start_position = entities
@@ -169,7 +166,7 @@ fn move_player(
 
 #[main_thread_system]
 fn check_player_death(
-    mut player: Query<(&mut VisibilityState, &Collisions), With<Player>>,
+    mut player: Query<(&mut GodotVisibility, &Collisions), With<Player>>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     if let Ok((mut visibility, collisions)) = player.single_mut() {
@@ -177,7 +174,7 @@ fn check_player_death(
             return;
         }
 
-        visibility.set_visible(false);
+        *visibility = GodotVisibility::Hidden;
         next_state.set(GameState::GameOver);
     }
 }
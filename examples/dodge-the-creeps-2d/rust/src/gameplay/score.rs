@@ -2,7 +2,6 @@ use bevy::{
     app::{App, Plugin, Update},
     ecs::{
         change_detection::DetectChanges,
-        event::EventWriter,
         resource::Resource,
         schedule::IntoScheduleConfigs,
         system::{Res, ResMut},
@@ -11,10 +10,9 @@ use bevy::{
     time::{Time, Timer, TimerMode},
 };
 
-use crate::{
-    GameState, Score,
-    commands::{UICommand, UIElement},
-};
+use godot_bevy::prelude::GodotCommands;
+
+use crate::{GameState, Score, main_menu::MenuAssets};
 
 pub struct ScorePlugin;
 impl Plugin for ScorePlugin {
@@ -33,12 +31,11 @@ fn reset_score(mut score: ResMut<Score>) {
     score.0 = 0;
 }
 
-fn update_score_counter(score: Res<Score>, mut ui_commands: EventWriter<UICommand>) {
-    if score.is_changed() {
-        ui_commands.write(UICommand::SetText {
-            target: UIElement::ScoreLabel,
-            text: score.0.to_string(),
-        });
+fn update_score_counter(score: Res<Score>, menu_assets: Res<MenuAssets>, mut godot: GodotCommands) {
+    if score.is_changed()
+        && let Some(label) = &menu_assets.score_label
+    {
+        godot.node(label).set("text", score.0.to_string());
     }
 }
 
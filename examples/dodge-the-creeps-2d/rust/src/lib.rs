@@ -22,6 +22,7 @@ fn build_app(app: &mut App) {
 
     // This example uses most godot-bevy features, so we'll use the convenience bundle
     app.add_plugins(GodotDefaultPlugins)
+        .add_plugins(GodotVisibilitySyncPlugin::default())
         .add_plugins(StatesPlugin)
         .init_state::<GameState>()
         .add_loading_state(
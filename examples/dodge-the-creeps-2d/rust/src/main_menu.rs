@@ -1,10 +1,10 @@
 use bevy::{
     app::{App, Plugin, Update},
     ecs::{
-        event::{EventReader, EventWriter},
+        event::EventReader,
         resource::Resource,
         schedule::IntoScheduleConfigs,
-        system::ResMut,
+        system::{Res, ResMut},
     },
     state::{
         condition::in_state,
@@ -13,13 +13,12 @@ use bevy::{
 };
 use godot_bevy::{
     interop::GodotNodeHandle,
-    prelude::{GodotSignal, GodotSignals, NodeTreeView, SceneTreeRef, main_thread_system},
+    prelude::{
+        GodotCommands, GodotSignal, GodotSignals, NodeTreeView, SceneTreeRef, main_thread_system,
+    },
 };
 
-use crate::{
-    GameState,
-    commands::{UICommand, UIElement, UIHandles},
-};
+use crate::GameState;
 
 #[derive(Resource, Default)]
 pub struct MenuAssets {
@@ -59,22 +58,22 @@ pub struct MenuUi {
     pub score_label: GodotNodeHandle,
 }
 
+impl MenuAssets {
+    /// Queue showing `text` in the message label
+    pub fn show_message(&self, godot: &mut GodotCommands, text: impl Into<String>) {
+        if let Some(label) = &self.message_label {
+            godot.node(label).set("text", text.into());
+        }
+    }
+}
+
 #[main_thread_system]
-fn init_menu_assets(
-    mut menu_assets: ResMut<MenuAssets>,
-    mut ui_handles: ResMut<UIHandles>,
-    mut scene_tree: SceneTreeRef,
-) {
+fn init_menu_assets(mut menu_assets: ResMut<MenuAssets>, mut scene_tree: SceneTreeRef) {
     let menu_ui = MenuUi::from_node(scene_tree.get().get_root().unwrap());
 
     menu_assets.message_label = Some(menu_ui.message_label.clone());
     menu_assets.start_button = Some(menu_ui.start_button.clone());
     menu_assets.score_label = Some(menu_ui.score_label.clone());
-
-    // Initialize UI handles for command system
-    ui_handles.start_button = Some(menu_ui.start_button.clone());
-    ui_handles.score_label = Some(menu_ui.score_label.clone());
-    ui_handles.message_label = Some(menu_ui.message_label.clone());
 }
 
 fn connect_start_button(mut menu_assets: ResMut<MenuAssets>, signals: GodotSignals) {
@@ -92,16 +91,14 @@ fn listen_for_start_button(
     }
 }
 
-fn hide_play_button(mut ui_commands: EventWriter<UICommand>) {
-    ui_commands.write(UICommand::SetVisible {
-        target: UIElement::StartButton,
-        visible: false,
-    });
+fn hide_play_button(menu_assets: Res<MenuAssets>, mut godot: GodotCommands) {
+    if let Some(button) = &menu_assets.start_button {
+        godot.node(button).set("visible", false);
+    }
 }
 
-fn show_play_button(mut ui_commands: EventWriter<UICommand>) {
-    ui_commands.write(UICommand::SetVisible {
-        target: UIElement::StartButton,
-        visible: true,
-    });
+fn show_play_button(menu_assets: Res<MenuAssets>, mut godot: GodotCommands) {
+    if let Some(button) = &menu_assets.start_button {
+        godot.node(button).set("visible", true);
+    }
 }
@@ -10,7 +10,7 @@ use godot::global::godot_print;
 use godot_bevy::prelude::godot_prelude::ExtensionLibrary;
 use godot_bevy::prelude::godot_prelude::gdextension;
 use godot_bevy::prelude::{
-    GodotNodeHandle, GodotTransformSyncPlugin, Sprite2DMarker, bevy_app, main_thread_system,
+    GodotTransformSyncPlugin, TypedNodeHandle, bevy_app, main_thread_system,
 };
 use std::f32::consts::PI;
 
@@ -73,16 +73,13 @@ fn orbit_setup(
     // Bevy Commands allow us to modify the state of the world, such as adding components to entities.
     mut commands: Commands,
 
-    // Gather all Godot nodes without the `NodeInitialized` component.
+    // Gather all Sprite2D nodes without the `NodeInitialized` component.
     // Also, include the Bevy entity identifier so we can add components to it.
-    mut uninitialized: Query<
-        (Entity, &mut GodotNodeHandle, &Sprite2DMarker),
-        Without<NodeInitialized>,
-    >,
+    uninitialized: Query<(Entity, &TypedNodeHandle<Sprite2D>), Without<NodeInitialized>>,
 ) {
-    for (entity, mut node_handle, _) in uninitialized.iter_mut() {
-        let sprite_node = node_handle.get::<Sprite2D>();
-        // The GodotNodeHandle allows us to call Godot methods such as `get_name()`.
+    for (entity, node_handle) in uninitialized.iter() {
+        let sprite_node = node_handle.get();
+        // The TypedNodeHandle allows us to call Godot methods such as `get_name()`.
         godot_print!(
             "Initializing node: {:?}",
             sprite_node.get_name().to_string()
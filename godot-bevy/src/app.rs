@@ -2,6 +2,7 @@ use crate::plugins::core::PrePhysicsUpdate;
 use crate::watchers::collision_watcher::CollisionWatcher;
 use crate::watchers::input_watcher::GodotInputWatcher;
 use crate::watchers::scene_tree_watcher::SceneTreeWatcher;
+use crate::watchers::transform_watcher::TransformWatcher;
 use crate::{
     GodotPlugin,
     plugins::{
@@ -10,6 +11,7 @@ use crate::{
         input::InputEventReader,
         scene_tree::SceneTreeEventReader,
         signals::{GodotSignalReader, GodotSignalSender},
+        transforms::sync_systems::{TransformChangedReader, TransformChangedSender},
     },
 };
 use bevy::app::App;
@@ -70,6 +72,16 @@ impl BevyApp {
         self.base_mut().add_child(&collision_watcher);
         app.insert_non_send_resource(CollisionEventReader(receiver));
     }
+
+    fn register_transform_watcher(&mut self, app: &mut App) {
+        let (sender, receiver) = channel();
+        let mut transform_watcher = TransformWatcher::new_alloc();
+        transform_watcher.bind_mut().notification_channel = Some(sender.clone());
+        transform_watcher.set_name("TransformWatcher");
+        self.base_mut().add_child(&transform_watcher);
+        app.insert_non_send_resource(TransformChangedSender(sender));
+        app.insert_non_send_resource(TransformChangedReader(receiver));
+    }
 }
 
 #[godot_api]
@@ -97,6 +109,7 @@ impl INode for BevyApp {
         self.register_signal_system(&mut app);
         self.register_input_event_watcher(&mut app);
         self.register_collision_watcher(&mut app);
+        self.register_transform_watcher(&mut app);
         app.init_resource::<PhysicsDelta>();
         self.app = Some(app);
     }
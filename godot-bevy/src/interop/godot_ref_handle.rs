@@ -0,0 +1,345 @@
+use bevy::ecs::{component::Component, resource::Resource};
+use godot::{
+    classes::RefCounted,
+    init::is_main_thread,
+    obj::{Gd, Inherits, InstanceId},
+};
+use std::{marker::PhantomData, sync::Mutex};
+
+use super::utils::{maybe_dec_ref, maybe_inc_ref};
+use crate::prelude::main_thread_system;
+
+/// References taken by handles cloned, and given up by handles dropped, outside the main thread,
+/// in the order they happened. Applied by [`apply_pending_ref_changes`] at the end of the frame,
+/// or before a handle changes its reference on the main thread
+static PENDING_REF_CHANGES: Mutex<Vec<(InstanceId, RefChange)>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RefChange {
+    Take,
+    Release,
+}
+
+/// A handle to a reference-counted Godot object (`Tween`, `Image`, `Curve`, a `RefCounted`
+/// script object...) that can be stored in components and resources.
+///
+/// The handle holds a reference to the object, so it stays alive for as long as the handle (or one
+/// of its clones) exists and is freed once the last reference is gone.
+/// [`GodotResourceHandle`](super::GodotResourceHandle) is the handle for Godot resources.
+///
+/// The handle itself is `Send` and `Sync`, but the object can only be accessed from the main
+/// thread: [`GodotRefHandle::get`] panics anywhere else. Handles cloned or dropped on another
+/// thread take or give back their reference on the main thread at the end of the frame.
+///
+/// ```ignore
+/// #[derive(Component)]
+/// struct Fade(GodotRefHandle<Tween>);
+///
+/// #[main_thread_system]
+/// fn start_fade(mut commands: Commands, sprites: Query<(Entity, &TypedNodeHandle<Sprite2D>)>) {
+///     for (entity, sprite) in sprites.iter() {
+///         let mut tween = sprite.get().create_tween().unwrap();
+///         tween.tween_property(&sprite.get(), "modulate:a", &0.0.to_variant(), 1.0);
+///         commands.entity(entity).insert(Fade(GodotRefHandle::new(tween)));
+///     }
+/// }
+/// ```
+#[derive(Component, Resource)]
+pub struct GodotRefHandle<T: Inherits<RefCounted>> {
+    instance_id: InstanceId,
+    class: PhantomData<fn() -> T>,
+}
+
+impl<T: Inherits<RefCounted>> GodotRefHandle<T> {
+    /// Create a new handle, taking a reference to the object
+    pub fn new(mut reference: Gd<T>) -> Self {
+        maybe_inc_ref(&mut reference);
+
+        Self {
+            instance_id: reference.instance_id(),
+            class: PhantomData,
+        }
+    }
+
+    /// Get the object.
+    ///
+    /// Panics when called outside the main thread.
+    pub fn get(&self) -> Gd<T> {
+        assert!(
+            is_main_thread(),
+            "GodotRefHandle<{}> accessed outside the main thread",
+            std::any::type_name::<T>()
+        );
+
+        Gd::from_instance_id(self.instance_id)
+    }
+
+    /// Get the object, or `None` when called outside the main thread
+    pub fn try_get(&self) -> Option<Gd<T>> {
+        if !is_main_thread() {
+            return None;
+        }
+
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
+
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+impl<T: Inherits<RefCounted>> From<Gd<T>> for GodotRefHandle<T> {
+    fn from(reference: Gd<T>) -> Self {
+        Self::new(reference)
+    }
+}
+
+impl<T: Inherits<RefCounted>> Clone for GodotRefHandle<T> {
+    fn clone(&self) -> Self {
+        change_reference(
+            self.instance_id,
+            RefChange::Take,
+            is_main_thread(),
+            apply_ref_change,
+        );
+
+        Self {
+            instance_id: self.instance_id,
+            class: PhantomData,
+        }
+    }
+}
+
+impl<T: Inherits<RefCounted>> Drop for GodotRefHandle<T> {
+    fn drop(&mut self) {
+        change_reference(
+            self.instance_id,
+            RefChange::Release,
+            is_main_thread(),
+            apply_ref_change,
+        );
+    }
+}
+
+impl<T: Inherits<RefCounted>> PartialEq for GodotRefHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: Inherits<RefCounted>> Eq for GodotRefHandle<T> {}
+
+impl<T: Inherits<RefCounted>> std::fmt::Debug for GodotRefHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GodotRefHandle")
+            .field("class", &T::class_name())
+            .field("instance_id", &self.instance_id)
+            .finish()
+    }
+}
+
+/// Takes or gives back a reference for a handle with `apply`, or queues the change when not on the
+/// main thread
+fn change_reference(
+    instance_id: InstanceId,
+    change: RefChange,
+    on_main_thread: bool,
+    mut apply: impl FnMut(InstanceId, RefChange),
+) {
+    if !on_main_thread {
+        PENDING_REF_CHANGES
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .push((instance_id, change));
+        return;
+    }
+
+    // A clone made on another thread may be waiting to take its reference, which has to happen
+    // before this one is given back
+    flush_pending_ref_changes(&mut apply);
+    apply(instance_id, change);
+}
+
+fn apply_ref_change(instance_id: InstanceId, change: RefChange) {
+    // The handle's own reference keeps the object alive, so the lookup only fails if it was freed
+    // some other way. `object` holds a reference of its own while the handle's is given back, and
+    // frees the object when it is dropped if no other references are left
+    let Ok(mut object) = Gd::<RefCounted>::try_from_instance_id(instance_id) else {
+        return;
+    };
+    match change {
+        RefChange::Take => maybe_inc_ref(&mut object),
+        RefChange::Release => {
+            maybe_dec_ref(&mut object);
+        }
+    }
+}
+
+fn take_pending_ref_changes() -> Vec<(InstanceId, RefChange)> {
+    std::mem::take(
+        &mut *PENDING_REF_CHANGES
+            .lock()
+            .unwrap_or_else(|error| error.into_inner()),
+    )
+}
+
+fn flush_pending_ref_changes(mut apply: impl FnMut(InstanceId, RefChange)) {
+    for (instance_id, change) in take_pending_ref_changes() {
+        apply(instance_id, change);
+    }
+}
+
+/// Applies the reference changes of handles that were cloned or dropped outside the main thread
+#[main_thread_system]
+pub(crate) fn apply_pending_ref_changes() {
+    flush_pending_ref_changes(apply_ref_change);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::core::GodotBaseCorePlugin;
+    use bevy::app::{App, Last};
+    use std::collections::HashMap;
+
+    /// The queue is shared by every test
+    static QUEUE_LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock_queue() -> std::sync::MutexGuard<'static, ()> {
+        let guard = QUEUE_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+        take_pending_ref_changes();
+        guard
+    }
+
+    /// Queues `change` like a handle cloned or dropped outside the main thread. Godot isn't
+    /// touched, so `apply` is never called
+    fn change_off_main_thread(instance_id: InstanceId, change: RefChange) {
+        change_reference(instance_id, change, false, |_, _| {
+            unreachable!("applied outside the main thread")
+        });
+    }
+
+    /// Applies changes to `counts`, standing in for the objects' reference counts
+    fn count_into(counts: &mut HashMap<InstanceId, i64>) -> impl FnMut(InstanceId, RefChange) + '_ {
+        |instance_id, change| {
+            *counts.entry(instance_id).or_default() += match change {
+                RefChange::Take => 1,
+                RefChange::Release => -1,
+            };
+        }
+    }
+
+    #[test]
+    fn test_ref_changes_off_main_thread_are_queued_in_order() {
+        let _queue = lock_queue();
+        let instance_id = InstanceId::from_i64(42);
+
+        change_off_main_thread(instance_id, RefChange::Take);
+        change_off_main_thread(instance_id, RefChange::Release);
+
+        assert_eq!(
+            take_pending_ref_changes(),
+            vec![
+                (instance_id, RefChange::Take),
+                (instance_id, RefChange::Release)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ref_changes_from_several_threads_are_all_queued() {
+        let _queue = lock_queue();
+        let instance_ids: Vec<_> = (1..=4).map(InstanceId::from_i64).collect();
+
+        // Each thread clones a handle twice and drops both clones
+        let threads: Vec<_> = instance_ids
+            .iter()
+            .map(|&instance_id| {
+                std::thread::spawn(move || {
+                    change_off_main_thread(instance_id, RefChange::Take);
+                    change_off_main_thread(instance_id, RefChange::Take);
+                    change_off_main_thread(instance_id, RefChange::Release);
+                    change_off_main_thread(instance_id, RefChange::Release);
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let pending = take_pending_ref_changes();
+        assert_eq!(pending.len(), 16);
+        for instance_id in instance_ids {
+            let changes: Vec<_> = pending
+                .iter()
+                .filter(|(id, _)| *id == instance_id)
+                .map(|(_, change)| *change)
+                .collect();
+            assert_eq!(
+                changes,
+                [
+                    RefChange::Take,
+                    RefChange::Take,
+                    RefChange::Release,
+                    RefChange::Release
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_flush_applies_queued_changes_once() {
+        let _queue = lock_queue();
+        let instance_id = InstanceId::from_i64(7);
+
+        // The reference taken by `GodotRefHandle::new`, then a clone made on another thread and
+        // both handles dropped there
+        let mut counts = HashMap::from([(instance_id, 1)]);
+        change_off_main_thread(instance_id, RefChange::Take);
+        change_off_main_thread(instance_id, RefChange::Release);
+        change_off_main_thread(instance_id, RefChange::Release);
+
+        flush_pending_ref_changes(count_into(&mut counts));
+        // Every reference is given back, so Godot frees the object
+        assert_eq!(counts[&instance_id], 0);
+
+        flush_pending_ref_changes(|_, _| panic!("applied twice"));
+        assert!(take_pending_ref_changes().is_empty());
+    }
+
+    #[test]
+    fn test_main_thread_changes_apply_queued_changes_first() {
+        let _queue = lock_queue();
+        let instance_id = InstanceId::from_i64(9);
+
+        // Cloned on another thread, then the original is dropped on the main thread: the clone's
+        // reference must be taken before the original's is given back, or the object is freed
+        let mut applied = Vec::new();
+        change_off_main_thread(instance_id, RefChange::Take);
+        change_reference(instance_id, RefChange::Release, true, |id, change| {
+            applied.push((id, change))
+        });
+
+        assert_eq!(
+            applied,
+            [
+                (instance_id, RefChange::Take),
+                (instance_id, RefChange::Release)
+            ]
+        );
+        assert!(take_pending_ref_changes().is_empty());
+    }
+
+    #[test]
+    fn test_pending_changes_are_applied_in_last() {
+        let mut app = App::new();
+        app.add_plugins(GodotBaseCorePlugin);
+
+        let last = app.get_schedule(Last).expect("the Last schedule");
+        assert!(
+            last.graph()
+                .systems()
+                .any(|(_, system, _)| system.name().contains("apply_pending_ref_changes"))
+        );
+    }
+}
@@ -1,10 +1,17 @@
 pub mod godot_node_handle;
 pub use godot_node_handle::*;
 
+pub mod godot_ref_handle;
+pub use godot_ref_handle::GodotRefHandle;
+pub(crate) use godot_ref_handle::apply_pending_ref_changes;
+
 pub mod godot_resource_handle;
 pub use godot_resource_handle::*;
 
 pub mod node_markers;
 pub use node_markers::*;
 
+pub mod typed_node_handle;
+pub use typed_node_handle::*;
+
 mod utils;
@@ -0,0 +1,129 @@
+use bevy::ecs::component::Component;
+use godot::{
+    classes::Node,
+    obj::{Gd, Inherits, InstanceId},
+};
+use std::marker::PhantomData;
+
+use super::GodotNodeHandle;
+
+/// A [`GodotNodeHandle`] to a node that is known to be a `T`.
+///
+/// Mirrored nodes get a `TypedNodeHandle` for their class and each of its base classes, next to
+/// the matching marker components, so systems can query the class they need directly:
+///
+/// ```ignore
+/// fn move_players(players: Query<&TypedNodeHandle<CharacterBody2D>, With<Player>>) {
+///     for player in players.iter() {
+///         player.get().move_and_slide();
+///     }
+/// }
+/// ```
+///
+/// Nodes of GDExtension classes get the handles of their engine base classes.
+#[derive(Component)]
+pub struct TypedNodeHandle<T: Inherits<Node>> {
+    instance_id: InstanceId,
+    class: PhantomData<fn() -> T>,
+}
+
+impl<T: Inherits<Node>> TypedNodeHandle<T> {
+    /// # SAFETY
+    /// When using TypedNodeHandle as a Bevy Resource or Component, do not create duplicate
+    /// references to the same instance because Godot is not completely thread-safe.
+    pub fn new(reference: Gd<T>) -> Self {
+        Self::new_unchecked(reference.instance_id())
+    }
+
+    /// The caller must make sure `instance_id` belongs to a node of class `T`
+    pub(crate) fn new_unchecked(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id,
+            class: PhantomData,
+        }
+    }
+
+    /// Returns the node.
+    ///
+    /// Panics if the node has been freed, use [`TypedNodeHandle::try_get`] or
+    /// [`TypedNodeHandle::is_valid`] for nodes that may be gone.
+    pub fn get(&self) -> Gd<T> {
+        self.try_get().unwrap_or_else(|| {
+            panic!(
+                "node {} ({}) has been freed",
+                self.instance_id,
+                std::any::type_name::<T>()
+            )
+        })
+    }
+
+    /// Returns the node, or `None` if it has been freed
+    pub fn try_get(&self) -> Option<Gd<T>> {
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
+
+    /// Returns true if the node still exists
+    pub fn is_valid(&self) -> bool {
+        self.instance_id.lookup_validity()
+    }
+
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+
+    /// Returns the untyped handle to the same node
+    pub fn untyped(&self) -> GodotNodeHandle {
+        GodotNodeHandle::from_instance_id(self.instance_id)
+    }
+}
+
+impl GodotNodeHandle {
+    /// Returns a typed handle to the node if it is a `T`, or `None` if it isn't or has been freed
+    pub fn typed<T: Inherits<Node>>(&self) -> Option<TypedNodeHandle<T>> {
+        Gd::<T>::try_from_instance_id(self.instance_id())
+            .ok()
+            .map(TypedNodeHandle::new)
+    }
+}
+
+impl<T: Inherits<Node>> From<TypedNodeHandle<T>> for GodotNodeHandle {
+    fn from(handle: TypedNodeHandle<T>) -> Self {
+        handle.untyped()
+    }
+}
+
+impl<T: Inherits<Node>> TryFrom<GodotNodeHandle> for TypedNodeHandle<T> {
+    /// The handle is given back if its node is not a `T` or has been freed
+    type Error = GodotNodeHandle;
+
+    fn try_from(handle: GodotNodeHandle) -> Result<Self, Self::Error> {
+        handle.typed().ok_or(handle)
+    }
+}
+
+// Manual impls, the derives would require `T` to implement these traits as well
+
+impl<T: Inherits<Node>> Clone for TypedNodeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Inherits<Node>> Copy for TypedNodeHandle<T> {}
+
+impl<T: Inherits<Node>> PartialEq for TypedNodeHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: Inherits<Node>> Eq for TypedNodeHandle<T> {}
+
+impl<T: Inherits<Node>> std::fmt::Debug for TypedNodeHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedNodeHandle")
+            .field("class", &T::class_name())
+            .field("instance_id", &self.instance_id)
+            .finish()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::interop::GodotNodeHandle;
-use crate::plugins::core::PrePhysicsUpdate;
+use crate::plugins::core::{GodotSyncSet, PrePhysicsUpdate};
+use crate::plugins::scene_tree::GodotNodeIndex;
 use bevy::{
     app::{App, Plugin},
     ecs::{
@@ -7,7 +8,7 @@ use bevy::{
         entity::Entity,
         event::{Event, EventReader, EventWriter, event_update_system},
         schedule::IntoScheduleConfigs,
-        system::{NonSendMut, Query},
+        system::{NonSendMut, Query, Res},
     },
 };
 use godot::prelude::*;
@@ -43,7 +44,8 @@ impl Plugin for GodotCollisionsPlugin {
             (
                 write_godot_collision_events.before(event_update_system),
                 update_godot_collisions,
-            ),
+            )
+                .in_set(GodotSyncSet::ReadSignals),
         )
         .add_event::<CollisionEvent>();
     }
@@ -75,35 +77,25 @@ pub enum CollisionEventType {
 
 fn update_godot_collisions(
     mut events: EventReader<CollisionEvent>,
-    mut entities: Query<(&GodotNodeHandle, &mut Collisions)>,
-    all_entities: Query<(Entity, &GodotNodeHandle)>,
+    mut entities: Query<&mut Collisions>,
+    node_index: Res<GodotNodeIndex>,
 ) {
-    for (_, mut collisions) in entities.iter_mut() {
+    for mut collisions in entities.iter_mut() {
         collisions.recent_collisions = vec![];
     }
 
     for event in events.read() {
         trace!(target: "godot_collisions_update", event = ?event);
 
-        let target = all_entities.iter().find_map(|(ent, reference)| {
-            if reference == &event.target {
-                Some(ent)
-            } else {
-                None
-            }
-        });
-
-        let collisions = entities.iter_mut().find_map(|(reference, collisions)| {
-            if reference == &event.origin {
-                Some(collisions)
-            } else {
-                None
-            }
-        });
+        let (Some(origin), Some(target)) = (
+            node_index.entity_for_handle(&event.origin),
+            node_index.entity_for_handle(&event.target),
+        ) else {
+            continue;
+        };
 
-        let (target, mut collisions) = match (target, collisions) {
-            (Some(target), Some(collisions)) => (target, collisions),
-            _ => continue,
+        let Ok(mut collisions) = entities.get_mut(origin) else {
+            continue;
         };
 
         match event.event_type {
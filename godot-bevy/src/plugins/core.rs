@@ -18,6 +18,34 @@ pub struct PrePhysicsUpdate;
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PhysicsUpdate;
 
+/// The points at which godot-bevy moves data between Godot and Bevy.
+///
+/// Every built-in plugin adds its sync systems to one of these sets, so other systems can be
+/// ordered around them, e.g. `.after(GodotSyncSet::ReadTransforms)`. The order between sets that
+/// share a schedule is configured by [`GodotBaseCorePlugin`].
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GodotSyncSet {
+    /// Mirrors added, removed, renamed and moved nodes, in `First`
+    ReadSceneTree,
+    /// Sends `GodotSignal` events in `First` and collision events in `PrePhysicsUpdate`. Runs
+    /// after `ReadSceneTree`
+    ReadSignals,
+    /// Sends Godot input events in `First` and updates Bevy's input resources in `PreUpdate`
+    ReadInput,
+    /// Copies node transforms to `Transform`, in `PreUpdate` after `ReadInput`
+    ReadTransforms,
+    /// Reads node properties synced to components, in `PreUpdate` after `ReadTransforms`
+    ReadProperties,
+    /// Writes names, groups, hierarchy changes and component-synced properties to Godot, in
+    /// `PostUpdate`
+    WriteProperties,
+    /// Applies queued `GodotCommands` in `PostUpdate`, after `WriteProperties`, and in
+    /// `PhysicsUpdate`
+    FlushCommands,
+    /// Copies `Transform` to node transforms, in `Last`
+    WriteTransforms,
+}
+
 /// Resource containing Godot's physics delta time for the current frame
 #[derive(Resource, Default)]
 pub struct PhysicsDelta {
@@ -40,18 +68,34 @@ impl PhysicsDelta {
 #[derive(Resource, Default, Debug)]
 pub struct MainThreadMarker;
 
-use crate::interop::GodotNodeHandle;
+use crate::interop::{GodotNodeHandle, apply_pending_ref_changes};
+use crate::plugins::scene_tree::script_class::script_class_names;
 use bevy::ecs::system::EntityCommands;
+use godot::builtin::StringName;
+use godot::classes::Node;
+use godot::obj::{GodotClass, Inherits};
 
 /// Function that adds a component to an entity with access to the Godot node
 type ComponentInserter = Box<dyn Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync>;
 
+/// What a registered inserter is deduplicated by
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ComponentRegistration {
+    /// A component inserted by [`SceneTreeComponentRegistry::register`] or
+    /// [`SceneTreeComponentRegistry::register_with_init`]
+    Component(TypeId),
+    /// A marker component for a Rust class, as `(marker, class)`
+    ClassMarker(TypeId, TypeId),
+    /// A marker component for a script class, as `(marker, class_name)`
+    ScriptClassMarker(TypeId, String),
+}
+
 /// Registry for components that should be added to entities spawned from the scene tree
 #[derive(Resource, Default)]
 pub struct SceneTreeComponentRegistry {
     /// Components to add to every entity spawned from scene tree
-    /// Stored as (TypeId, inserter) to avoid duplicates
-    components: Vec<(TypeId, ComponentInserter)>,
+    /// Stored with what they were registered for to avoid duplicates
+    components: Vec<(ComponentRegistration, ComponentInserter)>,
 }
 
 impl SceneTreeComponentRegistry {
@@ -60,17 +104,12 @@ impl SceneTreeComponentRegistry {
     where
         C: Component + Default,
     {
-        let type_id = TypeId::of::<C>();
-
-        // Check if already registered
-        if self.components.iter().any(|(id, _)| *id == type_id) {
-            return;
-        }
-
-        let inserter = Box::new(|entity: &mut EntityCommands, _node: &GodotNodeHandle| {
-            entity.insert(C::default());
-        });
-        self.components.push((type_id, inserter));
+        self.register_inserter(
+            ComponentRegistration::Component(TypeId::of::<C>()),
+            |entity, _node| {
+                entity.insert(C::default());
+            },
+        );
     }
 
     /// Register a component type with custom initialization logic
@@ -79,15 +118,19 @@ impl SceneTreeComponentRegistry {
         C: Component,
         F: Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync + 'static,
     {
-        let type_id = TypeId::of::<C>();
+        self.register_inserter(ComponentRegistration::Component(TypeId::of::<C>()), init_fn);
+    }
 
+    fn register_inserter<F>(&mut self, registration: ComponentRegistration, inserter: F)
+    where
+        F: Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync + 'static,
+    {
         // Check if already registered
-        if self.components.iter().any(|(id, _)| *id == type_id) {
+        if self.components.iter().any(|(id, _)| *id == registration) {
             return;
         }
 
-        let inserter = Box::new(init_fn);
-        self.components.push((type_id, inserter));
+        self.components.push((registration, Box::new(inserter)));
     }
 
     /// Add all registered components to an entity
@@ -110,6 +153,21 @@ pub trait AppSceneTreeExt {
     where
         C: Component,
         F: Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync + 'static;
+
+    /// Register a marker component added to entities whose node is an instance of the Rust
+    /// class `C` (e.g. a `#[derive(GodotClass)]` type) or of a class inheriting from it. The same
+    /// marker can be registered for several classes
+    fn register_godot_class_marker<C, M>(&mut self) -> &mut Self
+    where
+        C: GodotClass + Inherits<Node>,
+        M: Component + Default;
+
+    /// Register a marker component added to entities whose node runs a script declaring
+    /// `class_name`, or a script inheriting from one that does. The same marker can be registered
+    /// for several class names
+    fn register_godot_script_class_marker<M>(&mut self, class_name: &str) -> &mut Self
+    where
+        M: Component + Default;
 }
 
 impl AppSceneTreeExt for App {
@@ -153,6 +211,44 @@ impl AppSceneTreeExt for App {
 
         self
     }
+
+    fn register_godot_class_marker<C, M>(&mut self) -> &mut Self
+    where
+        C: GodotClass + Inherits<Node>,
+        M: Component + Default,
+    {
+        self.world_mut()
+            .get_resource_or_init::<SceneTreeComponentRegistry>()
+            .register_inserter(
+                ComponentRegistration::ClassMarker(TypeId::of::<M>(), TypeId::of::<C>()),
+                |entity, node| {
+                    if node.clone().try_get::<C>().is_some() {
+                        entity.insert(M::default());
+                    }
+                },
+            );
+
+        self
+    }
+
+    fn register_godot_script_class_marker<M>(&mut self, class_name: &str) -> &mut Self
+    where
+        M: Component + Default,
+    {
+        let registration =
+            ComponentRegistration::ScriptClassMarker(TypeId::of::<M>(), class_name.to_string());
+        let class_name = StringName::from(class_name);
+        self.world_mut()
+            .get_resource_or_init::<SceneTreeComponentRegistry>()
+            .register_inserter(registration, move |entity, node| {
+                let node = node.clone().get::<Node>();
+                if script_class_names(&node).any(|name| name == class_name) {
+                    entity.insert(M::default());
+                }
+            });
+
+        self
+    }
 }
 
 /// Minimal core plugin with only essential Godot-Bevy integration.
@@ -166,11 +262,30 @@ impl Plugin for GodotBaseCorePlugin {
             .add_plugins(bevy::diagnostic::DiagnosticsPlugin)
             .init_resource::<PhysicsDelta>()
             .init_non_send_resource::<MainThreadMarker>()
-            .init_resource::<SceneTreeComponentRegistry>();
+            .init_resource::<SceneTreeComponentRegistry>()
+            .add_systems(Last, apply_pending_ref_changes);
 
         // Add the PhysicsUpdate schedule
         app.add_schedule(Schedule::new(PrePhysicsUpdate));
         app.add_schedule(Schedule::new(PhysicsUpdate));
+
+        app.configure_sets(
+            First,
+            (GodotSyncSet::ReadSceneTree, GodotSyncSet::ReadSignals).chain(),
+        )
+        .configure_sets(
+            PreUpdate,
+            (
+                GodotSyncSet::ReadInput,
+                GodotSyncSet::ReadTransforms,
+                GodotSyncSet::ReadProperties,
+            )
+                .chain(),
+        )
+        .configure_sets(
+            PostUpdate,
+            (GodotSyncSet::WriteProperties, GodotSyncSet::FlushCommands).chain(),
+        );
     }
 }
 
@@ -218,3 +333,42 @@ where
         self.find_map(|(ent_name, t)| (ent_name.as_str() == name).then_some(t))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct SetOrder(Vec<GodotSyncSet>);
+
+    fn record(set: GodotSyncSet) -> impl FnMut(ResMut<SetOrder>) {
+        move |mut order: ResMut<SetOrder>| order.0.push(set)
+    }
+
+    #[test]
+    fn test_pre_update_reads_are_ordered() {
+        let mut app = App::new();
+        app.add_plugins(GodotBaseCorePlugin)
+            .init_resource::<SetOrder>()
+            // Added in reverse, so only the set order can put them right
+            .add_systems(
+                PreUpdate,
+                (
+                    record(GodotSyncSet::ReadProperties).in_set(GodotSyncSet::ReadProperties),
+                    record(GodotSyncSet::ReadTransforms).in_set(GodotSyncSet::ReadTransforms),
+                    record(GodotSyncSet::ReadInput).in_set(GodotSyncSet::ReadInput),
+                ),
+            );
+
+        app.world_mut().run_schedule(PreUpdate);
+
+        assert_eq!(
+            app.world().resource::<SetOrder>().0,
+            [
+                GodotSyncSet::ReadInput,
+                GodotSyncSet::ReadTransforms,
+                GodotSyncSet::ReadProperties,
+            ]
+        );
+    }
+}
@@ -0,0 +1,448 @@
+//! Deferred Godot node operations that can be queued from any system.
+//!
+//! Systems touching Godot objects have to run on the main thread, which keeps them from running in
+//! parallel. [`GodotCommands`] lets regular (multi-threaded) systems queue node operations instead;
+//! they are applied on the main thread in [`GodotSyncSet::FlushCommands`].
+
+use bevy::{
+    app::{App, Plugin, PostUpdate},
+    ecs::{
+        entity::Entity,
+        resource::Resource,
+        schedule::IntoScheduleConfigs,
+        system::{Deferred, Query, ResMut, SystemBuffer, SystemMeta, SystemParam},
+        world::World,
+    },
+    log::{debug, warn},
+};
+use godot::{
+    builtin::Variant,
+    classes::Node,
+    meta::ToGodot,
+    obj::{Gd, Inherits},
+};
+use std::marker::PhantomData;
+
+use super::core::{GodotSyncSet, PhysicsUpdate};
+use crate::interop::GodotNodeHandle;
+use crate::prelude::main_thread_system;
+
+#[derive(Default)]
+pub struct GodotCommandsPlugin;
+
+impl Plugin for GodotCommandsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GodotCommandQueue>()
+            .add_systems(
+                PostUpdate,
+                flush_godot_commands.in_set(GodotSyncSet::FlushCommands),
+            )
+            .add_systems(
+                PhysicsUpdate,
+                flush_godot_commands.in_set(GodotSyncSet::FlushCommands),
+            );
+    }
+}
+
+/// Queues operations on Godot nodes from systems that don't run on the main thread.
+///
+/// Nodes are addressed by the entity mirroring them or by their [`GodotNodeHandle`], and the
+/// operations are applied in [`GodotSyncSet::FlushCommands`] in the order they were queued.
+/// Commands queued by systems ordered `.before(GodotSyncSet::FlushCommands)` are applied in the
+/// same frame, commands queued by other systems at the next flush. Commands for entities without a
+/// node, or whose node has been freed, are dropped.
+///
+/// ```ignore
+/// fn update_hud(score: Res<Score>, hud: Res<HudNodes>, mut godot: GodotCommands) {
+///     godot
+///         .node(&hud.score_label)
+///         .set("text", format!("Score: {}", score.0));
+/// }
+///
+/// fn kill_mobs(mobs: Query<(Entity, &Health), With<Mob>>, mut godot: GodotCommands) {
+///     for (entity, health) in mobs.iter() {
+///         if health.0 <= 0 {
+///             godot
+///                 .entity(entity)
+///                 .call("play", ("death".to_string(),))
+///                 .emit_signal("died", ())
+///                 .queue_free();
+///         }
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct GodotCommands<'w, 's> {
+    buffer: Deferred<'s, GodotCommandBuffer>,
+    marker: PhantomData<&'w ()>,
+}
+
+impl GodotCommands<'_, '_> {
+    /// Queue operations on the node mirrored by `entity`
+    pub fn entity(&mut self, entity: Entity) -> GodotNodeCommands<'_> {
+        self.target(GodotCommandTarget::Entity(entity))
+    }
+
+    /// Queue operations on the node behind `handle`
+    pub fn node(&mut self, handle: &GodotNodeHandle) -> GodotNodeCommands<'_> {
+        self.target(GodotCommandTarget::Node(handle.clone()))
+    }
+
+    fn target(&mut self, target: GodotCommandTarget) -> GodotNodeCommands<'_> {
+        GodotNodeCommands {
+            target,
+            buffer: &mut self.buffer.0,
+        }
+    }
+}
+
+/// Operations on a single node, returned by [`GodotCommands::entity`] and [`GodotCommands::node`]
+pub struct GodotNodeCommands<'a> {
+    target: GodotCommandTarget,
+    buffer: &'a mut Vec<GodotCommand>,
+}
+
+impl GodotNodeCommands<'_> {
+    /// Set a property of the node, like `Object::set`
+    pub fn set<V: ToGodot + Send + 'static>(&mut self, property: &str, value: V) -> &mut Self {
+        self.push(GodotCommandOp::Set {
+            property: property.to_string(),
+            value: Box::new(move || value.to_variant()),
+        })
+    }
+
+    /// Call a method on the node, like `Object::call`. `args` is a tuple of arguments, e.g.
+    /// `("walk", 1.5)`, or `()` for none
+    pub fn call(&mut self, method: &str, args: impl GodotCommandArgs) -> &mut Self {
+        self.push(GodotCommandOp::Call {
+            method: method.to_string(),
+            args: Box::new(move || args.into_variants()),
+        })
+    }
+
+    /// Emit a signal from the node, like `Object::emit_signal`
+    pub fn emit_signal(&mut self, signal: &str, args: impl GodotCommandArgs) -> &mut Self {
+        self.push(GodotCommandOp::EmitSignal {
+            signal: signal.to_string(),
+            args: Box::new(move || args.into_variants()),
+        })
+    }
+
+    /// Add a node, given by its entity or handle, as child of this node. The child must not
+    /// have a parent yet
+    pub fn add_child(&mut self, child: impl Into<GodotCommandTarget>) -> &mut Self {
+        self.push(GodotCommandOp::AddChild(child.into()))
+    }
+
+    pub fn queue_free(&mut self) -> &mut Self {
+        self.push(GodotCommandOp::QueueFree)
+    }
+
+    /// Run `f` with the node cast to `T`, for anything not covered by the other commands. Skipped
+    /// if the node is not a `T`
+    pub fn with<T: Inherits<Node>>(&mut self, f: impl FnOnce(Gd<T>) + Send + 'static) -> &mut Self {
+        self.push(GodotCommandOp::Custom(Box::new(move |node| {
+            match node.try_cast::<T>() {
+                Ok(node) => f(node),
+                Err(node) => warn!(
+                    "GodotCommands: {} is not a {}",
+                    node.get_class(),
+                    std::any::type_name::<T>()
+                ),
+            }
+        })))
+    }
+
+    fn push(&mut self, op: GodotCommandOp) -> &mut Self {
+        self.buffer.push(GodotCommand {
+            target: self.target.clone(),
+            op,
+        });
+        self
+    }
+}
+
+/// A node addressed by a [`GodotCommands`] command
+#[derive(Debug, Clone)]
+pub enum GodotCommandTarget {
+    /// The node mirrored by this entity
+    Entity(Entity),
+    Node(GodotNodeHandle),
+}
+
+impl From<Entity> for GodotCommandTarget {
+    fn from(entity: Entity) -> Self {
+        Self::Entity(entity)
+    }
+}
+
+impl From<GodotNodeHandle> for GodotCommandTarget {
+    fn from(handle: GodotNodeHandle) -> Self {
+        Self::Node(handle)
+    }
+}
+
+impl From<&GodotNodeHandle> for GodotCommandTarget {
+    fn from(handle: &GodotNodeHandle) -> Self {
+        Self::Node(handle.clone())
+    }
+}
+
+/// Arguments of [`GodotNodeCommands::call`] and [`GodotNodeCommands::emit_signal`]: a tuple of
+/// up to six values that can be sent to the main thread
+pub trait GodotCommandArgs: Send + 'static {
+    fn into_variants(self) -> Vec<Variant>;
+}
+
+impl GodotCommandArgs for () {
+    fn into_variants(self) -> Vec<Variant> {
+        Vec::new()
+    }
+}
+
+macro_rules! impl_godot_command_args {
+    ($($arg:ident),+) => {
+        impl<$($arg: ToGodot + Send + 'static),+> GodotCommandArgs for ($($arg,)+) {
+            #[allow(non_snake_case)]
+            fn into_variants(self) -> Vec<Variant> {
+                let ($($arg,)+) = self;
+                vec![$($arg.to_variant()),+]
+            }
+        }
+    };
+}
+
+impl_godot_command_args!(A);
+impl_godot_command_args!(A, B);
+impl_godot_command_args!(A, B, C);
+impl_godot_command_args!(A, B, C, D);
+impl_godot_command_args!(A, B, C, D, E);
+impl_godot_command_args!(A, B, C, D, E, F);
+
+type VariantFn = Box<dyn FnOnce() -> Variant + Send>;
+type VariantsFn = Box<dyn FnOnce() -> Vec<Variant> + Send>;
+
+struct GodotCommand {
+    target: GodotCommandTarget,
+    op: GodotCommandOp,
+}
+
+enum GodotCommandOp {
+    Set { property: String, value: VariantFn },
+    Call { method: String, args: VariantsFn },
+    EmitSignal { signal: String, args: VariantsFn },
+    AddChild(GodotCommandTarget),
+    QueueFree,
+    Custom(Box<dyn FnOnce(Gd<Node>) + Send>),
+}
+
+/// Per-system command buffer, moved into the [`GodotCommandQueue`] when the system's deferred
+/// buffers are applied
+#[derive(Default)]
+struct GodotCommandBuffer(Vec<GodotCommand>);
+
+impl SystemBuffer for GodotCommandBuffer {
+    fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
+        if self.0.is_empty() {
+            return;
+        }
+
+        world
+            .resource_mut::<GodotCommandQueue>()
+            .commands
+            .append(&mut self.0);
+    }
+}
+
+/// Commands waiting for the next flush
+#[derive(Resource, Default)]
+struct GodotCommandQueue {
+    commands: Vec<GodotCommand>,
+}
+
+#[main_thread_system]
+fn flush_godot_commands(mut queue: ResMut<GodotCommandQueue>, nodes: Query<&GodotNodeHandle>) {
+    let resolve = |target: &GodotCommandTarget| {
+        let mut handle = match target {
+            GodotCommandTarget::Entity(entity) => nodes.get(*entity).ok()?.clone(),
+            GodotCommandTarget::Node(handle) => handle.clone(),
+        };
+        handle.try_get::<Node>()
+    };
+
+    for GodotCommand { target, op } in std::mem::take(&mut queue.commands) {
+        let Some(mut node) = resolve(&target) else {
+            debug!("GodotCommands: dropping command for {target:?}, it has no node");
+            continue;
+        };
+
+        match op {
+            GodotCommandOp::Set { property, value } => {
+                node.set(property.as_str(), &value());
+            }
+            GodotCommandOp::Call { method, args } => {
+                node.call(method.as_str(), &args());
+            }
+            GodotCommandOp::EmitSignal { signal, args } => {
+                node.emit_signal(signal.as_str(), &args());
+            }
+            GodotCommandOp::AddChild(child) => match resolve(&child) {
+                Some(child) => node.add_child(&child),
+                None => debug!("GodotCommands: can't add {child:?} as child, it has no node"),
+            },
+            GodotCommandOp::QueueFree => node.queue_free(),
+            GodotCommandOp::Custom(f) => f(node),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::core::MainThreadMarker;
+    use bevy::ecs::schedule::Schedule;
+
+    /// The target entity and a short description of every queued command, in queue order
+    fn queued(world: &World) -> Vec<(Entity, String)> {
+        world
+            .resource::<GodotCommandQueue>()
+            .commands
+            .iter()
+            .map(|command| {
+                let GodotCommandTarget::Entity(entity) = &command.target else {
+                    panic!("expected an entity target");
+                };
+                let op = match &command.op {
+                    GodotCommandOp::Set { property, .. } => format!("set {property}"),
+                    GodotCommandOp::Call { method, .. } => format!("call {method}"),
+                    GodotCommandOp::EmitSignal { signal, .. } => format!("emit {signal}"),
+                    GodotCommandOp::AddChild(_) => "add_child".to_string(),
+                    GodotCommandOp::QueueFree => "queue_free".to_string(),
+                    GodotCommandOp::Custom(_) => "custom".to_string(),
+                };
+                (*entity, op)
+            })
+            .collect()
+    }
+
+    fn op(entity: Entity, op: &str) -> (Entity, String) {
+        (entity, op.to_string())
+    }
+
+    /// An app with the plugin and `count` entities without a node, so flushing drops their
+    /// commands without touching Godot
+    fn app_with_entities(count: usize) -> (App, Vec<Entity>) {
+        let mut app = App::new();
+        app.add_plugins(GodotCommandsPlugin);
+        let world = app.world_mut();
+        world.init_non_send_resource::<MainThreadMarker>();
+        let entities = (0..count).map(|_| world.spawn_empty().id()).collect();
+        (app, entities)
+    }
+
+    #[test]
+    fn test_commands_are_queued_in_order() {
+        let mut world = World::new();
+        world.init_resource::<GodotCommandQueue>();
+        let (a, b) = (world.spawn_empty().id(), world.spawn_empty().id());
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                move |mut godot: GodotCommands| {
+                    godot
+                        .entity(a)
+                        .set("text", "hit".to_string())
+                        .call("play", ("death".to_string(),))
+                        .queue_free();
+                },
+                move |mut godot: GodotCommands| {
+                    godot.entity(b).emit_signal("died", ());
+                    godot.entity(a).add_child(b);
+                },
+            )
+                .chain(),
+        );
+        schedule.run(&mut world);
+
+        assert_eq!(
+            queued(&world),
+            [
+                op(a, "set text"),
+                op(a, "call play"),
+                op(a, "queue_free"),
+                op(b, "emit died"),
+                op(a, "add_child"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commands_from_several_systems_are_all_queued() {
+        let mut world = World::new();
+        world.init_resource::<GodotCommandQueue>();
+        let entities: Vec<_> = (0..3).map(|_| world.spawn_empty().id()).collect();
+
+        let mut schedule = Schedule::default();
+        for &entity in &entities {
+            schedule.add_systems(move |mut godot: GodotCommands| {
+                godot.entity(entity).set("visible", false);
+            });
+        }
+        schedule.run(&mut world);
+
+        // Unordered systems queue in any order, but each queues exactly once
+        let mut commands = queued(&world);
+        commands.sort();
+        let expected: Vec<_> = entities.iter().map(|&e| op(e, "set visible")).collect();
+        assert_eq!(commands, expected);
+    }
+
+    #[test]
+    fn test_commands_flush_in_post_update() {
+        let (mut app, entities) = app_with_entities(2);
+        let (early, late) = (entities[0], entities[1]);
+        app.add_systems(
+            PostUpdate,
+            (
+                (move |mut godot: GodotCommands| {
+                    godot.entity(early).queue_free();
+                })
+                .before(GodotSyncSet::FlushCommands),
+                (move |mut godot: GodotCommands| {
+                    godot.entity(late).queue_free();
+                })
+                .after(GodotSyncSet::FlushCommands),
+            ),
+        );
+
+        app.world_mut().run_schedule(PostUpdate);
+
+        // Queued after the flush, so it waits for the next one
+        assert_eq!(queued(app.world()), [op(late, "queue_free")]);
+    }
+
+    #[test]
+    fn test_commands_flush_in_physics_update() {
+        let (mut app, entities) = app_with_entities(2);
+        let (physics, frame) = (entities[0], entities[1]);
+        app.add_systems(
+            PhysicsUpdate,
+            (move |mut godot: GodotCommands| {
+                godot.entity(physics).queue_free();
+            })
+            .before(GodotSyncSet::FlushCommands),
+        );
+
+        // Queued during the frame, flushed by the next physics step
+        let mut schedule = Schedule::default();
+        schedule.add_systems(move |mut godot: GodotCommands| {
+            godot.entity(frame).call("hide", ());
+        });
+        schedule.run(app.world_mut());
+        assert_eq!(queued(app.world()), [op(frame, "call hide")]);
+
+        app.world_mut().run_schedule(PhysicsUpdate);
+        assert!(queued(app.world()).is_empty());
+    }
+}
@@ -17,6 +17,8 @@ use godot::{
 };
 use tracing::trace;
 
+use crate::plugins::core::GodotSyncSet;
+
 /// Plugin that handles Godot input events and converts them to Bevy events.
 /// This is the base input plugin that provides raw input event types.
 ///
@@ -32,14 +34,19 @@ pub type GodotInputPlugin = GodotInputEventPlugin;
 
 impl Plugin for GodotInputEventPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(First, write_input_events.before(event_update_system))
-            .add_event::<KeyboardInput>()
-            .add_event::<MouseButtonInput>()
-            .add_event::<MouseMotion>()
-            .add_event::<TouchInput>()
-            .add_event::<ActionInput>()
-            .add_event::<GamepadButtonInput>()
-            .add_event::<GamepadAxisInput>();
+        app.add_systems(
+            First,
+            write_input_events
+                .before(event_update_system)
+                .in_set(GodotSyncSet::ReadInput),
+        )
+        .add_event::<KeyboardInput>()
+        .add_event::<MouseButtonInput>()
+        .add_event::<MouseMotion>()
+        .add_event::<TouchInput>()
+        .add_event::<ActionInput>()
+        .add_event::<GamepadButtonInput>()
+        .add_event::<GamepadAxisInput>();
     }
 }
 
@@ -3,6 +3,7 @@ use bevy::{
     ecs::{
         entity::Entity,
         event::{EventReader, EventWriter},
+        schedule::IntoScheduleConfigs,
         system::ResMut,
     },
     input::{
@@ -16,6 +17,7 @@ use bevy::{
     math::Vec2,
 };
 
+use crate::plugins::core::GodotSyncSet;
 use crate::plugins::input::events::{
     KeyboardInput as GodotKeyboardInput, MouseButton as GodotMouseButton,
     MouseButtonInput as GodotMouseButtonInput, MouseMotion as GodotMouseMotion,
@@ -38,7 +40,8 @@ impl Plugin for BevyInputBridgePlugin {
                     bridge_mouse_button_input,
                     bridge_mouse_motion,
                     bridge_mouse_scroll,
-                ),
+                )
+                    .in_set(GodotSyncSet::ReadInput),
             )
             .add_systems(Last, clear_keyboard_input);
     }
@@ -8,11 +8,15 @@ pub mod collisions;
 pub mod core;
 #[cfg(feature = "godot_bevy_log")]
 pub mod godot_bevy_logger;
+pub mod godot_commands;
 pub mod input;
 pub mod packed_scene;
+pub mod property_sync;
 pub mod scene_tree;
 pub mod signals;
 pub mod transforms;
+#[cfg(feature = "visibility_sync")]
+pub mod visibility;
 
 // Re-export all plugins for convenience
 pub use assets::GodotAssetsPlugin;
@@ -21,11 +25,14 @@ pub use collisions::GodotCollisionsPlugin;
 pub use core::GodotBaseCorePlugin;
 #[cfg(feature = "godot_bevy_log")]
 pub use godot_bevy_logger::GodotBevyLogPlugin;
+pub use godot_commands::GodotCommandsPlugin;
 pub use input::{BevyInputBridgePlugin, GodotInputEventPlugin};
 pub use packed_scene::GodotPackedScenePlugin;
 pub use scene_tree::GodotSceneTreePlugin;
 pub use signals::GodotSignalsPlugin;
 pub use transforms::GodotTransformSyncPlugin;
+#[cfg(feature = "visibility_sync")]
+pub use visibility::GodotVisibilitySyncPlugin;
 
 // Re-export for backwards compatibility
 #[deprecated(note = "Use GodotInputEventPlugin instead")]
@@ -33,10 +40,11 @@ pub use input::GodotInputEventPlugin as GodotInputPlugin;
 
 plugin_group! {
     /// Minimal core functionality required for Godot-Bevy integration.
-    /// This includes scene tree management
+    /// This includes scene tree management and deferred node commands
     pub struct GodotCorePlugins {
         :GodotBaseCorePlugin,
         :GodotSceneTreePlugin,
+        :GodotCommandsPlugin,
     }
 }
 
@@ -1,32 +1,81 @@
-use super::scene_tree::SceneTreeRef;
+use super::core::GodotSyncSet;
+use super::scene_tree::plugin::NodeLeftTree;
+use super::scene_tree::{GodotNodeIndex, SceneTreeConfig, SceneTreeFilter, SceneTreeRef};
 use crate::plugins::assets::GodotResource;
 use crate::plugins::transforms::IntoGodotTransform2D;
 use crate::prelude::main_thread_system;
 use crate::{interop::GodotNodeHandle, plugins::transforms::IntoGodotTransform};
 use bevy::{
-    app::{App, Plugin, PostUpdate},
-    asset::{Assets, Handle},
+    app::{App, First, Plugin, PostUpdate},
+    asset::{AssetId, AssetServer, Assets, Handle, LoadState},
     ecs::{
+        bundle::Bundle,
         component::Component,
         entity::Entity,
-        query::Without,
-        system::{Commands, Query, ResMut},
+        error::ignore,
+        event::{Event, EventWriter},
+        hierarchy::{ChildOf, Children},
+        name::Name,
+        query::{QueryEntityError, With, Without},
+        schedule::IntoScheduleConfigs,
+        system::{Commands, EntityCommands, Query, Res, ResMut},
+        world::EntityWorldMut,
     },
     log::tracing,
+    prelude::Resource,
     transform::components::Transform,
 };
 use godot::{
-    builtin::GString,
-    classes::{Node, Node2D, Node3D, PackedScene, ResourceLoader},
+    builtin::{GString, StringName, Variant},
+    classes::{ClassDb, Node, Node2D, Node3D, PackedScene, ResourceLoader},
+    init::is_main_thread,
+    meta::ToGodot,
+    obj::{Gd, GodotClass, Inherits, InstanceId},
 };
+use std::collections::HashMap;
+use std::marker::PhantomData;
 use std::str::FromStr;
-use tracing::error;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tracing::{error, warn};
 
 #[derive(Default)]
 pub struct GodotPackedScenePlugin;
 impl Plugin for GodotPackedScenePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PostUpdate, spawn_scene);
+        app.init_resource::<GodotSceneSpawnConfig>()
+            .init_resource::<GodotScenePool>()
+            .add_event::<GodotSceneSpawnFailed>()
+            .add_event::<GodotNodeSpawnFailed>()
+            .add_event::<GodotSceneReady>()
+            .add_systems(
+                First,
+                (detect_ready_scenes, release_returned_instances)
+                    .after(GodotSyncSet::ReadSceneTree),
+            )
+            // Before the flush, so commands can address the nodes of scenes spawned this frame
+            .add_systems(
+                PostUpdate,
+                (spawn_scene, spawn_node).before(GodotSyncSet::FlushCommands),
+            );
+    }
+}
+
+/// Configuration resource for [`GodotScene`] and [`GodotNodeSpawn`] spawning
+#[derive(Resource, Debug, Clone)]
+pub struct GodotSceneSpawnConfig {
+    /// How long a [`GodotScene`] waits for its `Handle<GodotResource>` to load before failing
+    /// with [`GodotSceneSpawnError::LoadTimedOut`], and a [`GodotNodeSpawn`] waits for its
+    /// resources and parent entity's node before failing with
+    /// [`GodotNodeSpawnError::TimedOut`]. `None` waits forever.
+    pub load_timeout: Option<Duration>,
+}
+
+impl Default for GodotSceneSpawnConfig {
+    fn default() -> Self {
+        Self {
+            load_timeout: Some(Duration::from_secs(30)),
+        }
     }
 }
 
@@ -37,10 +86,17 @@ impl Plugin for GodotPackedScenePlugin {
 /// [`GodotScene`]s that are spawned/inserted into the bevy world will be instanced from the provided
 /// handle/path and the instance will be added as an [`GodotNodeHandle`] in the next PostUpdateFlush set.
 /// (see [`spawn_scene`])
+///
+/// Scenes whose handle is still loading stay pending until it has loaded. If the scene can't be
+/// spawned, a [`GodotSceneSpawnFailed`] event is sent and the reason is inserted on the entity
+/// as a [`GodotSceneSpawnError`] component. The [`GodotScene`] stays on the entity but isn't
+/// spawned while the error is there, remove the error to try again.
 #[derive(Debug, Component)]
 pub struct GodotScene {
     resource: GodotSceneResource,
     parent: Option<GodotNodeHandle>,
+    /// When the scene started waiting for its handle to load
+    waiting_since: Option<Instant>,
 }
 
 #[derive(Debug)]
@@ -56,6 +112,7 @@ impl GodotScene {
         Self {
             resource: GodotSceneResource::Handle(handle),
             parent: None,
+            waiting_since: None,
         }
     }
 
@@ -68,6 +125,7 @@ impl GodotScene {
         Self {
             resource: GodotSceneResource::Path(path.to_string()),
             parent: None,
+            waiting_since: None,
         }
     }
 
@@ -78,63 +136,1359 @@ impl GodotScene {
     }
 }
 
+/// Why a [`GodotScene`] could not be spawned
+#[derive(Component, Debug, Clone, PartialEq, Eq, Error)]
+pub enum GodotSceneSpawnError {
+    /// The scene's asset failed to load
+    #[error("failed to load scene asset: {0}")]
+    AssetLoadFailed(String),
+    /// The scene's asset did not finish loading within [`GodotSceneSpawnConfig::load_timeout`]
+    #[error("scene asset did not load within {0:?}")]
+    LoadTimedOut(Duration),
+    /// `ResourceLoader` could not load the scene at this path
+    #[error("failed to load scene at {0}")]
+    ResourceLoadFailed(String),
+    /// The resource is not a `PackedScene`
+    #[error("resource is not a PackedScene: {0}")]
+    NotAPackedScene(String),
+    /// `PackedScene::instantiate` returned no node
+    #[error("failed to instantiate PackedScene")]
+    InstantiateFailed,
+    /// The node given to [`GodotScene::with_parent`] was freed
+    #[error("parent node was freed")]
+    ParentFreed,
+}
+
+/// Sent when a [`GodotScene`] could not be spawned
+#[derive(Event, Debug, Clone)]
+pub struct GodotSceneSpawnFailed {
+    pub entity: Entity,
+    pub reason: GodotSceneSpawnError,
+}
+
+/// Why a [`GodotNodeSpawn`] could not be spawned
+#[derive(Component, Debug, Clone, PartialEq, Eq, Error)]
+pub enum GodotNodeSpawnError {
+    /// A resource given to [`GodotNodeSpawn::with_resource`] failed to load
+    #[error("failed to load resource asset: {0}")]
+    AssetLoadFailed(String),
+    /// The resources or the parent entity's node were not ready within
+    /// [`GodotSceneSpawnConfig::load_timeout`]
+    #[error("resources or parent node not ready within {0:?}")]
+    TimedOut(Duration),
+    /// The entity given to [`GodotNodeSpawn::with_parent_entity`] was despawned
+    #[error("parent entity was despawned")]
+    ParentDespawned,
+    /// The parent node was freed
+    #[error("parent node was freed")]
+    ParentFreed,
+    /// `ClassDb` could not create a node of this class
+    #[error("failed to create a node of class {0}")]
+    InstantiateFailed(String),
+}
+
+/// Sent when a [`GodotNodeSpawn`] could not be spawned
+#[derive(Event, Debug, Clone)]
+pub struct GodotNodeSpawnFailed {
+    pub entity: Entity,
+    pub reason: GodotNodeSpawnError,
+}
+
+/// Keeps instances of frequently spawned scenes around for reuse instead of instancing a fresh
+/// copy for every [`GodotScene`].
+///
+/// Pooling is enabled per scene handle. Entities spawned from a pooled scene get a
+/// [`PooledSceneInstance`] component, and [`ReturnToPoolExt::return_to_pool`] hands their
+/// instance back: the node is detached from the tree (so it stops processing and rendering),
+/// kept in the pool and the entity is despawned. The next [`GodotScene`] requesting that handle
+/// takes the idle instance instead of instantiating the `PackedScene` again.
+///
+/// A reused instance is mirrored into a new entity like a freshly instanced one, with the
+/// components the [`GodotScene`] was spawned with. Node state (positions of child nodes, running
+/// animations, script variables...) is kept while pooled; reset it in the hook given to
+/// [`GodotScenePool::enable_with_reset`]. Entity state that should differ between fresh and
+/// reused instances goes in the hook given to [`GodotScenePool::enable_with_entity_reset`].
+///
+/// ```ignore
+/// fn setup(mut pool: ResMut<GodotScenePool>, assets: Res<GameAssets>) {
+///     pool.enable_with_reset(&assets.bullet_scene, 256, |node| {
+///         node.set("modulate", &Color::WHITE.to_variant());
+///     });
+///     pool.enable_with_entity_reset(&assets.bullet_scene, 256, |entity| {
+///         entity.insert(Bullet::default()).remove::<Exploding>();
+///     });
+/// }
+///
+/// fn expire_bullets(mut commands: Commands, bullets: Query<(Entity, &Bullet)>) {
+///     for (entity, bullet) in bullets.iter() {
+///         if bullet.expired() {
+///             commands.entity(entity).return_to_pool();
+///         }
+///     }
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct GodotScenePool {
+    scenes: HashMap<AssetId<GodotResource>, ScenePool>,
+}
+
+struct ScenePool {
+    /// Instances ready to be handed out
+    idle: Vec<GodotNodeHandle>,
+    /// Instances returned this frame, whose removal from the tree hasn't been processed yet
+    returned: Vec<GodotNodeHandle>,
+    capacity: usize,
+    reset: Option<NodeReset>,
+    entity_reset: Option<EntityReset>,
+}
+
+type NodeReset = Box<dyn Fn(&mut Gd<Node>) + Send + Sync>;
+type EntityReset = Box<dyn Fn(&mut EntityCommands) + Send + Sync>;
+
+impl GodotScenePool {
+    /// Reuse instances of `scene`, keeping at most `capacity` idle instances around. Instances
+    /// returned to a full pool are freed.
+    pub fn enable(&mut self, scene: &Handle<GodotResource>, capacity: usize) {
+        self.scenes
+            .entry(scene.id())
+            .or_insert_with(|| ScenePool {
+                idle: Vec::new(),
+                returned: Vec::new(),
+                capacity,
+                reset: None,
+                entity_reset: None,
+            })
+            .capacity = capacity;
+    }
+
+    /// Like [`GodotScenePool::enable`], calling `reset` on every instance before it is reused
+    pub fn enable_with_reset(
+        &mut self,
+        scene: &Handle<GodotResource>,
+        capacity: usize,
+        reset: impl Fn(&mut Gd<Node>) + Send + Sync + 'static,
+    ) {
+        self.enable(scene, capacity);
+        if let Some(pool) = self.scenes.get_mut(&scene.id()) {
+            pool.reset = Some(Box::new(reset));
+        }
+    }
+
+    /// Like [`GodotScenePool::enable`], calling `reset` on the entity of every reused instance,
+    /// after the components it was spawned with and its [`GodotNodeHandle`] were inserted
+    pub fn enable_with_entity_reset(
+        &mut self,
+        scene: &Handle<GodotResource>,
+        capacity: usize,
+        reset: impl Fn(&mut EntityCommands) + Send + Sync + 'static,
+    ) {
+        self.enable(scene, capacity);
+        if let Some(pool) = self.scenes.get_mut(&scene.id()) {
+            pool.entity_reset = Some(Box::new(reset));
+        }
+    }
+
+    pub fn is_enabled(&self, scene: &Handle<GodotResource>) -> bool {
+        self.scenes.contains_key(&scene.id())
+    }
+
+    /// Number of instances of `scene` waiting to be reused
+    pub fn idle_count(&self, scene: &Handle<GodotResource>) -> usize {
+        self.scenes
+            .get(&scene.id())
+            .map_or(0, |pool| pool.idle.len() + pool.returned.len())
+    }
+
+    /// Frees all idle instances and disables pooling for every scene
+    pub fn clear(&mut self) {
+        for (_, pool) in self.scenes.drain() {
+            pool.free_instances();
+        }
+    }
+
+    fn take(&mut self, scene: AssetId<GodotResource>) -> Option<Gd<Node>> {
+        while let Some(mut handle) = self.take_handle(scene) {
+            // Skip instances that were freed while pooled
+            if let Some(mut node) = handle.try_get::<Node>() {
+                if let Some(reset) = self.scenes.get(&scene).and_then(|pool| pool.reset.as_ref()) {
+                    reset(&mut node);
+                }
+                return Some(node);
+            }
+        }
+        None
+    }
+
+    /// Pops the most recently released idle instance
+    fn take_handle(&mut self, scene: AssetId<GodotResource>) -> Option<GodotNodeHandle> {
+        self.scenes.get_mut(&scene)?.idle.pop()
+    }
+
+    /// Stores a detached instance, returns false if the pool is full or disabled
+    fn put(&mut self, scene: AssetId<GodotResource>, handle: GodotNodeHandle) -> bool {
+        let Some(pool) = self.scenes.get_mut(&scene) else {
+            return false;
+        };
+
+        if pool.idle.len() + pool.returned.len() >= pool.capacity {
+            return false;
+        }
+
+        pool.returned.push(handle);
+        true
+    }
+
+    /// Runs the entity reset hook of `scene`, if it has one, on the entity of a reused instance
+    fn reset_entity(&self, scene: AssetId<GodotResource>, entity: &mut EntityCommands) {
+        if let Some(reset) = self
+            .scenes
+            .get(&scene)
+            .and_then(|pool| pool.entity_reset.as_ref())
+        {
+            reset(entity);
+        }
+    }
+}
+
+impl Drop for GodotScenePool {
+    fn drop(&mut self) {
+        if self
+            .scenes
+            .values()
+            .all(|pool| pool.idle.is_empty() && pool.returned.is_empty())
+        {
+            return;
+        }
+
+        // Pooled instances are detached from the tree, so they aren't freed along with it when
+        // the app exits
+        if !is_main_thread() {
+            warn!("GodotScenePool dropped outside the main thread, leaking its pooled instances");
+            return;
+        }
+        self.clear();
+    }
+}
+
+impl ScenePool {
+    fn free_instances(self) {
+        for mut handle in self.idle.into_iter().chain(self.returned) {
+            let Some(mut node) = handle.try_get::<Node>() else {
+                continue;
+            };
+            // Detached nodes are freed right away, the scene tree may not process another frame
+            if node.is_inside_tree() {
+                node.queue_free();
+            } else {
+                node.free();
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for GodotScenePool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut map = f.debug_map();
+        for (scene, pool) in &self.scenes {
+            map.entry(
+                scene,
+                &format_args!(
+                    "{}/{} idle",
+                    pool.idle.len() + pool.returned.len(),
+                    pool.capacity
+                ),
+            );
+        }
+        map.finish()
+    }
+}
+
+/// Instances returned in a frame only become available once their `NodeRemoved` events have been
+/// processed. Handing one out earlier would turn its removal and re-adding into a single
+/// `NodeReparented` event and the new entity would never be set up as a fresh scene.
+fn release_returned_instances(mut pool: ResMut<GodotScenePool>) {
+    for pool in pool.scenes.values_mut() {
+        let returned = std::mem::take(&mut pool.returned);
+        pool.idle.extend(returned);
+    }
+}
+
+/// Added to entities whose [`GodotScene`] was spawned from a scene pooled in [`GodotScenePool`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PooledSceneInstance {
+    scene: AssetId<GodotResource>,
+}
+
+impl PooledSceneInstance {
+    /// The pooled scene this entity's instance came from
+    pub fn scene(&self) -> AssetId<GodotResource> {
+        self.scene
+    }
+}
+
+/// Extension trait for `EntityCommands` to return pooled scene instances
+pub trait ReturnToPoolExt {
+    /// Detaches the entity's scene instance from the tree, stores it in the [`GodotScenePool`]
+    /// and despawns the entity. The instance is freed if the pool is full.
+    ///
+    /// Entities without a [`PooledSceneInstance`] are despawned as usual.
+    fn return_to_pool(&mut self) -> &mut Self;
+}
+
+impl ReturnToPoolExt for EntityCommands<'_> {
+    fn return_to_pool(&mut self) -> &mut Self {
+        self.queue_handled(return_entity_to_pool, ignore)
+    }
+}
+
+fn return_entity_to_pool(mut entity: EntityWorldMut) {
+    let pooled = entity.get::<PooledSceneInstance>().copied();
+    let handle = entity.get::<GodotNodeHandle>().cloned();
+    let node = handle
+        .clone()
+        .and_then(|mut handle| handle.try_get::<Node>());
+
+    let (Some(pooled), Some(handle), Some(mut node)) = (pooled, handle, node) else {
+        warn!(
+            "return_to_pool called on {}, which has no pooled scene instance",
+            entity.id()
+        );
+        entity.despawn();
+        return;
+    };
+
+    if let Some(mut parent) = node.get_parent() {
+        parent.remove_child(&node);
+    }
+
+    let stored = entity.world_scope(|world| {
+        world
+            .resource_mut::<GodotScenePool>()
+            .put(pooled.scene, handle)
+    });
+    if !stored {
+        node.queue_free();
+    }
+
+    // The node has already left the tree, so despawn policies must not free it. The entities of
+    // its descendants are let go of and despawned once their `NodeRemoved` events come in.
+    entity.insert(NodeLeftTree).remove::<Children>();
+    entity.despawn();
+}
+
 #[main_thread_system]
 fn spawn_scene(
     mut commands: Commands,
-    mut new_scenes: Query<(&mut GodotScene, Entity, Option<&Transform>), Without<GodotNodeHandle>>,
+    mut new_scenes: Query<
+        (
+            &mut GodotScene,
+            Entity,
+            Option<&Transform>,
+            Option<&ChildOf>,
+            Option<&Name>,
+        ),
+        (Without<GodotNodeHandle>, Without<GodotSceneSpawnError>),
+    >,
+    nodes: Query<&GodotNodeHandle>,
     mut scene_tree: SceneTreeRef,
     mut assets: ResMut<Assets<GodotResource>>,
+    asset_server: Res<AssetServer>,
+    config: Res<SceneTreeConfig>,
+    spawn_config: Res<GodotSceneSpawnConfig>,
+    mut pool: ResMut<GodotScenePool>,
+    mut failures: EventWriter<GodotSceneSpawnFailed>,
 ) {
-    for (mut scene, ent, transform) in new_scenes.iter_mut() {
-        let packed_scene = match &scene.resource {
-            GodotSceneResource::Handle(handle) => assets
-                .get_mut(handle)
-                .expect("packed scene to exist in assets")
-                .get()
-                .clone(),
-            GodotSceneResource::Path(path) => ResourceLoader::singleton()
-                .load(&GString::from_str(path).expect("path to be a valid GString"))
-                .expect("packed scene to load"),
+    for (scene, ent, transform, child_of, name) in new_scenes.iter_mut() {
+        let scene = scene.into_inner();
+
+        // With hierarchy write-back on, an entity parent that has a node decides where the
+        // instance goes, so the node doesn't first land under the root and get moved
+        let parent = scene.parent.clone().or_else(|| {
+            child_of
+                .filter(|_| config.sync_hierarchy_to_godot)
+                .and_then(|child_of| nodes.get(child_of.parent()).ok())
+                .cloned()
+        });
+        let parent = match parent.map(|mut parent| parent.try_get::<Node>()) {
+            Some(None) => {
+                fail_scene_spawn(
+                    &mut commands,
+                    &mut failures,
+                    ent,
+                    &mut scene.waiting_since,
+                    GodotSceneSpawnError::ParentFreed,
+                );
+                continue;
+            }
+            parent => parent.flatten(),
         };
 
-        let packed_scene_cast = packed_scene.clone().try_cast::<PackedScene>();
-        if packed_scene_cast.is_err() {
-            error!("Resource is not a PackedScene: {:?}", packed_scene);
-            continue;
+        let pooled_scene = match &scene.resource {
+            GodotSceneResource::Handle(handle) if pool.is_enabled(handle) => Some(handle.id()),
+            _ => None,
+        };
+
+        let reused = pooled_scene.and_then(|scene| pool.take(scene));
+        let is_reused = reused.is_some();
+        let mut instance = match reused {
+            Some(instance) => instance,
+            None => match instantiate_scene(scene, &mut assets, &asset_server, &spawn_config) {
+                Ok(Some(instance)) => instance,
+                // Still loading
+                Ok(None) => continue,
+                Err(reason) => {
+                    fail_scene_spawn(
+                        &mut commands,
+                        &mut failures,
+                        ent,
+                        &mut scene.waiting_since,
+                        reason,
+                    );
+                    continue;
+                }
+            },
+        };
+
+        if let Some(transform) = transform {
+            set_spawn_transform(&instance, transform);
         }
 
-        let packed_scene = packed_scene_cast.unwrap();
+        // With name write-back on, the entity's name wins over the one saved in the scene
+        if config.sync_names_to_godot
+            && let Some(name) = name
+        {
+            instance.set_name(name.as_str());
+        }
 
-        let instance = match packed_scene.instantiate() {
-            Some(instance) => instance,
+        add_to_parent(&instance, parent, &mut scene_tree);
+
+        let mut ent = commands.entity(ent);
+        ent.insert((GodotNodeHandle::new(instance), PendingSceneInstance));
+        if let Some(scene) = pooled_scene {
+            ent.insert(PooledSceneInstance { scene });
+            if is_reused {
+                pool.reset_entity(scene, &mut ent);
+            }
+        }
+    }
+}
+
+/// Loads the scene's resource and instantiates it, or returns `Ok(None)` while its handle is
+/// still loading
+fn instantiate_scene(
+    scene: &mut GodotScene,
+    assets: &mut Assets<GodotResource>,
+    asset_server: &AssetServer,
+    spawn_config: &GodotSceneSpawnConfig,
+) -> Result<Option<Gd<Node>>, GodotSceneSpawnError> {
+    let resource = match &scene.resource {
+        GodotSceneResource::Handle(handle) => match assets.get_mut(handle) {
+            Some(asset) => asset.get(),
             None => {
-                error!("Failed to instantiate PackedScene");
-                continue;
+                return wait_for_scene_asset(
+                    asset_server.load_state(handle),
+                    &mut scene.waiting_since,
+                    spawn_config.load_timeout,
+                )
+                .map(|()| None);
             }
+        },
+        GodotSceneResource::Path(path) => ResourceLoader::singleton()
+            .load(&GString::from_str(path).expect("path to be a valid GString"))
+            .ok_or_else(|| GodotSceneSpawnError::ResourceLoadFailed(path.clone()))?,
+    };
+
+    let packed_scene = resource.try_cast::<PackedScene>().map_err(|resource| {
+        GodotSceneSpawnError::NotAPackedScene(resource.get_class().to_string())
+    })?;
+
+    packed_scene
+        .instantiate()
+        .map(Some)
+        .ok_or(GodotSceneSpawnError::InstantiateFailed)
+}
+
+/// Keeps waiting for a scene handle that isn't in `Assets` yet, or returns why to give up on it
+fn wait_for_scene_asset(
+    load_state: LoadState,
+    waiting_since: &mut Option<Instant>,
+    timeout: Option<Duration>,
+) -> Result<(), GodotSceneSpawnError> {
+    if let LoadState::Failed(error) = load_state {
+        return Err(GodotSceneSpawnError::AssetLoadFailed(error.to_string()));
+    }
+
+    match wait_timed_out(waiting_since, timeout) {
+        Some(timeout) => Err(GodotSceneSpawnError::LoadTimedOut(timeout)),
+        None => Ok(()),
+    }
+}
+
+/// Marks the scene's entity as failed, see [`GodotScene`]. The wait for a loading handle starts
+/// over if the spawn is retried
+fn fail_scene_spawn(
+    commands: &mut Commands,
+    failures: &mut EventWriter<GodotSceneSpawnFailed>,
+    entity: Entity,
+    waiting_since: &mut Option<Instant>,
+    reason: GodotSceneSpawnError,
+) {
+    error!("Failed to spawn GodotScene on {entity}: {reason}");
+    *waiting_since = None;
+    commands.entity(entity).insert(reason.clone());
+    failures.write(GodotSceneSpawnFailed { entity, reason });
+}
+
+/// Sent, and triggered on the scene's entity, once every node of a spawned [`GodotScene`]
+/// instance has been mirrored into an entity.
+///
+/// At that point the scene's entity has a [`SceneInstanceMembers`] component to look up the
+/// entities of the instance's nodes.
+///
+/// ```ignore
+/// commands
+///     .spawn(GodotScene::from_handle(assets.enemy_scene.clone()))
+///     .observe(|trigger: Trigger<GodotSceneReady>, members: Query<&SceneInstanceMembers>| {
+///         let sprite = members.get(trigger.root).unwrap().get("Body/Sprite2D");
+///         // ...
+///     });
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GodotSceneReady {
+    /// The entity holding the [`GodotScene`], which mirrors the instance's root node
+    pub root: Entity,
+}
+
+/// The entities mirroring the nodes of a spawned [`GodotScene`] instance, keyed by node path
+/// relative to the instance root (e.g. `"Body/Sprite2D"`). The root itself is not listed.
+///
+/// Inserted on the scene's entity when [`GodotSceneReady`] is sent. Nodes added to or removed
+/// from the instance afterwards are not tracked.
+#[derive(Component, Debug, Default, Clone)]
+pub struct SceneInstanceMembers {
+    members: HashMap<String, Entity>,
+}
+
+impl SceneInstanceMembers {
+    /// The entity mirroring the node at `path`, relative to the instance root
+    pub fn get(&self, path: &str) -> Option<Entity> {
+        self.members.get(path).copied()
+    }
+
+    /// Iterate over all `(node path, entity)` pairs
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Entity)> {
+        self.members
+            .iter()
+            .map(|(path, entity)| (path.as_str(), *entity))
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+}
+
+/// Marks a spawned scene instance whose nodes haven't all been mirrored yet
+#[derive(Component)]
+struct PendingSceneInstance;
+
+#[main_thread_system]
+fn detect_ready_scenes(
+    mut commands: Commands,
+    pending: Query<(Entity, &GodotNodeHandle), With<PendingSceneInstance>>,
+    node_index: Res<GodotNodeIndex>,
+    config: Res<SceneTreeConfig>,
+    mut ready: EventWriter<GodotSceneReady>,
+) {
+    for (root, handle) in pending.iter() {
+        let Some(root_node) = handle.clone().try_get::<Node>() else {
+            commands.entity(root).remove::<PendingSceneInstance>();
+            continue;
         };
 
-        if let Some(transform) = transform {
-            if let Ok(mut node) = instance.clone().try_cast::<Node3D>() {
-                node.set_global_transform(transform.to_godot_transform());
-            } else if let Ok(mut node) = instance.clone().try_cast::<Node2D>() {
-                node.set_global_transform(transform.to_godot_transform_2d());
-            } else {
-                error!(
-                    "attempted to spawn a scene with a transform on Node that did not inherit from Node, the transform was not set"
-                )
+        // Some nodes of the instance haven't been mirrored yet
+        let Some(members) = scene_instance_members(&root_node, &node_index, &config.filter) else {
+            continue;
+        };
+
+        commands
+            .entity(root)
+            .remove::<PendingSceneInstance>()
+            .insert(SceneInstanceMembers { members });
+        ready.write(GodotSceneReady { root });
+        commands.trigger_targets(GodotSceneReady { root }, root);
+    }
+}
+
+/// Maps the paths of all mirrored descendants of `root` to their entities, or returns `None` if
+/// a descendant that should be mirrored has no entity yet
+fn scene_instance_members(
+    root: &Gd<Node>,
+    node_index: &GodotNodeIndex,
+    filter: &SceneTreeFilter,
+) -> Option<HashMap<String, Entity>> {
+    let mut descendants = Vec::new();
+    let mut to_visit = vec![root.clone()];
+
+    while let Some(node) = to_visit.pop() {
+        if filter.skips_children_of(&node) {
+            continue;
+        }
+
+        for child in node.get_children().iter_shared() {
+            if filter.should_mirror(&child) {
+                descendants.push((root.get_path_to(&child).to_string(), child.instance_id()));
             }
+            to_visit.push(child);
+        }
+    }
+
+    members_by_path(descendants, node_index)
+}
+
+/// Maps each `(path, node)` to the node's entity, or returns `None` if any of them has no entity
+/// yet
+fn members_by_path(
+    descendants: impl IntoIterator<Item = (String, InstanceId)>,
+    node_index: &GodotNodeIndex,
+) -> Option<HashMap<String, Entity>> {
+    descendants
+        .into_iter()
+        .map(|(path, instance_id)| Some((path, node_index.entity_for(instance_id)?)))
+        .collect()
+}
+
+/// A to-be-created Godot node of a given class, for nodes that don't come from a scene file.
+///
+/// Entities with a [`GodotNodeSpawn`] get a freshly allocated node of the requested class in
+/// `PostUpdate`, set up from the given properties and init closures, added under the parent
+/// node (or the scene tree root) and attached as a [`GodotNodeHandle`]. The node is named after
+/// the entity's `Name`, if it has one, and placed at its `Transform`.
+///
+/// If the node can't be created, a [`GodotNodeSpawnFailed`] event is sent and the reason is
+/// inserted on the entity as a [`GodotNodeSpawnError`] component. Like for [`GodotScene`], the
+/// [`GodotNodeSpawn`] stays on the entity but isn't spawned while the error is there, remove the
+/// error to try again.
+///
+/// [`SpawnNode`] is the typed builder for this component.
+///
+/// ```ignore
+/// commands.spawn((
+///     GodotNodeSpawn::new("Sprite2D")
+///         .with_property("modulate", Color::from_rgb(1.0, 0.0, 0.0))
+///         .with_resource("texture", assets.marker_texture.clone()),
+///     Transform::from_xyz(100.0, 200.0, 0.0),
+/// ));
+/// ```
+#[derive(Component)]
+pub struct GodotNodeSpawn {
+    class: StringName,
+    properties: Vec<(StringName, NodeProperty)>,
+    init: Vec<NodeInit>,
+    parent: Option<SpawnParent>,
+    /// When the spawn started waiting for its resources or parent node
+    waiting_since: Option<Instant>,
+}
+
+type NodeInit = Box<dyn FnOnce(&mut Gd<Node>) + Send + Sync>;
+
+enum NodeProperty {
+    Value(Box<dyn Fn() -> Variant + Send + Sync>),
+    Resource(Handle<GodotResource>),
+}
+
+enum SpawnParent {
+    Node(GodotNodeHandle),
+    Entity(Entity),
+}
+
+impl GodotNodeSpawn {
+    /// Create a node of the Godot class `class`, e.g. `"Sprite2D"` or a class registered by a
+    /// GDExtension
+    pub fn new(class: &str) -> Self {
+        Self {
+            class: StringName::from(class),
+            properties: Vec::new(),
+            init: Vec::new(),
+            parent: None,
+            waiting_since: None,
         }
+    }
+
+    /// Set a property on the node before it enters the scene tree
+    pub fn with_property<V>(mut self, property: &str, value: V) -> Self
+    where
+        V: ToGodot + Send + Sync + 'static,
+    {
+        self.properties.push((
+            StringName::from(property),
+            NodeProperty::Value(Box::new(move || value.to_variant())),
+        ));
+        self
+    }
+
+    /// Set a property to a Godot resource loaded through Bevy's asset system. The node is not
+    /// created until the resource has loaded, for up to [`GodotSceneSpawnConfig::load_timeout`].
+    pub fn with_resource(mut self, property: &str, handle: Handle<GodotResource>) -> Self {
+        self.properties
+            .push((StringName::from(property), NodeProperty::Resource(handle)));
+        self
+    }
+
+    /// Run `init` on the node after its properties are set, before it enters the scene tree
+    pub fn with_init(mut self, init: impl FnOnce(&mut Gd<Node>) + Send + Sync + 'static) -> Self {
+        self.init.push(Box::new(init));
+        self
+    }
+
+    /// Add the node under `parent` instead of the scene tree root
+    pub fn with_parent(mut self, parent: GodotNodeHandle) -> Self {
+        self.parent = Some(SpawnParent::Node(parent));
+        self
+    }
+
+    /// Add the node under the node of the entity `parent`. If that entity has no node yet, the
+    /// spawn waits for it, for up to [`GodotSceneSpawnConfig::load_timeout`].
+    pub fn with_parent_entity(mut self, parent: Entity) -> Self {
+        self.parent = Some(SpawnParent::Entity(parent));
+        self
+    }
+
+    pub fn class(&self) -> &StringName {
+        &self.class
+    }
+}
+
+impl std::fmt::Debug for GodotNodeSpawn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GodotNodeSpawn")
+            .field("class", &self.class)
+            .field(
+                "properties",
+                &self
+                    .properties
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+/// Typed builder for [`GodotNodeSpawn`], whose init closures receive the node as `Gd<T>`.
+///
+/// ```ignore
+/// commands.spawn((
+///     SpawnNode::<Sprite2D>::new()
+///         .with_resource("texture", assets.marker_texture.clone())
+///         .with_init(|sprite| sprite.set_centered(false)),
+///     Name::new("Debug Marker"),
+/// ));
+/// ```
+#[derive(Bundle)]
+pub struct SpawnNode<T: GodotClass + Inherits<Node>> {
+    spawn: GodotNodeSpawn,
+    #[bundle(ignore)]
+    class: PhantomData<fn() -> T>,
+}
+
+impl<T: GodotClass + Inherits<Node>> SpawnNode<T> {
+    pub fn new() -> Self {
+        Self {
+            spawn: GodotNodeSpawn::new(&T::class_name().to_string()),
+            class: PhantomData,
+        }
+    }
+
+    /// See [`GodotNodeSpawn::with_property`]
+    pub fn with_property<V>(mut self, property: &str, value: V) -> Self
+    where
+        V: ToGodot + Send + Sync + 'static,
+    {
+        self.spawn = self.spawn.with_property(property, value);
+        self
+    }
+
+    /// See [`GodotNodeSpawn::with_resource`]
+    pub fn with_resource(mut self, property: &str, handle: Handle<GodotResource>) -> Self {
+        self.spawn = self.spawn.with_resource(property, handle);
+        self
+    }
+
+    /// See [`GodotNodeSpawn::with_init`]
+    pub fn with_init(mut self, init: impl FnOnce(&mut Gd<T>) + Send + Sync + 'static) -> Self {
+        self.spawn = self
+            .spawn
+            .with_init(move |node| init(&mut node.clone().cast::<T>()));
+        self
+    }
+
+    /// See [`GodotNodeSpawn::with_parent`]
+    pub fn with_parent(mut self, parent: GodotNodeHandle) -> Self {
+        self.spawn = self.spawn.with_parent(parent);
+        self
+    }
+
+    /// See [`GodotNodeSpawn::with_parent_entity`]
+    pub fn with_parent_entity(mut self, parent: Entity) -> Self {
+        self.spawn = self.spawn.with_parent_entity(parent);
+        self
+    }
+}
+
+impl<T: GodotClass + Inherits<Node>> Default for SpawnNode<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        match &mut scene.parent {
-            Some(parent) => {
-                let mut parent = parent.get::<Node>();
-                parent.add_child(&instance);
+impl<T: GodotClass + Inherits<Node>> From<SpawnNode<T>> for GodotNodeSpawn {
+    fn from(spawn: SpawnNode<T>) -> Self {
+        spawn.spawn
+    }
+}
+
+#[main_thread_system]
+fn spawn_node(
+    mut commands: Commands,
+    mut new_nodes: Query<
+        (
+            &mut GodotNodeSpawn,
+            Entity,
+            Option<&Transform>,
+            Option<&ChildOf>,
+            Option<&Name>,
+        ),
+        (Without<GodotNodeHandle>, Without<GodotNodeSpawnError>),
+    >,
+    nodes: Query<&GodotNodeHandle>,
+    mut scene_tree: SceneTreeRef,
+    mut assets: ResMut<Assets<GodotResource>>,
+    asset_server: Res<AssetServer>,
+    config: Res<SceneTreeConfig>,
+    spawn_config: Res<GodotSceneSpawnConfig>,
+    mut failures: EventWriter<GodotNodeSpawnFailed>,
+) {
+    for (spawn, ent, transform, child_of, name) in new_nodes.iter_mut() {
+        let spawn = spawn.into_inner();
+
+        // `None` while the parent entity's node doesn't exist yet
+        let parent = match &spawn.parent {
+            Some(SpawnParent::Node(parent)) => Some(Some(parent.clone())),
+            Some(SpawnParent::Entity(parent)) => match entity_parent_node(nodes.get(*parent)) {
+                Ok(parent) => parent.map(Some),
+                Err(reason) => {
+                    fail_node_spawn(
+                        &mut commands,
+                        &mut failures,
+                        ent,
+                        &mut spawn.waiting_since,
+                        reason,
+                    );
+                    continue;
+                }
+            },
+            // Same as for scenes: with hierarchy write-back on, an entity parent decides
+            None => Some(
+                child_of
+                    .filter(|_| config.sync_hierarchy_to_godot)
+                    .and_then(|child_of| nodes.get(child_of.parent()).ok())
+                    .cloned(),
+            ),
+        };
+
+        // Wait until every resource property has loaded and the parent node exists
+        let resources_loaded = match node_spawn_resources_loaded(spawn, &assets, &asset_server) {
+            Ok(loaded) => loaded,
+            Err(reason) => {
+                fail_node_spawn(
+                    &mut commands,
+                    &mut failures,
+                    ent,
+                    &mut spawn.waiting_since,
+                    reason,
+                );
+                continue;
             }
-            None => {
-                scene_tree.get().get_root().unwrap().add_child(&instance);
+        };
+        let Some(parent) = parent.filter(|_| resources_loaded) else {
+            if let Some(timeout) =
+                wait_timed_out(&mut spawn.waiting_since, spawn_config.load_timeout)
+            {
+                fail_node_spawn(
+                    &mut commands,
+                    &mut failures,
+                    ent,
+                    &mut spawn.waiting_since,
+                    GodotNodeSpawnError::TimedOut(timeout),
+                );
             }
+            continue;
+        };
+
+        let parent = match parent.map(|mut parent| parent.try_get::<Node>()) {
+            Some(None) => {
+                fail_node_spawn(
+                    &mut commands,
+                    &mut failures,
+                    ent,
+                    &mut spawn.waiting_since,
+                    GodotNodeSpawnError::ParentFreed,
+                );
+                continue;
+            }
+            parent => parent.flatten(),
+        };
+
+        // Resolved before creating the node, so a failure doesn't leave an orphan behind
+        let properties = match node_spawn_property_values(spawn, &mut assets) {
+            Ok(properties) => properties,
+            Err(reason) => {
+                fail_node_spawn(
+                    &mut commands,
+                    &mut failures,
+                    ent,
+                    &mut spawn.waiting_since,
+                    reason,
+                );
+                continue;
+            }
+        };
+
+        // `instantiate` creates any class, so check first: anything but a Node would leak
+        let class_db = ClassDb::singleton();
+        let Some(mut instance) = class_db
+            .is_parent_class(&spawn.class, &StringName::from("Node"))
+            .then(|| class_db.instantiate(&spawn.class).try_to::<Gd<Node>>().ok())
+            .flatten()
+        else {
+            fail_node_spawn(
+                &mut commands,
+                &mut failures,
+                ent,
+                &mut spawn.waiting_since,
+                GodotNodeSpawnError::InstantiateFailed(spawn.class.to_string()),
+            );
+            continue;
+        };
+
+        for (property, value) in &properties {
+            instance.set(property, value);
+        }
+
+        for init in std::mem::take(&mut spawn.init) {
+            init(&mut instance);
+        }
+
+        if let Some(transform) = transform {
+            set_spawn_transform(&instance, transform);
         }
 
+        if let Some(name) = name {
+            instance.set_name(name.as_str());
+        }
+
+        add_to_parent(&instance, parent, &mut scene_tree);
+
         commands.entity(ent).insert(GodotNodeHandle::new(instance));
     }
 }
+
+/// The node of a [`GodotNodeSpawn::with_parent_entity`] parent, `Ok(None)` while the entity has
+/// no node yet
+fn entity_parent_node(
+    parent: Result<&GodotNodeHandle, QueryEntityError>,
+) -> Result<Option<GodotNodeHandle>, GodotNodeSpawnError> {
+    match parent {
+        Ok(parent) => Ok(Some(parent.clone())),
+        Err(QueryEntityError::EntityDoesNotExist(_)) => Err(GodotNodeSpawnError::ParentDespawned),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Whether every resource property of `spawn` has loaded, or the error if one failed to load
+fn node_spawn_resources_loaded(
+    spawn: &GodotNodeSpawn,
+    assets: &Assets<GodotResource>,
+    asset_server: &AssetServer,
+) -> Result<bool, GodotNodeSpawnError> {
+    let mut loaded = true;
+    for (_, property) in &spawn.properties {
+        if let NodeProperty::Resource(handle) = property
+            && !assets.contains(handle)
+        {
+            if let LoadState::Failed(error) = asset_server.load_state(handle) {
+                return Err(GodotNodeSpawnError::AssetLoadFailed(error.to_string()));
+            }
+            loaded = false;
+        }
+    }
+    Ok(loaded)
+}
+
+/// The values of the properties of `spawn`, or [`GodotNodeSpawnError::AssetLoadFailed`] if a
+/// resource went missing since [`node_spawn_resources_loaded`]
+fn node_spawn_property_values(
+    spawn: &GodotNodeSpawn,
+    assets: &mut Assets<GodotResource>,
+) -> Result<Vec<(StringName, Variant)>, GodotNodeSpawnError> {
+    spawn
+        .properties
+        .iter()
+        .map(|(property, value)| {
+            let value = match value {
+                NodeProperty::Value(value) => value(),
+                NodeProperty::Resource(handle) => assets
+                    .get_mut(handle)
+                    .ok_or_else(|| {
+                        GodotNodeSpawnError::AssetLoadFailed(format!(
+                            "resource for property {property} was unloaded"
+                        ))
+                    })?
+                    .get()
+                    .to_variant(),
+            };
+            Ok((property.clone(), value))
+        })
+        .collect()
+}
+
+/// Starts the wait on the first call, returns the timeout once it has been waited for
+fn wait_timed_out(
+    waiting_since: &mut Option<Instant>,
+    timeout: Option<Duration>,
+) -> Option<Duration> {
+    let waiting_since = *waiting_since.get_or_insert_with(Instant::now);
+    timeout.filter(|timeout| waiting_since.elapsed() >= *timeout)
+}
+
+/// Marks the node's entity as failed, see [`GodotNodeSpawn`]. The wait for resources and the
+/// parent node starts over if the spawn is retried
+fn fail_node_spawn(
+    commands: &mut Commands,
+    failures: &mut EventWriter<GodotNodeSpawnFailed>,
+    entity: Entity,
+    waiting_since: &mut Option<Instant>,
+    reason: GodotNodeSpawnError,
+) {
+    error!("Failed to spawn GodotNodeSpawn on {entity}: {reason}");
+    *waiting_since = None;
+    commands.entity(entity).insert(reason.clone());
+    failures.write(GodotNodeSpawnFailed { entity, reason });
+}
+
+fn set_spawn_transform(instance: &Gd<Node>, transform: &Transform) {
+    if let Ok(mut node) = instance.clone().try_cast::<Node3D>() {
+        node.set_global_transform(transform.to_godot_transform());
+    } else if let Ok(mut node) = instance.clone().try_cast::<Node2D>() {
+        node.set_global_transform(transform.to_godot_transform_2d());
+    } else {
+        error!(
+            "attempted to spawn a scene with a transform on Node that did not inherit from Node, the transform was not set"
+        )
+    }
+}
+
+fn add_to_parent(instance: &Gd<Node>, parent: Option<Gd<Node>>, scene_tree: &mut SceneTreeRef) {
+    match parent {
+        Some(mut parent) => {
+            parent.add_child(instance);
+        }
+        None => {
+            scene_tree.get().get_root().unwrap().add_child(instance);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::AssetLoadError;
+    use bevy::ecs::{
+        event::Events,
+        schedule::Schedule,
+        system::{Query, RunSystemOnce},
+        world::World,
+    };
+    use std::sync::Arc;
+
+    fn handle(id: i64) -> GodotNodeHandle {
+        GodotNodeHandle::from_instance_id(InstanceId::from_i64(id))
+    }
+
+    fn scene(id: u128) -> Handle<GodotResource> {
+        Handle::weak_from_u128(id)
+    }
+
+    /// Runs `release_returned_instances` once, like `First` does every frame
+    fn release(world: &mut World) {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(release_returned_instances);
+        schedule.run(world);
+    }
+
+    #[test]
+    fn test_pool_reuses_returned_instances_from_the_next_frame() {
+        let mut world = World::new();
+        let bullet = scene(1);
+        let mut pool = GodotScenePool::default();
+        pool.enable(&bullet, 4);
+        world.insert_resource(pool);
+
+        let mut pool = world.resource_mut::<GodotScenePool>();
+        assert!(pool.put(bullet.id(), handle(10)));
+        assert!(pool.put(bullet.id(), handle(11)));
+        assert_eq!(pool.idle_count(&bullet), 2);
+        // Their `NodeRemoved` events haven't been processed yet
+        assert_eq!(pool.take_handle(bullet.id()), None);
+
+        release(&mut world);
+
+        let mut pool = world.resource_mut::<GodotScenePool>();
+        assert_eq!(pool.take_handle(bullet.id()), Some(handle(11)));
+        assert_eq!(pool.take_handle(bullet.id()), Some(handle(10)));
+        assert_eq!(pool.take_handle(bullet.id()), None);
+        assert_eq!(pool.idle_count(&bullet), 0);
+    }
+
+    #[test]
+    fn test_pool_rejects_instances_beyond_capacity() {
+        let mut world = World::new();
+        let bullet = scene(1);
+        let mut pool = GodotScenePool::default();
+        pool.enable(&bullet, 2);
+        world.insert_resource(pool);
+
+        let mut pool = world.resource_mut::<GodotScenePool>();
+        assert!(pool.put(bullet.id(), handle(10)));
+        release(&mut world);
+
+        // Idle and just returned instances both count towards the capacity
+        let mut pool = world.resource_mut::<GodotScenePool>();
+        assert!(pool.put(bullet.id(), handle(11)));
+        assert!(!pool.put(bullet.id(), handle(12)));
+        assert_eq!(pool.idle_count(&bullet), 2);
+
+        // Shrinking the capacity keeps what is already pooled
+        pool.enable(&bullet, 1);
+        assert!(!pool.put(bullet.id(), handle(12)));
+        assert!(pool.take_handle(bullet.id()).is_some());
+        release(&mut world);
+        let mut pool = world.resource_mut::<GodotScenePool>();
+        assert!(pool.take_handle(bullet.id()).is_some());
+    }
+
+    #[test]
+    fn test_pool_ignores_scenes_without_pooling() {
+        let mut pool = GodotScenePool::default();
+        let bullet = scene(1);
+        let other = scene(2);
+        pool.enable(&bullet, 4);
+
+        assert!(pool.is_enabled(&bullet));
+        assert!(!pool.is_enabled(&other));
+        assert!(!pool.put(other.id(), handle(10)));
+        assert_eq!(pool.take_handle(other.id()), None);
+        assert_eq!(pool.idle_count(&other), 0);
+    }
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Health(u32);
+
+    #[derive(Component)]
+    struct Exploding;
+
+    #[test]
+    fn test_pool_resets_reused_entities() {
+        let mut world = World::new();
+        let bullet = scene(1);
+        let other = scene(2);
+        let mut pool = GodotScenePool::default();
+        pool.enable_with_entity_reset(&bullet, 4, |entity| {
+            entity.insert(Health(100)).remove::<Exploding>();
+        });
+        pool.enable(&other, 4);
+
+        let reused = world.spawn((Health(3), Exploding)).id();
+        let not_reset = world.spawn((Health(3), Exploding)).id();
+        pool.reset_entity(bullet.id(), &mut world.commands().entity(reused));
+        pool.reset_entity(other.id(), &mut world.commands().entity(not_reset));
+        world.flush();
+
+        assert_eq!(world.get::<Health>(reused), Some(&Health(100)));
+        assert!(world.get::<Exploding>(reused).is_none());
+        assert_eq!(world.get::<Health>(not_reset), Some(&Health(3)));
+        assert!(world.get::<Exploding>(not_reset).is_some());
+    }
+
+    #[test]
+    fn test_wait_timed_out() {
+        let mut waiting_since = None;
+        assert_eq!(wait_timed_out(&mut waiting_since, None), None);
+        let started = waiting_since.expect("the wait to have started");
+
+        // Later calls keep the start of the wait
+        assert_eq!(
+            wait_timed_out(&mut waiting_since, Some(Duration::from_secs(3600))),
+            None
+        );
+        assert_eq!(waiting_since, Some(started));
+        assert_eq!(
+            wait_timed_out(&mut waiting_since, Some(Duration::ZERO)),
+            Some(Duration::ZERO)
+        );
+    }
+
+    fn world_with_failure_events() -> World {
+        let mut world = World::new();
+        world.init_resource::<Events<GodotSceneSpawnFailed>>();
+        world.init_resource::<Events<GodotNodeSpawnFailed>>();
+        world
+    }
+
+    #[test]
+    fn test_failed_scene_keeps_its_component() {
+        let mut world = world_with_failure_events();
+        let mut scene = GodotScene::from_path("res://missing.tscn");
+        scene.waiting_since = Some(Instant::now());
+        let entity = world.spawn(scene).id();
+
+        let reason = GodotSceneSpawnError::LoadTimedOut(Duration::from_secs(30));
+        let failure = reason.clone();
+        world
+            .run_system_once(
+                move |mut commands: Commands,
+                      mut failures: EventWriter<GodotSceneSpawnFailed>,
+                      mut scenes: Query<&mut GodotScene>| {
+                    let mut scene = scenes.get_mut(entity).unwrap();
+                    fail_scene_spawn(
+                        &mut commands,
+                        &mut failures,
+                        entity,
+                        &mut scene.waiting_since,
+                        failure.clone(),
+                    );
+                },
+            )
+            .unwrap();
+
+        // Kept for a retry, which waits for the handle from scratch
+        let scene = world.get::<GodotScene>(entity).unwrap();
+        assert_eq!(scene.waiting_since, None);
+        assert_eq!(world.get::<GodotSceneSpawnError>(entity), Some(&reason));
+        let events = world.resource::<Events<GodotSceneSpawnFailed>>();
+        let sent = events.iter_current_update_events().collect::<Vec<_>>();
+        assert_eq!(sent.len(), 1);
+        assert_eq!((sent[0].entity, &sent[0].reason), (entity, &reason));
+    }
+
+    #[test]
+    fn test_failed_node_spawn_is_reported() {
+        let mut world = world_with_failure_events();
+        let entity = world.spawn_empty().id();
+
+        let waiting_since = world
+            .run_system_once(
+                move |mut commands: Commands, mut failures: EventWriter<GodotNodeSpawnFailed>| {
+                    let mut waiting_since = Some(Instant::now());
+                    fail_node_spawn(
+                        &mut commands,
+                        &mut failures,
+                        entity,
+                        &mut waiting_since,
+                        GodotNodeSpawnError::ParentDespawned,
+                    );
+                    waiting_since
+                },
+            )
+            .unwrap();
+
+        assert_eq!(waiting_since, None);
+        assert_eq!(
+            world.get::<GodotNodeSpawnError>(entity),
+            Some(&GodotNodeSpawnError::ParentDespawned)
+        );
+        let events = world.resource::<Events<GodotNodeSpawnFailed>>();
+        let sent = events.iter_current_update_events().collect::<Vec<_>>();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].reason, GodotNodeSpawnError::ParentDespawned);
+    }
+
+    #[test]
+    fn test_scene_is_ready_once_every_member_is_mirrored() {
+        let mut index = GodotNodeIndex::default();
+        let body = Entity::from_raw(1);
+        index.insert(InstanceId::from_i64(1), body);
+        let descendants = || {
+            [
+                ("Body".to_string(), InstanceId::from_i64(1)),
+                ("Body/Sprite2D".to_string(), InstanceId::from_i64(2)),
+            ]
+        };
+
+        assert_eq!(members_by_path(descendants(), &index), None);
+
+        let sprite = Entity::from_raw(2);
+        index.insert(InstanceId::from_i64(2), sprite);
+        let members = SceneInstanceMembers {
+            members: members_by_path(descendants(), &index).expect("every member to be mirrored"),
+        };
+        assert_eq!(members.len(), 2);
+        assert_eq!(members.get("Body"), Some(body));
+        assert_eq!(members.get("Body/Sprite2D"), Some(sprite));
+        assert_eq!(members.get("Sprite2D"), None);
+    }
+
+    #[test]
+    fn test_scene_without_members_is_ready() {
+        let members = members_by_path([], &GodotNodeIndex::default());
+        assert_eq!(members, Some(HashMap::new()));
+    }
+
+    #[test]
+    fn test_scene_asset_errors() {
+        let mut waiting_since = None;
+        assert_eq!(
+            wait_for_scene_asset(LoadState::Loading, &mut waiting_since, None),
+            Ok(())
+        );
+        assert!(waiting_since.is_some());
+        assert_eq!(
+            wait_for_scene_asset(LoadState::Loading, &mut waiting_since, Some(Duration::ZERO)),
+            Err(GodotSceneSpawnError::LoadTimedOut(Duration::ZERO))
+        );
+
+        let error = Arc::new(AssetLoadError::AssetMetaReadError);
+        assert_eq!(
+            wait_for_scene_asset(LoadState::Failed(error.clone()), &mut None, None),
+            Err(GodotSceneSpawnError::AssetLoadFailed(error.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_entity_parent_errors() {
+        let mut world = World::new();
+        let with_node = world.spawn(handle(1)).id();
+        let without_node = world.spawn_empty().id();
+        let despawned = world.spawn_empty().id();
+        world.despawn(despawned);
+
+        let mut nodes = world.query::<&GodotNodeHandle>();
+        assert_eq!(
+            entity_parent_node(nodes.get(&world, with_node)),
+            Ok(Some(handle(1)))
+        );
+        // Waits for the node
+        assert_eq!(
+            entity_parent_node(nodes.get(&world, without_node)),
+            Ok(None)
+        );
+        assert_eq!(
+            entity_parent_node(nodes.get(&world, despawned)),
+            Err(GodotNodeSpawnError::ParentDespawned)
+        );
+    }
+}
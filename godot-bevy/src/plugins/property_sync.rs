@@ -0,0 +1,560 @@
+//! Component-to-node property sync: keeps a property of mirrored nodes in step with a component.
+//!
+//! Bindings are registered with [`AppPropertySyncExt::sync_component_property`]. All bindings of
+//! a component type are handled by one write system (in [`GodotSyncSet::WriteProperties`]) and,
+//! if any of them reads from Godot, one read system (in [`GodotSyncSet::ReadProperties`]), so each
+//! changed entity's node is looked up once per frame no matter how many properties it maps.
+//! Values read from Godot are tagged with a sync tick in [`PropertySyncMetadata`] so they are not
+//! written back, like [`TransformSyncMetadata`](super::transforms::TransformSyncMetadata) does
+//! for transforms.
+//!
+//! Godot has no general notification for property changes, so properties registered with
+//! [`AppPropertySyncExt::sync_component_property`] are polled: one `Object::get` per reading
+//! binding per entity, every frame. Bindings registered with
+//! [`AppPropertySyncExt::sync_component_property_on_signal`] are only read from nodes that emitted
+//! the given change signal (e.g. `value_changed`) since the last read.
+
+use bevy::{
+    app::{App, PostUpdate, PreUpdate},
+    ecs::{
+        change_detection::{DetectChanges, Mut, Ref},
+        component::{Component, Tick},
+        entity::Entity,
+        query::{Added, Changed, Or, With, Without},
+        resource::Resource,
+        schedule::IntoScheduleConfigs,
+        system::{Commands, Query, Res, SystemChangeTick},
+    },
+    log::warn,
+};
+use godot::{
+    builtin::{Callable, Variant},
+    classes::Node,
+    obj::{Gd, Inherits, InstanceId},
+};
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use super::core::GodotSyncSet;
+use super::scene_tree::GodotNodeIndex;
+use crate::interop::GodotNodeHandle;
+use crate::prelude::main_thread_system;
+
+/// Which way a property registered with [`AppPropertySyncExt::sync_component_property`] is synced
+pub enum PropertySyncDirection<C> {
+    /// Changes to the component are written to the node
+    BevyToGodot,
+    /// The property is read from Godot, and applied to the component with the given function
+    /// when it differs from the component's value
+    GodotToBevy(fn(&mut C, Variant)),
+    /// Both of the above. Changes read from Godot are not written back
+    TwoWay(fn(&mut C, Variant)),
+}
+
+/// Extension trait for App to sync component values to node properties
+pub trait AppPropertySyncExt {
+    /// Sync `property` of every node of class `N` (or a class inheriting from it) with the
+    /// component `C` of its entity. `to_godot` gives the property value for a component, it is
+    /// also used to tell whether a value read from Godot differs from the component.
+    ///
+    /// Reading directions poll the property of every entity with a `C` every frame. Prefer
+    /// [`sync_component_property_on_signal`](Self::sync_component_property_on_signal) for
+    /// properties with a change signal, or on many entities.
+    ///
+    /// ```ignore
+    /// app.sync_component_property::<Health, ProgressBar>(
+    ///     "value",
+    ///     |health| health.0.to_variant(),
+    ///     PropertySyncDirection::BevyToGodot,
+    /// );
+    /// app.sync_component_property::<Volume, HSlider>(
+    ///     "value",
+    ///     |volume| volume.0.to_variant(),
+    ///     PropertySyncDirection::TwoWay(|volume, value| volume.0 = value.to()),
+    /// );
+    /// ```
+    fn sync_component_property<C, N>(
+        &mut self,
+        property: &str,
+        to_godot: impl Fn(&C) -> Variant + Send + Sync + 'static,
+        direction: PropertySyncDirection<C>,
+    ) -> &mut Self
+    where
+        C: Component,
+        N: Inherits<Node>;
+
+    /// Like [`sync_component_property`](Self::sync_component_property), but the property is only
+    /// read from a node after it emits `signal`, and once when its entity gets a `C`. Writes are
+    /// not affected.
+    ///
+    /// ```ignore
+    /// app.sync_component_property_on_signal::<Volume, HSlider>(
+    ///     "value",
+    ///     "value_changed",
+    ///     |volume| volume.0.to_variant(),
+    ///     PropertySyncDirection::TwoWay(|volume, value| volume.0 = value.to()),
+    /// );
+    /// ```
+    fn sync_component_property_on_signal<C, N>(
+        &mut self,
+        property: &str,
+        signal: &str,
+        to_godot: impl Fn(&C) -> Variant + Send + Sync + 'static,
+        direction: PropertySyncDirection<C>,
+    ) -> &mut Self
+    where
+        C: Component,
+        N: Inherits<Node>;
+}
+
+impl AppPropertySyncExt for App {
+    fn sync_component_property<C, N>(
+        &mut self,
+        property: &str,
+        to_godot: impl Fn(&C) -> Variant + Send + Sync + 'static,
+        direction: PropertySyncDirection<C>,
+    ) -> &mut Self
+    where
+        C: Component,
+        N: Inherits<Node>,
+    {
+        add_property_binding::<C, N>(self, property, None, Box::new(to_godot), direction)
+    }
+
+    fn sync_component_property_on_signal<C, N>(
+        &mut self,
+        property: &str,
+        signal: &str,
+        to_godot: impl Fn(&C) -> Variant + Send + Sync + 'static,
+        direction: PropertySyncDirection<C>,
+    ) -> &mut Self
+    where
+        C: Component,
+        N: Inherits<Node>,
+    {
+        add_property_binding::<C, N>(self, property, Some(signal), Box::new(to_godot), direction)
+    }
+}
+
+fn add_property_binding<C: Component, N: Inherits<Node>>(
+    app: &mut App,
+    property: &str,
+    read_signal: Option<&str>,
+    to_godot: Box<dyn Fn(&C) -> Variant + Send + Sync>,
+    direction: PropertySyncDirection<C>,
+) -> &mut App {
+    // The systems are added with the first binding of a component type and handle all of them
+    if !app.world().contains_resource::<PropertyBindings<C>>() {
+        app.init_resource::<PropertyBindings<C>>()
+            .add_systems(
+                PreUpdate,
+                (
+                    connect_property_signals::<C>.run_if(reads_on_signal::<C>),
+                    read_component_properties::<C>.run_if(reads_from_godot::<C>),
+                )
+                    .chain()
+                    .in_set(GodotSyncSet::ReadProperties),
+            )
+            .add_systems(
+                PostUpdate,
+                write_component_properties::<C>.in_set(GodotSyncSet::WriteProperties),
+            );
+    }
+
+    let (write, from_godot) = direction.split();
+
+    app.world_mut()
+        .resource_mut::<PropertyBindings<C>>()
+        .bindings
+        .push(PropertyBinding {
+            property: property.to_string(),
+            is_target: is_instance_of::<N>,
+            to_godot,
+            from_godot,
+            read_signal: read_signal.map(str::to_string),
+            write,
+        });
+
+    app
+}
+
+impl<C> PropertySyncDirection<C> {
+    /// Whether changes are written to Godot, and how values read from Godot are applied
+    fn split(self) -> (bool, Option<fn(&mut C, Variant)>) {
+        match self {
+            PropertySyncDirection::BevyToGodot => (true, None),
+            PropertySyncDirection::GodotToBevy(from_godot) => (false, Some(from_godot)),
+            PropertySyncDirection::TwoWay(from_godot) => (true, Some(from_godot)),
+        }
+    }
+}
+
+/// Metadata component to tell values read from Godot apart from Bevy changes of `C`
+#[derive(Component)]
+pub struct PropertySyncMetadata<C: Component> {
+    pub last_sync_tick: Option<Tick>,
+    component: PhantomData<fn() -> C>,
+}
+
+impl<C: Component> Default for PropertySyncMetadata<C> {
+    fn default() -> Self {
+        Self {
+            last_sync_tick: None,
+            component: PhantomData,
+        }
+    }
+}
+
+struct PropertyBinding<C> {
+    property: String,
+    is_target: fn(&Gd<Node>) -> bool,
+    to_godot: Box<dyn Fn(&C) -> Variant + Send + Sync>,
+    from_godot: Option<fn(&mut C, Variant)>,
+    /// Only read after the node emits this signal, instead of every frame
+    read_signal: Option<String>,
+    write: bool,
+}
+
+impl<C> PropertyBinding<C> {
+    /// Whether to read the property this frame, `signalled` if the node emitted a change signal
+    fn reads(&self, signalled: bool) -> bool {
+        self.from_godot.is_some() && (signalled || self.read_signal.is_none())
+    }
+}
+
+/// All properties synced with the component `C`
+#[derive(Resource)]
+struct PropertyBindings<C: Component> {
+    bindings: Vec<PropertyBinding<C>>,
+    /// Nodes that emitted the change signal of a binding since the last read
+    signalled: Arc<Mutex<HashSet<InstanceId>>>,
+}
+
+impl<C: Component> Default for PropertyBindings<C> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            signalled: Arc::default(),
+        }
+    }
+}
+
+impl<C: Component> PropertyBindings<C> {
+    /// Whether any binding is read every frame
+    fn polls(&self) -> bool {
+        self.bindings.iter().any(|binding| binding.reads(false))
+    }
+}
+
+/// Marks entities whose node is connected to the change signals of the bindings of `C`
+#[derive(Component)]
+struct PropertySignalsConnected<C: Component>(PhantomData<fn() -> C>);
+
+fn is_instance_of<N: Inherits<Node>>(node: &Gd<Node>) -> bool {
+    node.clone().try_cast::<N>().is_ok()
+}
+
+fn reads_from_godot<C: Component>(bindings: Res<PropertyBindings<C>>) -> bool {
+    bindings
+        .bindings
+        .iter()
+        .any(|binding| binding.from_godot.is_some())
+}
+
+fn reads_on_signal<C: Component>(bindings: Res<PropertyBindings<C>>) -> bool {
+    bindings
+        .bindings
+        .iter()
+        .any(|binding| binding.from_godot.is_some() && binding.read_signal.is_some())
+}
+
+/// Connects the change signals of the bindings of `C` on newly mirrored nodes, and has them read
+/// once
+#[main_thread_system]
+fn connect_property_signals<C: Component>(
+    mut commands: Commands,
+    bindings: Res<PropertyBindings<C>>,
+    entities: Query<(Entity, &GodotNodeHandle), (With<C>, Without<PropertySignalsConnected<C>>)>,
+) {
+    for (entity, handle) in entities.iter() {
+        commands
+            .entity(entity)
+            .insert(PropertySignalsConnected::<C>(PhantomData));
+
+        let Some(mut node) = handle.clone().try_get::<Node>() else {
+            continue;
+        };
+        let instance_id = node.instance_id();
+
+        for binding in &bindings.bindings {
+            let Some(signal) = binding.read_signal.as_deref() else {
+                continue;
+            };
+            if binding.from_godot.is_none() || !(binding.is_target)(&node) {
+                continue;
+            }
+            if !node.has_signal(signal) {
+                warn!(
+                    "Can't read {} on signal: {} has no signal {signal}",
+                    binding.property,
+                    node.get_class()
+                );
+                continue;
+            }
+
+            let signalled = bindings.signalled.clone();
+            let callable = Callable::from_local_fn("property_changed", move |_| {
+                signalled.lock().insert(instance_id);
+                Ok(Variant::nil())
+            });
+            node.connect(signal, &callable);
+        }
+
+        // The node may have changed before it was connected
+        bindings.signalled.lock().insert(instance_id);
+    }
+}
+
+#[main_thread_system]
+fn read_component_properties<C: Component>(
+    mut commands: Commands,
+    bindings: Res<PropertyBindings<C>>,
+    node_index: Res<GodotNodeIndex>,
+    mut entities: Query<(
+        Entity,
+        &mut C,
+        &GodotNodeHandle,
+        Option<&mut PropertySyncMetadata<C>>,
+    )>,
+) {
+    let signalled = std::mem::take(&mut *bindings.signalled.lock());
+
+    if bindings.polls() {
+        for (entity, component, handle, metadata) in entities.iter_mut() {
+            let is_signalled = signalled.contains(&handle.instance_id());
+            read_entity_properties(
+                &mut commands,
+                &bindings,
+                (entity, component, handle, metadata),
+                is_signalled,
+            );
+        }
+    } else {
+        // Only the nodes that emitted a change signal need reading
+        for instance_id in signalled {
+            if let Some(entity) = node_index.entity_for(instance_id)
+                && let Ok(item) = entities.get_mut(entity)
+            {
+                read_entity_properties(&mut commands, &bindings, item, true);
+            }
+        }
+    }
+}
+
+type ReadItem<'a, C> = (
+    Entity,
+    Mut<'a, C>,
+    &'a GodotNodeHandle,
+    Option<Mut<'a, PropertySyncMetadata<C>>>,
+);
+
+fn read_entity_properties<C: Component>(
+    commands: &mut Commands,
+    bindings: &PropertyBindings<C>,
+    (entity, mut component, handle, metadata): ReadItem<'_, C>,
+    signalled: bool,
+) {
+    let Some(node) = handle.clone().try_get::<Node>() else {
+        return;
+    };
+
+    let mut changed = false;
+    for binding in &bindings.bindings {
+        let Some(from_godot) = binding.from_godot.filter(|_| binding.reads(signalled)) else {
+            continue;
+        };
+        if !(binding.is_target)(&node) {
+            continue;
+        }
+
+        let value = node.get(binding.property.as_str());
+        // Compare before writing, so unchanged properties don't trigger change detection
+        if value != (binding.to_godot)(&component) {
+            from_godot(&mut component, value);
+            changed = true;
+        }
+    }
+
+    if changed {
+        let last_sync_tick = Some(component.last_changed());
+        match metadata {
+            Some(mut metadata) => metadata.last_sync_tick = last_sync_tick,
+            None => {
+                commands.entity(entity).insert(PropertySyncMetadata::<C> {
+                    last_sync_tick,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+}
+
+#[main_thread_system]
+fn write_component_properties<C: Component>(
+    change_tick: SystemChangeTick,
+    bindings: Res<PropertyBindings<C>>,
+    entities: Query<
+        (Ref<C>, &GodotNodeHandle, Option<&PropertySyncMetadata<C>>),
+        Or<(Changed<C>, Added<GodotNodeHandle>)>,
+    >,
+) {
+    for (component, handle, metadata) in entities.iter() {
+        // Skip values that were read from Godot
+        if was_read_from_godot(&component, metadata, change_tick.this_run()) {
+            continue;
+        }
+
+        let Some(mut node) = handle.clone().try_get::<Node>() else {
+            continue;
+        };
+
+        for binding in bindings.bindings.iter().filter(|binding| binding.write) {
+            if (binding.is_target)(&node) {
+                node.set(binding.property.as_str(), &(binding.to_godot)(&component));
+            }
+        }
+    }
+}
+
+/// Whether the last change of `component` is the value [`read_component_properties`] read from
+/// Godot, rather than a change made in Bevy since
+fn was_read_from_godot<C: Component>(
+    component: &Ref<C>,
+    metadata: Option<&PropertySyncMetadata<C>>,
+    this_run: Tick,
+) -> bool {
+    metadata
+        .and_then(|metadata| metadata.last_sync_tick)
+        .is_some_and(|sync_tick| !component.last_changed().is_newer_than(sync_tick, this_run))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::{system::RunSystemOnce, world::World};
+    use godot::meta::ToGodot;
+
+    #[derive(Component)]
+    struct Volume(f32);
+
+    fn set_volume(volume: &mut Volume, value: Variant) {
+        volume.0 = value.to();
+    }
+
+    fn binding(
+        direction: PropertySyncDirection<Volume>,
+        signal: Option<&str>,
+    ) -> PropertyBinding<Volume> {
+        let (write, from_godot) = direction.split();
+        PropertyBinding {
+            property: "value".to_string(),
+            is_target: |_| true,
+            to_godot: Box::new(|volume| volume.0.to_variant()),
+            from_godot,
+            read_signal: signal.map(str::to_string),
+            write,
+        }
+    }
+
+    #[test]
+    fn test_directions() {
+        let bevy_to_godot = binding(PropertySyncDirection::BevyToGodot, None);
+        assert!(bevy_to_godot.write);
+        assert!(!bevy_to_godot.reads(false) && !bevy_to_godot.reads(true));
+
+        let godot_to_bevy = binding(PropertySyncDirection::GodotToBevy(set_volume), None);
+        assert!(!godot_to_bevy.write);
+        assert!(godot_to_bevy.reads(false));
+
+        let two_way = binding(PropertySyncDirection::TwoWay(set_volume), None);
+        assert!(two_way.write);
+        assert!(two_way.reads(false));
+    }
+
+    #[test]
+    fn test_signal_bindings_only_read_signalled_nodes() {
+        let on_signal = binding(
+            PropertySyncDirection::TwoWay(set_volume),
+            Some("value_changed"),
+        );
+        assert!(!on_signal.reads(false));
+        assert!(on_signal.reads(true));
+
+        let mut bindings = PropertyBindings::<Volume>::default();
+        bindings.bindings.push(on_signal);
+        bindings
+            .bindings
+            .push(binding(PropertySyncDirection::BevyToGodot, None));
+        assert!(!bindings.polls());
+
+        bindings.bindings.push(binding(
+            PropertySyncDirection::GodotToBevy(set_volume),
+            None,
+        ));
+        assert!(bindings.polls());
+    }
+
+    /// Whether each changed `Volume` was last changed by a read from Godot
+    fn read_from_godot(world: &mut World) -> Vec<bool> {
+        world
+            .run_system_once(
+                |change_tick: SystemChangeTick,
+                 volumes: Query<
+                    (Ref<Volume>, Option<&PropertySyncMetadata<Volume>>),
+                    Changed<Volume>,
+                >| {
+                    volumes
+                        .iter()
+                        .map(|(volume, metadata)| {
+                            was_read_from_godot(&volume, metadata, change_tick.this_run())
+                        })
+                        .collect()
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_values_read_from_godot_are_not_written_back() {
+        let mut world = World::new();
+        world.spawn((Volume(0.0), PropertySyncMetadata::<Volume>::default()));
+
+        // Never read from Godot
+        assert_eq!(read_from_godot(&mut world), [false]);
+
+        // A read, tagged like `read_component_properties` does
+        world
+            .run_system_once(
+                |mut volumes: Query<(&mut Volume, &mut PropertySyncMetadata<Volume>)>| {
+                    for (mut volume, mut metadata) in volumes.iter_mut() {
+                        volume.0 = 0.5;
+                        metadata.last_sync_tick = Some(volume.last_changed());
+                    }
+                },
+            )
+            .unwrap();
+        assert_eq!(read_from_godot(&mut world), [true]);
+
+        // A later Bevy change is written again
+        world
+            .run_system_once(|mut volumes: Query<&mut Volume>| {
+                for mut volume in volumes.iter_mut() {
+                    volume.0 = 1.0;
+                }
+            })
+            .unwrap();
+        assert_eq!(read_from_godot(&mut world), [false]);
+    }
+}
@@ -0,0 +1,146 @@
+//! Rules deciding which Godot nodes are mirrored into entities.
+
+use godot::{classes::Node, obj::Gd};
+
+/// A single node matching rule used by [`SceneTreeFilter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeFilterRule {
+    /// Nodes of this Godot class or any class inheriting from it, e.g. `"Control"`
+    Class(String),
+    /// Nodes in this group
+    Group(String),
+    /// The node at this absolute path and everything below it, e.g. `"/root/BevyAppSingleton"`
+    PathPrefix(String),
+    /// Nodes that have this metadata key set
+    Metadata(String),
+}
+
+impl NodeFilterRule {
+    pub fn class(class: impl Into<String>) -> Self {
+        Self::Class(class.into())
+    }
+
+    pub fn group(group: impl Into<String>) -> Self {
+        Self::Group(group.into())
+    }
+
+    pub fn path_prefix(path: impl Into<String>) -> Self {
+        Self::PathPrefix(path.into())
+    }
+
+    pub fn metadata(key: impl Into<String>) -> Self {
+        Self::Metadata(key.into())
+    }
+
+    /// Returns true if `node` matches this rule
+    pub fn matches(&self, node: &Gd<Node>) -> bool {
+        match self {
+            Self::Class(class) => node.is_class(class.as_str()),
+            Self::Group(group) => node.is_in_group(group.as_str()),
+            Self::PathPrefix(prefix) => path_has_prefix(&node.get_path().to_string(), prefix),
+            Self::Metadata(key) => node.has_meta(key.as_str()),
+        }
+    }
+}
+
+/// Matches whole path segments, so `/root/Level` covers `/root/Level/Player` but not
+/// `/root/Level2`
+fn path_has_prefix(path: &str, prefix: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Include/exclude rules applied by the scene tree plugin before a node is mirrored into an
+/// entity. Filtered nodes get no entity, no marker components and no collision wiring.
+///
+/// The default filter mirrors every node.
+///
+/// ```ignore
+/// SceneTreeFilter::default()
+///     // Don't mirror the watcher nodes godot-bevy itself adds
+///     .exclude(NodeFilterRule::path_prefix("/root/BevyAppSingleton"))
+///     // Mirror UI containers, but none of the widgets inside them
+///     .skip_children_of(NodeFilterRule::class("Container"))
+///     // Let level designers opt subtrees out from the editor
+///     .skip_children_of(NodeFilterRule::group("bevy_skip_children"))
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SceneTreeFilter {
+    /// When not empty, only nodes matching at least one of these rules are mirrored
+    pub include: Vec<NodeFilterRule>,
+    /// Nodes matching any of these rules are not mirrored. Class, group and metadata rules only
+    /// apply to the matching node itself, not to its children
+    pub exclude: Vec<NodeFilterRule>,
+    /// Descendants of nodes matching any of these rules are not mirrored. The matching node
+    /// itself is still subject to `include` and `exclude`
+    pub skip_children_of: Vec<NodeFilterRule>,
+}
+
+impl SceneTreeFilter {
+    pub fn include(mut self, rule: NodeFilterRule) -> Self {
+        self.include.push(rule);
+        self
+    }
+
+    pub fn exclude(mut self, rule: NodeFilterRule) -> Self {
+        self.exclude.push(rule);
+        self
+    }
+
+    pub fn skip_children_of(mut self, rule: NodeFilterRule) -> Self {
+        self.skip_children_of.push(rule);
+        self
+    }
+
+    /// Returns true if the filter mirrors every node
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty() && self.skip_children_of.is_empty()
+    }
+
+    /// Returns true if `node` should be mirrored into an entity
+    pub fn should_mirror(&self, node: &Gd<Node>) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+
+        if !self.include.is_empty() && !self.include.iter().any(|rule| rule.matches(node)) {
+            return false;
+        }
+
+        if self.exclude.iter().any(|rule| rule.matches(node)) {
+            return false;
+        }
+
+        if !self.skip_children_of.is_empty() {
+            let mut ancestor = node.get_parent();
+            while let Some(parent) = ancestor {
+                if self.skips_children_of(&parent) {
+                    return false;
+                }
+                ancestor = parent.get_parent();
+            }
+        }
+
+        true
+    }
+
+    /// Returns true if the descendants of `node` should not be mirrored
+    pub fn skips_children_of(&self, node: &Gd<Node>) -> bool {
+        self.skip_children_of.iter().any(|rule| rule.matches(node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_has_prefix_matches_whole_segments() {
+        assert!(path_has_prefix("/root/Level", "/root/Level"));
+        assert!(path_has_prefix("/root/Level/Player", "/root/Level"));
+        assert!(path_has_prefix("/root/Level/Player", "/root/Level/"));
+        assert!(!path_has_prefix("/root/Level2", "/root/Level"));
+        assert!(!path_has_prefix("/root", "/root/Level"));
+    }
+}
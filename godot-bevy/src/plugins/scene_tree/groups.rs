@@ -0,0 +1,281 @@
+//! Godot group membership of mirrored nodes, kept in sync in both directions.
+
+use bevy::{
+    app::{App, First, PostUpdate},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        observer::Trigger,
+        query::{Changed, Has},
+        schedule::IntoScheduleConfigs,
+        system::{Commands, Query, Res},
+        world::{OnInsert, OnReplace},
+    },
+};
+use godot::{
+    classes::Node,
+    obj::{Gd, Inherits},
+};
+use std::marker::PhantomData;
+
+use super::SceneTreeConfig;
+use crate::interop::GodotNodeHandle;
+use crate::plugins::core::GodotSyncSet;
+use crate::prelude::main_thread_system;
+
+/// The groups a node belongs to.
+///
+/// Groups added or removed with [`Groups::add`] and [`Groups::remove`] are written to the node
+/// during `PostUpdate`. Changes made on the Godot side (`add_to_group`, `remove_from_group`) are
+/// picked up each frame when [`SceneTreeConfig::sync_groups_from_godot`] is enabled.
+#[derive(Component, Debug, Default)]
+pub struct Groups {
+    groups: Vec<String>,
+    /// Changes made through `add`/`remove` that haven't been written to the node yet
+    pending: Vec<GroupChange>,
+}
+
+#[derive(Debug, Clone)]
+enum GroupChange {
+    Add(String),
+    Remove(String),
+}
+
+impl Groups {
+    pub fn is(&self, group_name: &str) -> bool {
+        self.groups.iter().any(|name| name == group_name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.groups.iter().map(String::as_str)
+    }
+
+    /// Adds the node to `group_name`. Returns false if it already was in the group
+    pub fn add(&mut self, group_name: impl Into<String>) -> bool {
+        let group_name = group_name.into();
+        if self.is(&group_name) {
+            return false;
+        }
+
+        self.groups.push(group_name.clone());
+        self.pending.push(GroupChange::Add(group_name));
+        true
+    }
+
+    /// Removes the node from `group_name`. Returns false if it wasn't in the group
+    pub fn remove(&mut self, group_name: &str) -> bool {
+        let Some(index) = self.groups.iter().position(|name| name == group_name) else {
+            return false;
+        };
+
+        self.groups.remove(index);
+        self.pending
+            .push(GroupChange::Remove(group_name.to_string()));
+        true
+    }
+
+    /// Replaces the group list with the one read from Godot, keeping changes that haven't been
+    /// written back yet
+    fn set_from_godot(&mut self, mut groups: Vec<String>) {
+        for change in &self.pending {
+            match change {
+                GroupChange::Add(name) if !groups.contains(name) => groups.push(name.clone()),
+                GroupChange::Remove(name) => groups.retain(|group| group != name),
+                _ => {}
+            }
+        }
+        self.groups = groups;
+    }
+}
+
+impl<T: Inherits<Node>> From<&Gd<T>> for Groups {
+    fn from(node: &Gd<T>) -> Self {
+        Groups {
+            groups: node_groups(&node.clone().upcast::<Node>()),
+            pending: Vec::new(),
+        }
+    }
+}
+
+fn node_groups(node: &Gd<Node>) -> Vec<String> {
+    node.get_groups()
+        .iter_shared()
+        .map(|variant| variant.to_string())
+        .collect()
+}
+
+pub(crate) fn groups_sync_enabled(config: Res<SceneTreeConfig>) -> bool {
+    config.sync_groups_from_godot
+}
+
+#[main_thread_system]
+pub(crate) fn read_groups_from_godot(mut entities: Query<(&GodotNodeHandle, &mut Groups)>) {
+    for (handle, mut groups) in entities.iter_mut() {
+        let Some(node) = handle.clone().try_get::<Node>() else {
+            continue;
+        };
+
+        // Godot doesn't guarantee any group order, compare as sets
+        let node_groups = node_groups(&node);
+        let unchanged = node_groups.len() == groups.groups.len()
+            && node_groups.iter().all(|name| groups.is(name));
+        if unchanged && groups.pending.is_empty() {
+            continue;
+        }
+
+        groups.set_from_godot(node_groups);
+    }
+}
+
+#[main_thread_system]
+pub(crate) fn write_groups_to_godot(
+    mut entities: Query<(&GodotNodeHandle, &mut Groups), Changed<Groups>>,
+) {
+    for (handle, mut groups) in entities.iter_mut() {
+        if groups.pending.is_empty() {
+            continue;
+        }
+
+        let pending = std::mem::take(&mut groups.bypass_change_detection().pending);
+        let Some(mut node) = handle.clone().try_get::<Node>() else {
+            continue;
+        };
+
+        for change in pending {
+            match change {
+                GroupChange::Add(name) => node.add_to_group(&name),
+                GroupChange::Remove(name) => node.remove_from_group(&name),
+            }
+        }
+    }
+}
+
+/// A Godot group that gets an [`InGroup`] marker component, registered with
+/// [`AppGroupsExt::register_godot_group`].
+///
+/// ```ignore
+/// struct Enemies;
+///
+/// impl GodotGroup for Enemies {
+///     const NAME: &'static str = "enemies";
+/// }
+///
+/// app.register_godot_group::<Enemies>();
+///
+/// fn chase(enemies: Query<&mut Transform, With<InGroup<Enemies>>>) {
+///     // ...
+/// }
+/// ```
+pub trait GodotGroup: Send + Sync + 'static {
+    /// The group name as used in Godot
+    const NAME: &'static str;
+}
+
+/// Marker for entities whose node is in the group `G`, kept in sync with [`Groups`]
+#[derive(Component)]
+pub struct InGroup<G: GodotGroup>(PhantomData<G>);
+
+impl<G: GodotGroup> Default for InGroup<G> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<G: GodotGroup> std::fmt::Debug for InGroup<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "InGroup({})", G::NAME)
+    }
+}
+
+/// Extension trait for App to register [`GodotGroup`] markers
+pub trait AppGroupsExt {
+    /// Keep an [`InGroup<G>`] marker on every entity whose [`Groups`] contain `G::NAME`
+    fn register_godot_group<G: GodotGroup>(&mut self) -> &mut Self;
+}
+
+impl AppGroupsExt for App {
+    fn register_godot_group<G: GodotGroup>(&mut self) -> &mut Self {
+        self.add_observer(mark_inserted_groups::<G>)
+            .add_observer(unmark_replaced_groups::<G>)
+            .add_systems(
+                First,
+                sync_group_markers::<G>.after(GodotSyncSet::ReadSceneTree),
+            )
+            .add_systems(PostUpdate, sync_group_markers::<G>)
+    }
+}
+
+fn mark_inserted_groups<G: GodotGroup>(
+    trigger: Trigger<OnInsert, Groups>,
+    groups: Query<&Groups>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    if let Ok(groups) = groups.get(entity)
+        && groups.is(G::NAME)
+    {
+        commands.entity(entity).insert(InGroup::<G>::default());
+    }
+}
+
+fn unmark_replaced_groups<G: GodotGroup>(
+    trigger: Trigger<OnReplace, Groups>,
+    mut commands: Commands,
+) {
+    commands.entity(trigger.target()).try_remove::<InGroup<G>>();
+}
+
+fn sync_group_markers<G: GodotGroup>(
+    mut commands: Commands,
+    entities: Query<(Entity, &Groups, Has<InGroup<G>>), Changed<Groups>>,
+) {
+    for (entity, groups, has_marker) in entities.iter() {
+        match (groups.is(G::NAME), has_marker) {
+            (true, false) => {
+                commands.entity(entity).insert(InGroup::<G>::default());
+            }
+            (false, true) => {
+                commands.entity(entity).remove::<InGroup<G>>();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groups(names: &[&str]) -> Groups {
+        Groups {
+            groups: names.iter().map(|name| name.to_string()).collect(),
+            pending: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_record_pending_changes() {
+        let mut groups = groups(&["enemies"]);
+
+        assert!(!groups.add("enemies"));
+        assert!(groups.add("flying"));
+        assert!(groups.remove("enemies"));
+        assert!(!groups.remove("enemies"));
+
+        assert_eq!(groups.iter().collect::<Vec<_>>(), ["flying"]);
+        assert_eq!(groups.pending.len(), 2);
+    }
+
+    #[test]
+    fn test_set_from_godot_keeps_pending_changes() {
+        let mut groups = groups(&["enemies"]);
+        groups.add("flying");
+        groups.remove("enemies");
+
+        groups.set_from_godot(vec!["enemies".to_string(), "bosses".to_string()]);
+
+        assert!(groups.is("bosses"));
+        assert!(groups.is("flying"));
+        assert!(!groups.is("enemies"));
+    }
+}
@@ -0,0 +1,143 @@
+//! Bevy-to-Godot hierarchy write-back: changing an entity's `ChildOf` reparents its node.
+//!
+//! Enabled with [`SceneTreeConfig::sync_hierarchy_to_godot`]. Hierarchy changes that originate
+//! in Godot are mirrored into `ChildOf` by the scene tree plugin and tagged with a sync tick in
+//! [`HierarchySyncMetadata`], the same way [`TransformSyncMetadata`] tags transforms read from
+//! Godot, so they are not written back.
+//!
+//! [`TransformSyncMetadata`]: crate::plugins::transforms::TransformSyncMetadata
+
+use bevy::ecs::{
+    change_detection::{DetectChanges, Ref},
+    component::{Component, Tick},
+    hierarchy::ChildOf,
+    query::{Added, Changed, Or, Without},
+    removal_detection::RemovedComponents,
+    system::{Query, Res, SystemChangeTick},
+};
+use godot::{classes::Node, obj::Gd};
+use tracing::warn;
+
+use super::sync_metadata::GodotSyncMetadata;
+use super::{GodotNodeIndex, SceneTreeConfig, SceneTreeRef};
+use crate::interop::GodotNodeHandle;
+use crate::prelude::main_thread_system;
+
+/// Metadata component to tell Godot-originated `ChildOf` changes apart from Bevy ones
+#[derive(Component, Default)]
+pub struct HierarchySyncMetadata {
+    pub last_sync_tick: Option<Tick>,
+}
+
+impl GodotSyncMetadata for HierarchySyncMetadata {
+    fn synced_at(tick: Tick) -> Self {
+        Self {
+            last_sync_tick: Some(tick),
+        }
+    }
+}
+
+pub(crate) fn hierarchy_sync_enabled(config: Res<SceneTreeConfig>) -> bool {
+    config.sync_hierarchy_to_godot
+}
+
+#[main_thread_system]
+pub(crate) fn write_hierarchy_to_godot(
+    change_tick: SystemChangeTick,
+    reparented: Query<
+        (
+            Ref<ChildOf>,
+            &GodotNodeHandle,
+            Option<&HierarchySyncMetadata>,
+        ),
+        Or<(Changed<ChildOf>, Added<GodotNodeHandle>)>,
+    >,
+    mut detached: RemovedComponents<ChildOf>,
+    detached_nodes: Query<&GodotNodeHandle, Without<ChildOf>>,
+    nodes: Query<&GodotNodeHandle>,
+    node_index: Res<GodotNodeIndex>,
+    config: Res<SceneTreeConfig>,
+    mut scene_tree: SceneTreeRef,
+) {
+    for (child_of, handle, metadata) in reparented.iter() {
+        // Skip hierarchy changes that were synced from Godot
+        if let Some(sync_tick) = metadata.and_then(|metadata| metadata.last_sync_tick)
+            && !child_of
+                .last_changed()
+                .is_newer_than(sync_tick, change_tick.this_run())
+        {
+            continue;
+        }
+
+        // Parents without a node (pure ECS entities) have nothing to mirror
+        let Ok(parent_handle) = nodes.get(child_of.parent()) else {
+            continue;
+        };
+
+        let (Some(mut node), Some(new_parent)) = (
+            handle.clone().try_get::<Node>(),
+            parent_handle.clone().try_get::<Node>(),
+        ) else {
+            continue;
+        };
+
+        reparent_node(
+            &mut node,
+            &new_parent,
+            config.keep_global_transform_on_reparent,
+        );
+    }
+
+    for entity in detached.read() {
+        // Despawned entities and entities whose `ChildOf` was replaced rather than removed are
+        // not matched here
+        let Ok(handle) = detached_nodes.get(entity) else {
+            continue;
+        };
+
+        let Some(mut node) = handle.clone().try_get::<Node>() else {
+            continue;
+        };
+
+        // Godot-side moves to an unmirrored parent remove `ChildOf` too; nothing to write back
+        if let Some(parent) = node.get_parent()
+            && !node_index.contains(parent.instance_id())
+        {
+            continue;
+        }
+
+        let Some(root) = scene_tree.get().get_root() else {
+            continue;
+        };
+
+        reparent_node(
+            &mut node,
+            &root.upcast::<Node>(),
+            config.keep_global_transform_on_reparent,
+        );
+    }
+}
+
+fn reparent_node(node: &mut Gd<Node>, new_parent: &Gd<Node>, keep_global_transform: bool) {
+    let current_parent = node.get_parent();
+    if current_parent.as_ref() == Some(new_parent) {
+        return;
+    }
+
+    if *node == *new_parent || node.is_ancestor_of(new_parent) {
+        warn!(
+            "Not reparenting node {} under its own descendant {}",
+            node.get_path(),
+            new_parent.get_path()
+        );
+        return;
+    }
+
+    if current_parent.is_some() {
+        node.reparent_ex(new_parent)
+            .keep_global_transform(keep_global_transform)
+            .done();
+    } else {
+        new_parent.clone().add_child(&*node);
+    }
+}
@@ -1,13 +1,26 @@
 pub mod autosync;
+pub mod filter;
+pub mod groups;
+pub mod hierarchy_sync;
+pub mod name_sync;
+pub mod node_index;
 pub mod node_type_checking_generated;
 pub mod plugin;
+pub mod script_class;
+pub mod sync_metadata;
 
 // Re-export main components
 pub use autosync::{
     AutoSyncBundleRegistry, BundleCreatorFn, register_all_autosync_bundles,
     try_add_bundles_for_node,
 };
+pub use filter::{NodeFilterRule, SceneTreeFilter};
+pub use groups::{AppGroupsExt, GodotGroup, Groups, InGroup};
+pub use hierarchy_sync::HierarchySyncMetadata;
+pub use name_sync::NameSyncMetadata;
+pub use node_index::GodotNodeIndex;
 pub use plugin::{
-    GodotSceneTreePlugin, Groups, SceneTreeConfig, SceneTreeEvent, SceneTreeEventReader,
-    SceneTreeEventType, SceneTreeRef,
+    GodotSceneTreePlugin, NodeDespawnPolicy, NodeReparented, SceneTreeConfig, SceneTreeEvent,
+    SceneTreeEventReader, SceneTreeEventType, SceneTreeRef,
 };
+pub use script_class::GodotScriptClass;
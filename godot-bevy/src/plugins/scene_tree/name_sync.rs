@@ -0,0 +1,71 @@
+//! Bevy-to-Godot name write-back: changing an entity's `Name` renames its node.
+//!
+//! Enabled with [`SceneTreeConfig::sync_names_to_godot`]. Names that come from Godot (when a node
+//! is mirrored or renamed) are tagged with a sync tick in [`NameSyncMetadata`] so they are not
+//! written back.
+
+use bevy::ecs::{
+    change_detection::DetectChanges,
+    component::{Component, Tick},
+    name::Name,
+    query::Changed,
+    system::{Query, Res, SystemChangeTick},
+};
+use godot::classes::Node;
+
+use super::SceneTreeConfig;
+use super::sync_metadata::GodotSyncMetadata;
+use crate::interop::GodotNodeHandle;
+use crate::prelude::main_thread_system;
+
+/// Metadata component to tell Godot-originated `Name` changes apart from Bevy ones
+#[derive(Component, Default)]
+pub struct NameSyncMetadata {
+    pub last_sync_tick: Option<Tick>,
+}
+
+impl GodotSyncMetadata for NameSyncMetadata {
+    fn synced_at(tick: Tick) -> Self {
+        Self {
+            last_sync_tick: Some(tick),
+        }
+    }
+}
+
+pub(crate) fn name_sync_enabled(config: Res<SceneTreeConfig>) -> bool {
+    config.sync_names_to_godot
+}
+
+#[main_thread_system]
+pub(crate) fn write_names_to_godot(
+    change_tick: SystemChangeTick,
+    mut renamed: Query<(&mut Name, &GodotNodeHandle, Option<&NameSyncMetadata>), Changed<Name>>,
+) {
+    for (mut name, handle, metadata) in renamed.iter_mut() {
+        // Skip names that were synced from Godot
+        if let Some(sync_tick) = metadata.and_then(|metadata| metadata.last_sync_tick)
+            && !name
+                .last_changed()
+                .is_newer_than(sync_tick, change_tick.this_run())
+        {
+            continue;
+        }
+
+        let Some(mut node) = handle.clone().try_get::<Node>() else {
+            continue;
+        };
+
+        if node.get_name().to_string() == name.as_str() {
+            continue;
+        }
+
+        node.set_name(name.as_str());
+
+        // Godot replaces characters that aren't allowed in node names and makes names unique
+        // among siblings, report what it settled on
+        let node_name = node.get_name().to_string();
+        if node_name != name.as_str() {
+            name.set(node_name);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Persistent lookup from Godot node instance ids to the entities that mirror them.
+
+use bevy::ecs::{
+    entity::Entity,
+    observer::Trigger,
+    system::{Query, ResMut},
+    world::{OnInsert, OnReplace},
+};
+use bevy::prelude::Resource;
+use godot::{
+    classes::Node,
+    obj::{Gd, Inherits, InstanceId},
+};
+use std::collections::HashMap;
+
+use crate::interop::GodotNodeHandle;
+
+/// Maps Godot node instance ids to the entities that hold their [`GodotNodeHandle`].
+///
+/// The index is maintained by the [`GodotSceneTreePlugin`](super::GodotSceneTreePlugin): it is
+/// updated whenever a node is mirrored or removed, and whenever a [`GodotNodeHandle`] is inserted
+/// onto or removed from an entity (which also covers `GodotScene` spawns and despawned entities).
+/// Lookups are O(1), so prefer this over scanning every `GodotNodeHandle` in a query.
+///
+/// ```ignore
+/// fn on_signal(mut events: EventReader<GodotSignal>, index: Res<GodotNodeIndex>) {
+///     for signal in events.read() {
+///         if let Some(entity) = index.entity_for(signal.origin.instance_id()) {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct GodotNodeIndex {
+    entities: HashMap<InstanceId, Entity>,
+}
+
+impl GodotNodeIndex {
+    /// Returns the entity mirroring the node with the given instance id, if any.
+    #[inline]
+    pub fn entity_for(&self, instance_id: InstanceId) -> Option<Entity> {
+        self.entities.get(&instance_id).copied()
+    }
+
+    /// Returns the entity mirroring the given node, if any.
+    #[inline]
+    pub fn entity_for_node<T: Inherits<Node>>(&self, node: &Gd<T>) -> Option<Entity> {
+        self.entity_for(node.instance_id())
+    }
+
+    /// Returns the entity holding a handle to the same node as `handle`, if any.
+    #[inline]
+    pub fn entity_for_handle(&self, handle: &GodotNodeHandle) -> Option<Entity> {
+        self.entity_for(handle.instance_id())
+    }
+
+    /// Returns true if the node with the given instance id is mirrored by an entity.
+    #[inline]
+    pub fn contains(&self, instance_id: InstanceId) -> bool {
+        self.entities.contains_key(&instance_id)
+    }
+
+    /// Number of indexed nodes.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Iterate over all `(instance id, entity)` pairs in the index.
+    pub fn iter(&self) -> impl Iterator<Item = (InstanceId, Entity)> + '_ {
+        self.entities.iter().map(|(id, ent)| (*id, *ent))
+    }
+
+    pub(crate) fn insert(&mut self, instance_id: InstanceId, entity: Entity) {
+        self.entities.insert(instance_id, entity);
+    }
+
+    pub(crate) fn remove(&mut self, instance_id: InstanceId) -> Option<Entity> {
+        self.entities.remove(&instance_id)
+    }
+
+    /// Removes the mapping for `instance_id` only if it still points at `entity`, so a stale
+    /// removal can't clobber a newer association of the same node with another entity.
+    pub(crate) fn remove_if(&mut self, instance_id: InstanceId, entity: Entity) {
+        if self.entity_for(instance_id) == Some(entity) {
+            self.entities.remove(&instance_id);
+        }
+    }
+}
+
+pub(crate) fn index_inserted_node_handle(
+    trigger: Trigger<OnInsert, GodotNodeHandle>,
+    handles: Query<&GodotNodeHandle>,
+    mut index: ResMut<GodotNodeIndex>,
+) {
+    let entity = trigger.target();
+    if let Ok(handle) = handles.get(entity) {
+        index.insert(handle.instance_id(), entity);
+    }
+}
+
+pub(crate) fn unindex_replaced_node_handle(
+    trigger: Trigger<OnReplace, GodotNodeHandle>,
+    handles: Query<&GodotNodeHandle>,
+    mut index: ResMut<GodotNodeIndex>,
+) {
+    let entity = trigger.target();
+    if let Ok(handle) = handles.get(entity) {
+        index.remove_if(handle.instance_id(), entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::world::World;
+
+    fn world_with_index() -> World {
+        let mut world = World::new();
+        world.init_resource::<GodotNodeIndex>();
+        world.add_observer(index_inserted_node_handle);
+        world.add_observer(unindex_replaced_node_handle);
+        world
+    }
+
+    fn handle(id: i64) -> GodotNodeHandle {
+        GodotNodeHandle::from_instance_id(InstanceId::from_i64(id))
+    }
+
+    #[test]
+    fn test_index_tracks_inserted_handles() {
+        let mut world = world_with_index();
+        let entity = world.spawn(handle(1)).id();
+
+        let index = world.resource::<GodotNodeIndex>();
+        assert_eq!(index.entity_for(InstanceId::from_i64(1)), Some(entity));
+        assert_eq!(index.entity_for_handle(&handle(1)), Some(entity));
+        assert_eq!(index.entity_for(InstanceId::from_i64(2)), None);
+    }
+
+    #[test]
+    fn test_index_forgets_despawned_and_stripped_entities() {
+        let mut world = world_with_index();
+        let despawned = world.spawn(handle(1)).id();
+        let stripped = world.spawn(handle(2)).id();
+
+        world.despawn(despawned);
+        world.entity_mut(stripped).remove::<GodotNodeHandle>();
+
+        assert!(world.resource::<GodotNodeIndex>().is_empty());
+    }
+
+    #[test]
+    fn test_index_keeps_newer_mapping_on_stale_removal() {
+        let mut world = world_with_index();
+        let old = world.spawn(handle(1)).id();
+        let new = world.spawn(handle(1)).id();
+
+        world.despawn(old);
+
+        let index = world.resource::<GodotNodeIndex>();
+        assert_eq!(index.entity_for(InstanceId::from_i64(1)), Some(new));
+    }
+}
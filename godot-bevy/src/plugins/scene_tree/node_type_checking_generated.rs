@@ -1,986 +1,6742 @@
 // 🤖 This file is automatically generated by scripts/generate_godot_types.py
 // To regenerate: python scripts/generate_godot_types.py
 
-use crate::interop::{GodotNodeHandle, node_markers::*};
-use bevy::ecs::system::EntityCommands;
+use std::{cell::RefCell, collections::HashMap};
 
-/// Adds appropriate marker components to an entity based on the Godot node type.
-/// This function is automatically generated and handles all 200 Godot node types.
-///
-/// Godot's hierarchy: Node -> {Node3D, CanvasItem -> {Node2D, Control}, Others}
-/// We check the major branches: 3D, 2D, Control (UI), and Universal (direct Node children)
-pub fn add_comprehensive_node_type_markers(
-    entity_commands: &mut EntityCommands,
-    node: &mut GodotNodeHandle,
-) {
-    // All nodes inherit from Node, so add this first
-    entity_commands.insert(NodeMarker);
-
-    // Check the major hierarchy branches to minimize FFI calls
-    if node.try_get::<godot::classes::Node3D>().is_some() {
-        entity_commands.insert(Node3DMarker);
-        check_3d_node_types_comprehensive(entity_commands, node);
-    } else if node.try_get::<godot::classes::Node2D>().is_some() {
-        entity_commands.insert(Node2DMarker);
-        entity_commands.insert(CanvasItemMarker); // Node2D inherits from CanvasItem
-        check_2d_node_types_comprehensive(entity_commands, node);
-    } else if node.try_get::<godot::classes::Control>().is_some() {
-        entity_commands.insert(ControlMarker);
-        entity_commands.insert(CanvasItemMarker); // Control inherits from CanvasItem
-        check_control_node_types_comprehensive(entity_commands, node);
-    }
+use crate::interop::{GodotNodeHandle, TypedNodeHandle, node_markers::*};
+use bevy::ecs::{
+    component::Component, error::ignore, system::EntityCommands, world::EntityWorldMut,
+};
+use godot::{
+    classes::{self as gd, ClassDb, Node},
+    obj::InstanceId,
+};
 
-    // Check node types that inherit directly from Node
-    check_universal_node_types_comprehensive(entity_commands, node);
+/// The engine class whose marker components and typed handles were added to this entity.
+///
+/// Used to remove exactly those components again when the node leaves the scene tree.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeTypeMarkers {
+    pub class: &'static str,
 }
 
-pub fn remove_comprehensive_node_type_markers(entity_commands: &mut EntityCommands) {
-    // All nodes inherit from Node, so remove this first
-    entity_commands.remove::<NodeMarker>();
-
-    entity_commands.remove::<Node3DMarker>();
-    remove_3d_node_types_comprehensive(entity_commands);
-
-    entity_commands.remove::<Node2DMarker>();
-    entity_commands.remove::<CanvasItemMarker>(); // Node2D inherits from CanvasItem
-    remove_2d_node_types_comprehensive(entity_commands);
-
-    entity_commands.remove::<ControlMarker>();
-    remove_control_node_types_comprehensive(entity_commands);
-
-    remove_universal_node_types_comprehensive(entity_commands);
+thread_local! {
+    /// Nearest engine base class of classes missing from the generated table (GDExtension
+    /// classes, engine classes newer than the table), looked up through ClassDB once per class
+    static RESOLVED_CLASSES: RefCell<HashMap<String, &'static str>> = RefCell::new(HashMap::new());
 }
 
-fn check_3d_node_types_comprehensive(
+/// Adds marker components and [`TypedNodeHandle`]s to an entity based on the Godot node type.
+///
+/// The node's class is read with a single `get_class()` call and the markers and typed handles
+/// for the class and all of its base classes are inserted as one bundle. The inheritance chains of the
+/// 247 Godot node types come from a table generated from Godot's extension API;
+/// any other class (e.g. a GDExtension class) gets the markers of its nearest engine base class.
+pub fn add_comprehensive_node_type_markers(
     entity_commands: &mut EntityCommands,
     node: &mut GodotNodeHandle,
 ) {
-    if node.try_get::<godot::classes::AnimatableBody3D>().is_some() {
-        entity_commands.insert(AnimatableBody3DMarker);
-    }
-    if node.try_get::<godot::classes::AnimatedSprite3D>().is_some() {
-        entity_commands.insert(AnimatedSprite3DMarker);
-    }
-    if node.try_get::<godot::classes::Area3D>().is_some() {
-        entity_commands.insert(Area3DMarker);
-    }
-    if node.try_get::<godot::classes::AudioListener3D>().is_some() {
-        entity_commands.insert(AudioListener3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::AudioStreamPlayer3D>()
-        .is_some()
-    {
-        entity_commands.insert(AudioStreamPlayer3DMarker);
-    }
-    if node.try_get::<godot::classes::BoneAttachment3D>().is_some() {
-        entity_commands.insert(BoneAttachment3DMarker);
-    }
-    if node.try_get::<godot::classes::CpuParticles3D>().is_some() {
-        entity_commands.insert(CPUParticles3DMarker);
-    }
-    if node.try_get::<godot::classes::Camera3D>().is_some() {
-        entity_commands.insert(Camera3DMarker);
-    }
-    if node.try_get::<godot::classes::CharacterBody3D>().is_some() {
-        entity_commands.insert(CharacterBody3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::CollisionObject3D>()
-        .is_some()
-    {
-        entity_commands.insert(CollisionObject3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::CollisionPolygon3D>()
-        .is_some()
-    {
-        entity_commands.insert(CollisionPolygon3DMarker);
-    }
-    if node.try_get::<godot::classes::CollisionShape3D>().is_some() {
-        entity_commands.insert(CollisionShape3DMarker);
-    }
-    if node.try_get::<godot::classes::ConeTwistJoint3D>().is_some() {
-        entity_commands.insert(ConeTwistJoint3DMarker);
-    }
-    if node.try_get::<godot::classes::Decal>().is_some() {
-        entity_commands.insert(DecalMarker);
-    }
-    if node
-        .try_get::<godot::classes::DirectionalLight3D>()
-        .is_some()
-    {
-        entity_commands.insert(DirectionalLight3DMarker);
-    }
-    if node.try_get::<godot::classes::GpuParticles3D>().is_some() {
-        entity_commands.insert(GPUParticles3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GpuParticlesAttractor3D>()
-        .is_some()
-    {
-        entity_commands.insert(GPUParticlesAttractor3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GpuParticlesAttractorBox3D>()
-        .is_some()
-    {
-        entity_commands.insert(GPUParticlesAttractorBox3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GpuParticlesAttractorSphere3D>()
-        .is_some()
-    {
-        entity_commands.insert(GPUParticlesAttractorSphere3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GpuParticlesAttractorVectorField3D>()
-        .is_some()
-    {
-        entity_commands.insert(GPUParticlesAttractorVectorField3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GpuParticlesCollision3D>()
-        .is_some()
-    {
-        entity_commands.insert(GPUParticlesCollision3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GpuParticlesCollisionBox3D>()
-        .is_some()
-    {
-        entity_commands.insert(GPUParticlesCollisionBox3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GpuParticlesCollisionHeightField3D>()
-        .is_some()
-    {
-        entity_commands.insert(GPUParticlesCollisionHeightField3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GpuParticlesCollisionSdf3d>()
-        .is_some()
-    {
-        entity_commands.insert(GPUParticlesCollisionSDF3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GpuParticlesCollisionSphere3D>()
-        .is_some()
-    {
-        entity_commands.insert(GPUParticlesCollisionSphere3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::Generic6DofJoint3D>()
-        .is_some()
-    {
-        entity_commands.insert(Generic6DOFJoint3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::GeometryInstance3D>()
-        .is_some()
-    {
-        entity_commands.insert(GeometryInstance3DMarker);
-    }
-    if node.try_get::<godot::classes::GridMap>().is_some() {
-        entity_commands.insert(GridMapMarker);
-    }
-    if node.try_get::<godot::classes::HingeJoint3D>().is_some() {
-        entity_commands.insert(HingeJoint3DMarker);
-    }
-    if node.try_get::<godot::classes::Joint3D>().is_some() {
-        entity_commands.insert(Joint3DMarker);
-    }
-    if node.try_get::<godot::classes::Label3D>().is_some() {
-        entity_commands.insert(Label3DMarker);
-    }
-    if node.try_get::<godot::classes::Light3D>().is_some() {
-        entity_commands.insert(Light3DMarker);
-    }
-    if node.try_get::<godot::classes::LightmapProbe>().is_some() {
-        entity_commands.insert(LightmapProbeMarker);
-    }
-    if node.try_get::<godot::classes::LookAtModifier3D>().is_some() {
-        entity_commands.insert(LookAtModifier3DMarker);
-    }
-    if node.try_get::<godot::classes::Marker3D>().is_some() {
-        entity_commands.insert(Marker3DMarker);
-    }
-    if node.try_get::<godot::classes::MeshInstance3D>().is_some() {
-        entity_commands.insert(MeshInstance3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::MultiMeshInstance3D>()
-        .is_some()
-    {
-        entity_commands.insert(MultiMeshInstance3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::OccluderInstance3D>()
-        .is_some()
-    {
-        entity_commands.insert(OccluderInstance3DMarker);
-    }
-    if node.try_get::<godot::classes::OmniLight3D>().is_some() {
-        entity_commands.insert(OmniLight3DMarker);
-    }
-    if node.try_get::<godot::classes::Path3D>().is_some() {
-        entity_commands.insert(Path3DMarker);
-    }
-    if node.try_get::<godot::classes::PathFollow3D>().is_some() {
-        entity_commands.insert(PathFollow3DMarker);
-    }
-    if node.try_get::<godot::classes::PhysicalBone3D>().is_some() {
-        entity_commands.insert(PhysicalBone3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::PhysicalBoneSimulator3D>()
-        .is_some()
-    {
-        entity_commands.insert(PhysicalBoneSimulator3DMarker);
-    }
-    if node.try_get::<godot::classes::PhysicsBody3D>().is_some() {
-        entity_commands.insert(PhysicsBody3DMarker);
-    }
-    if node.try_get::<godot::classes::PinJoint3D>().is_some() {
-        entity_commands.insert(PinJoint3DMarker);
-    }
-    if node.try_get::<godot::classes::RayCast3D>().is_some() {
-        entity_commands.insert(RayCast3DMarker);
-    }
-    if node.try_get::<godot::classes::ReflectionProbe>().is_some() {
-        entity_commands.insert(ReflectionProbeMarker);
-    }
-    if node
-        .try_get::<godot::classes::RemoteTransform3D>()
-        .is_some()
-    {
-        entity_commands.insert(RemoteTransform3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::RetargetModifier3D>()
-        .is_some()
-    {
-        entity_commands.insert(RetargetModifier3DMarker);
-    }
-    if node.try_get::<godot::classes::RigidBody3D>().is_some() {
-        entity_commands.insert(RigidBody3DMarker);
-    }
-    if node.try_get::<godot::classes::RootMotionView>().is_some() {
-        entity_commands.insert(RootMotionViewMarker);
-    }
-    if node.try_get::<godot::classes::ShapeCast3D>().is_some() {
-        entity_commands.insert(ShapeCast3DMarker);
-    }
-    if node.try_get::<godot::classes::Skeleton3D>().is_some() {
-        entity_commands.insert(Skeleton3DMarker);
-    }
-    if node.try_get::<godot::classes::SkeletonIk3d>().is_some() {
-        entity_commands.insert(SkeletonIK3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::SkeletonModifier3D>()
-        .is_some()
-    {
-        entity_commands.insert(SkeletonModifier3DMarker);
-    }
-    if node.try_get::<godot::classes::SliderJoint3D>().is_some() {
-        entity_commands.insert(SliderJoint3DMarker);
-    }
-    if node.try_get::<godot::classes::SoftBody3D>().is_some() {
-        entity_commands.insert(SoftBody3DMarker);
-    }
-    if node.try_get::<godot::classes::SpotLight3D>().is_some() {
-        entity_commands.insert(SpotLight3DMarker);
-    }
-    if node.try_get::<godot::classes::SpringArm3D>().is_some() {
-        entity_commands.insert(SpringArm3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::SpringBoneCollision3D>()
-        .is_some()
-    {
-        entity_commands.insert(SpringBoneCollision3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::SpringBoneCollisionCapsule3D>()
-        .is_some()
-    {
-        entity_commands.insert(SpringBoneCollisionCapsule3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::SpringBoneCollisionPlane3D>()
-        .is_some()
-    {
-        entity_commands.insert(SpringBoneCollisionPlane3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::SpringBoneCollisionSphere3D>()
-        .is_some()
-    {
-        entity_commands.insert(SpringBoneCollisionSphere3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::SpringBoneSimulator3D>()
-        .is_some()
-    {
-        entity_commands.insert(SpringBoneSimulator3DMarker);
-    }
-    if node.try_get::<godot::classes::Sprite3D>().is_some() {
-        entity_commands.insert(Sprite3DMarker);
-    }
-    if node.try_get::<godot::classes::SpriteBase3D>().is_some() {
-        entity_commands.insert(SpriteBase3DMarker);
-    }
-    if node.try_get::<godot::classes::StaticBody3D>().is_some() {
-        entity_commands.insert(StaticBody3DMarker);
-    }
-    if node.try_get::<godot::classes::VehicleBody3D>().is_some() {
-        entity_commands.insert(VehicleBody3DMarker);
-    }
-    if node.try_get::<godot::classes::VehicleWheel3D>().is_some() {
-        entity_commands.insert(VehicleWheel3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::VisibleOnScreenEnabler3D>()
-        .is_some()
-    {
-        entity_commands.insert(VisibleOnScreenEnabler3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::VisibleOnScreenNotifier3D>()
-        .is_some()
-    {
-        entity_commands.insert(VisibleOnScreenNotifier3DMarker);
-    }
-    if node.try_get::<godot::classes::VisualInstance3D>().is_some() {
-        entity_commands.insert(VisualInstance3DMarker);
-    }
+    let class = node.get::<Node>().get_class().to_string();
+    insert_node_type_markers(
+        entity_commands,
+        resolve_node_class(&class),
+        node.instance_id(),
+    );
 }
 
-fn remove_3d_node_types_comprehensive(entity_commands: &mut EntityCommands) {
-    entity_commands
-        .remove::<AnimatableBody3DMarker>()
-        .remove::<AnimatedSprite3DMarker>()
-        .remove::<Area3DMarker>()
-        .remove::<AudioListener3DMarker>()
-        .remove::<AudioStreamPlayer3DMarker>()
-        .remove::<BoneAttachment3DMarker>()
-        .remove::<CPUParticles3DMarker>()
-        .remove::<Camera3DMarker>()
-        .remove::<CharacterBody3DMarker>()
-        .remove::<CollisionObject3DMarker>()
-        .remove::<CollisionPolygon3DMarker>()
-        .remove::<CollisionShape3DMarker>()
-        .remove::<ConeTwistJoint3DMarker>()
-        .remove::<DecalMarker>()
-        .remove::<DirectionalLight3DMarker>()
-        .remove::<GPUParticles3DMarker>()
-        .remove::<GPUParticlesAttractor3DMarker>()
-        .remove::<GPUParticlesAttractorBox3DMarker>()
-        .remove::<GPUParticlesAttractorSphere3DMarker>()
-        .remove::<GPUParticlesAttractorVectorField3DMarker>()
-        .remove::<GPUParticlesCollision3DMarker>()
-        .remove::<GPUParticlesCollisionBox3DMarker>()
-        .remove::<GPUParticlesCollisionHeightField3DMarker>()
-        .remove::<GPUParticlesCollisionSDF3DMarker>()
-        .remove::<GPUParticlesCollisionSphere3DMarker>()
-        .remove::<Generic6DOFJoint3DMarker>()
-        .remove::<GeometryInstance3DMarker>()
-        .remove::<GridMapMarker>()
-        .remove::<HingeJoint3DMarker>()
-        .remove::<Joint3DMarker>()
-        .remove::<Label3DMarker>()
-        .remove::<Light3DMarker>()
-        .remove::<LightmapProbeMarker>()
-        .remove::<LookAtModifier3DMarker>()
-        .remove::<Marker3DMarker>()
-        .remove::<MeshInstance3DMarker>()
-        .remove::<MultiMeshInstance3DMarker>()
-        .remove::<OccluderInstance3DMarker>()
-        .remove::<OmniLight3DMarker>()
-        .remove::<Path3DMarker>()
-        .remove::<PathFollow3DMarker>()
-        .remove::<PhysicalBone3DMarker>()
-        .remove::<PhysicalBoneSimulator3DMarker>()
-        .remove::<PhysicsBody3DMarker>()
-        .remove::<PinJoint3DMarker>()
-        .remove::<RayCast3DMarker>()
-        .remove::<ReflectionProbeMarker>()
-        .remove::<RemoteTransform3DMarker>()
-        .remove::<RetargetModifier3DMarker>()
-        .remove::<RigidBody3DMarker>()
-        .remove::<RootMotionViewMarker>()
-        .remove::<ShapeCast3DMarker>()
-        .remove::<Skeleton3DMarker>()
-        .remove::<SkeletonIK3DMarker>()
-        .remove::<SkeletonModifier3DMarker>()
-        .remove::<SliderJoint3DMarker>()
-        .remove::<SoftBody3DMarker>()
-        .remove::<SpotLight3DMarker>()
-        .remove::<SpringArm3DMarker>()
-        .remove::<SpringBoneCollision3DMarker>()
-        .remove::<SpringBoneCollisionCapsule3DMarker>()
-        .remove::<SpringBoneCollisionPlane3DMarker>()
-        .remove::<SpringBoneCollisionSphere3DMarker>()
-        .remove::<SpringBoneSimulator3DMarker>()
-        .remove::<Sprite3DMarker>()
-        .remove::<SpriteBase3DMarker>()
-        .remove::<StaticBody3DMarker>()
-        .remove::<VehicleBody3DMarker>()
-        .remove::<VehicleWheel3DMarker>()
-        .remove::<VisibleOnScreenEnabler3DMarker>()
-        .remove::<VisibleOnScreenNotifier3DMarker>()
-        .remove::<VisualInstance3DMarker>();
+/// Removes the marker components and typed handles added by [`add_comprehensive_node_type_markers`].
+pub fn remove_comprehensive_node_type_markers(entity_commands: &mut EntityCommands) {
+    entity_commands.queue_handled(
+        |mut entity: EntityWorldMut| {
+            if let Some(markers) = entity.take::<NodeTypeMarkers>() {
+                remove_node_type_markers(&mut entity, markers.class);
+            }
+        },
+        ignore,
+    );
 }
 
-fn check_2d_node_types_comprehensive(
-    entity_commands: &mut EntityCommands,
-    node: &mut GodotNodeHandle,
-) {
-    if node.try_get::<godot::classes::AnimatableBody2D>().is_some() {
-        entity_commands.insert(AnimatableBody2DMarker);
-    }
-    if node.try_get::<godot::classes::AnimatedSprite2D>().is_some() {
-        entity_commands.insert(AnimatedSprite2DMarker);
-    }
-    if node.try_get::<godot::classes::Area2D>().is_some() {
-        entity_commands.insert(Area2DMarker);
-    }
-    if node.try_get::<godot::classes::AudioListener2D>().is_some() {
-        entity_commands.insert(AudioListener2DMarker);
-    }
-    if node
-        .try_get::<godot::classes::AudioStreamPlayer2D>()
-        .is_some()
-    {
-        entity_commands.insert(AudioStreamPlayer2DMarker);
-    }
-    if node.try_get::<godot::classes::BackBufferCopy>().is_some() {
-        entity_commands.insert(BackBufferCopyMarker);
-    }
-    if node.try_get::<godot::classes::Bone2D>().is_some() {
-        entity_commands.insert(Bone2DMarker);
-    }
-    if node.try_get::<godot::classes::CpuParticles2D>().is_some() {
-        entity_commands.insert(CPUParticles2DMarker);
-    }
-    if node.try_get::<godot::classes::Camera2D>().is_some() {
-        entity_commands.insert(Camera2DMarker);
-    }
-    if node.try_get::<godot::classes::CanvasGroup>().is_some() {
-        entity_commands.insert(CanvasGroupMarker);
-    }
-    if node.try_get::<godot::classes::CanvasModulate>().is_some() {
-        entity_commands.insert(CanvasModulateMarker);
-    }
-    if node.try_get::<godot::classes::CharacterBody2D>().is_some() {
-        entity_commands.insert(CharacterBody2DMarker);
-    }
-    if node
-        .try_get::<godot::classes::CollisionObject2D>()
-        .is_some()
-    {
-        entity_commands.insert(CollisionObject2DMarker);
-    }
-    if node
-        .try_get::<godot::classes::CollisionPolygon2D>()
-        .is_some()
-    {
-        entity_commands.insert(CollisionPolygon2DMarker);
-    }
-    if node.try_get::<godot::classes::CollisionShape2D>().is_some() {
-        entity_commands.insert(CollisionShape2DMarker);
-    }
-    if node
-        .try_get::<godot::classes::DampedSpringJoint2D>()
-        .is_some()
-    {
-        entity_commands.insert(DampedSpringJoint2DMarker);
-    }
-    if node
-        .try_get::<godot::classes::DirectionalLight2D>()
-        .is_some()
-    {
-        entity_commands.insert(DirectionalLight2DMarker);
+/// Maps a Godot class name to the engine class whose markers it gets
+fn resolve_node_class(class: &str) -> &'static str {
+    if let Some(class) = node_class(class) {
+        return class;
     }
-    if node.try_get::<godot::classes::GpuParticles2D>().is_some() {
-        entity_commands.insert(GPUParticles2DMarker);
-    }
-    if node.try_get::<godot::classes::GrooveJoint2D>().is_some() {
-        entity_commands.insert(GrooveJoint2DMarker);
-    }
-    if node.try_get::<godot::classes::Joint2D>().is_some() {
-        entity_commands.insert(Joint2DMarker);
-    }
-    if node.try_get::<godot::classes::Light2D>().is_some() {
-        entity_commands.insert(Light2DMarker);
-    }
-    if node.try_get::<godot::classes::LightOccluder2D>().is_some() {
-        entity_commands.insert(LightOccluder2DMarker);
-    }
-    if node.try_get::<godot::classes::Line2D>().is_some() {
-        entity_commands.insert(Line2DMarker);
-    }
-    if node.try_get::<godot::classes::Marker2D>().is_some() {
-        entity_commands.insert(Marker2DMarker);
-    }
-    if node.try_get::<godot::classes::MeshInstance2D>().is_some() {
-        entity_commands.insert(MeshInstance2DMarker);
-    }
-    if node
-        .try_get::<godot::classes::MultiMeshInstance2D>()
-        .is_some()
-    {
-        entity_commands.insert(MultiMeshInstance2DMarker);
-    }
-    if node.try_get::<godot::classes::ParallaxLayer>().is_some() {
-        entity_commands.insert(ParallaxLayerMarker);
-    }
-    if node.try_get::<godot::classes::Path2D>().is_some() {
-        entity_commands.insert(Path2DMarker);
-    }
-    if node.try_get::<godot::classes::PathFollow2D>().is_some() {
-        entity_commands.insert(PathFollow2DMarker);
-    }
-    if node.try_get::<godot::classes::PhysicalBone2D>().is_some() {
-        entity_commands.insert(PhysicalBone2DMarker);
-    }
-    if node.try_get::<godot::classes::PhysicsBody2D>().is_some() {
-        entity_commands.insert(PhysicsBody2DMarker);
-    }
-    if node.try_get::<godot::classes::PinJoint2D>().is_some() {
-        entity_commands.insert(PinJoint2DMarker);
-    }
-    if node.try_get::<godot::classes::PointLight2D>().is_some() {
-        entity_commands.insert(PointLight2DMarker);
-    }
-    if node.try_get::<godot::classes::Polygon2D>().is_some() {
-        entity_commands.insert(Polygon2DMarker);
-    }
-    if node.try_get::<godot::classes::RayCast2D>().is_some() {
-        entity_commands.insert(RayCast2DMarker);
-    }
-    if node
-        .try_get::<godot::classes::RemoteTransform2D>()
-        .is_some()
-    {
-        entity_commands.insert(RemoteTransform2DMarker);
-    }
-    if node.try_get::<godot::classes::RigidBody2D>().is_some() {
-        entity_commands.insert(RigidBody2DMarker);
-    }
-    if node.try_get::<godot::classes::ShapeCast2D>().is_some() {
-        entity_commands.insert(ShapeCast2DMarker);
-    }
-    if node.try_get::<godot::classes::Skeleton2D>().is_some() {
-        entity_commands.insert(Skeleton2DMarker);
-    }
-    if node.try_get::<godot::classes::Sprite2D>().is_some() {
-        entity_commands.insert(Sprite2DMarker);
-    }
-    if node.try_get::<godot::classes::StaticBody2D>().is_some() {
-        entity_commands.insert(StaticBody2DMarker);
-    }
-    if node.try_get::<godot::classes::TileMap>().is_some() {
-        entity_commands.insert(TileMapMarker);
-    }
-    if node.try_get::<godot::classes::TileMapLayer>().is_some() {
-        entity_commands.insert(TileMapLayerMarker);
-    }
-    if node
-        .try_get::<godot::classes::TouchScreenButton>()
-        .is_some()
-    {
-        entity_commands.insert(TouchScreenButtonMarker);
-    }
-    if node
-        .try_get::<godot::classes::VisibleOnScreenEnabler2D>()
-        .is_some()
-    {
-        entity_commands.insert(VisibleOnScreenEnabler2DMarker);
-    }
-    if node
-        .try_get::<godot::classes::VisibleOnScreenNotifier2D>()
-        .is_some()
-    {
-        entity_commands.insert(VisibleOnScreenNotifier2DMarker);
-    }
-}
 
-fn remove_2d_node_types_comprehensive(entity_commands: &mut EntityCommands) {
-    entity_commands
-        .remove::<AnimatableBody2DMarker>()
-        .remove::<AnimatedSprite2DMarker>()
-        .remove::<Area2DMarker>()
-        .remove::<AudioListener2DMarker>()
-        .remove::<AudioStreamPlayer2DMarker>()
-        .remove::<BackBufferCopyMarker>()
-        .remove::<Bone2DMarker>()
-        .remove::<CPUParticles2DMarker>()
-        .remove::<Camera2DMarker>()
-        .remove::<CanvasGroupMarker>()
-        .remove::<CanvasModulateMarker>()
-        .remove::<CharacterBody2DMarker>()
-        .remove::<CollisionObject2DMarker>()
-        .remove::<CollisionPolygon2DMarker>()
-        .remove::<CollisionShape2DMarker>()
-        .remove::<DampedSpringJoint2DMarker>()
-        .remove::<DirectionalLight2DMarker>()
-        .remove::<GPUParticles2DMarker>()
-        .remove::<GrooveJoint2DMarker>()
-        .remove::<Joint2DMarker>()
-        .remove::<Light2DMarker>()
-        .remove::<LightOccluder2DMarker>()
-        .remove::<Line2DMarker>()
-        .remove::<Marker2DMarker>()
-        .remove::<MeshInstance2DMarker>()
-        .remove::<MultiMeshInstance2DMarker>()
-        .remove::<ParallaxLayerMarker>()
-        .remove::<Path2DMarker>()
-        .remove::<PathFollow2DMarker>()
-        .remove::<PhysicalBone2DMarker>()
-        .remove::<PhysicsBody2DMarker>()
-        .remove::<PinJoint2DMarker>()
-        .remove::<PointLight2DMarker>()
-        .remove::<Polygon2DMarker>()
-        .remove::<RayCast2DMarker>()
-        .remove::<RemoteTransform2DMarker>()
-        .remove::<RigidBody2DMarker>()
-        .remove::<ShapeCast2DMarker>()
-        .remove::<Skeleton2DMarker>()
-        .remove::<Sprite2DMarker>()
-        .remove::<StaticBody2DMarker>()
-        .remove::<TileMapMarker>()
-        .remove::<TileMapLayerMarker>()
-        .remove::<TouchScreenButtonMarker>()
-        .remove::<VisibleOnScreenEnabler2DMarker>()
-        .remove::<VisibleOnScreenNotifier2DMarker>();
-}
-
-fn check_control_node_types_comprehensive(
-    entity_commands: &mut EntityCommands,
-    node: &mut GodotNodeHandle,
-) {
-    if node
-        .try_get::<godot::classes::AspectRatioContainer>()
-        .is_some()
-    {
-        entity_commands.insert(AspectRatioContainerMarker);
-    }
-    if node.try_get::<godot::classes::BaseButton>().is_some() {
-        entity_commands.insert(BaseButtonMarker);
-    }
-    if node.try_get::<godot::classes::BoxContainer>().is_some() {
-        entity_commands.insert(BoxContainerMarker);
-    }
-    if node.try_get::<godot::classes::Button>().is_some() {
-        entity_commands.insert(ButtonMarker);
-    }
-    if node.try_get::<godot::classes::CenterContainer>().is_some() {
-        entity_commands.insert(CenterContainerMarker);
-    }
-    if node.try_get::<godot::classes::CheckBox>().is_some() {
-        entity_commands.insert(CheckBoxMarker);
-    }
-    if node.try_get::<godot::classes::CheckButton>().is_some() {
-        entity_commands.insert(CheckButtonMarker);
-    }
-    if node.try_get::<godot::classes::CodeEdit>().is_some() {
-        entity_commands.insert(CodeEditMarker);
-    }
-    if node.try_get::<godot::classes::ColorPicker>().is_some() {
-        entity_commands.insert(ColorPickerMarker);
-    }
-    if node
-        .try_get::<godot::classes::ColorPickerButton>()
-        .is_some()
-    {
-        entity_commands.insert(ColorPickerButtonMarker);
-    }
-    if node.try_get::<godot::classes::ColorRect>().is_some() {
-        entity_commands.insert(ColorRectMarker);
-    }
-    if node.try_get::<godot::classes::Container>().is_some() {
-        entity_commands.insert(ContainerMarker);
-    }
-    if node.try_get::<godot::classes::FlowContainer>().is_some() {
-        entity_commands.insert(FlowContainerMarker);
-    }
-    if node.try_get::<godot::classes::GridContainer>().is_some() {
-        entity_commands.insert(GridContainerMarker);
-    }
-    if node.try_get::<godot::classes::HBoxContainer>().is_some() {
-        entity_commands.insert(HBoxContainerMarker);
-    }
-    if node.try_get::<godot::classes::HFlowContainer>().is_some() {
-        entity_commands.insert(HFlowContainerMarker);
-    }
-    if node.try_get::<godot::classes::HScrollBar>().is_some() {
-        entity_commands.insert(HScrollBarMarker);
-    }
-    if node.try_get::<godot::classes::HSeparator>().is_some() {
-        entity_commands.insert(HSeparatorMarker);
-    }
-    if node.try_get::<godot::classes::HSlider>().is_some() {
-        entity_commands.insert(HSliderMarker);
-    }
-    if node.try_get::<godot::classes::HSplitContainer>().is_some() {
-        entity_commands.insert(HSplitContainerMarker);
-    }
-    if node.try_get::<godot::classes::ItemList>().is_some() {
-        entity_commands.insert(ItemListMarker);
-    }
-    if node.try_get::<godot::classes::Label>().is_some() {
-        entity_commands.insert(LabelMarker);
-    }
-    if node.try_get::<godot::classes::LineEdit>().is_some() {
-        entity_commands.insert(LineEditMarker);
-    }
-    if node.try_get::<godot::classes::LinkButton>().is_some() {
-        entity_commands.insert(LinkButtonMarker);
-    }
-    if node.try_get::<godot::classes::MarginContainer>().is_some() {
-        entity_commands.insert(MarginContainerMarker);
-    }
-    if node.try_get::<godot::classes::MenuBar>().is_some() {
-        entity_commands.insert(MenuBarMarker);
-    }
-    if node.try_get::<godot::classes::MenuButton>().is_some() {
-        entity_commands.insert(MenuButtonMarker);
-    }
-    if node.try_get::<godot::classes::NinePatchRect>().is_some() {
-        entity_commands.insert(NinePatchRectMarker);
-    }
-    if node.try_get::<godot::classes::OptionButton>().is_some() {
-        entity_commands.insert(OptionButtonMarker);
-    }
-    if node.try_get::<godot::classes::Panel>().is_some() {
-        entity_commands.insert(PanelMarker);
-    }
-    if node.try_get::<godot::classes::PanelContainer>().is_some() {
-        entity_commands.insert(PanelContainerMarker);
-    }
-    if node.try_get::<godot::classes::ProgressBar>().is_some() {
-        entity_commands.insert(ProgressBarMarker);
-    }
-    if node.try_get::<godot::classes::Range>().is_some() {
-        entity_commands.insert(RangeMarker);
-    }
-    if node.try_get::<godot::classes::ReferenceRect>().is_some() {
-        entity_commands.insert(ReferenceRectMarker);
-    }
-    if node.try_get::<godot::classes::RichTextLabel>().is_some() {
-        entity_commands.insert(RichTextLabelMarker);
-    }
-    if node.try_get::<godot::classes::ScrollBar>().is_some() {
-        entity_commands.insert(ScrollBarMarker);
-    }
-    if node.try_get::<godot::classes::ScrollContainer>().is_some() {
-        entity_commands.insert(ScrollContainerMarker);
-    }
-    if node.try_get::<godot::classes::Separator>().is_some() {
-        entity_commands.insert(SeparatorMarker);
-    }
-    if node.try_get::<godot::classes::Slider>().is_some() {
-        entity_commands.insert(SliderMarker);
-    }
-    if node.try_get::<godot::classes::SpinBox>().is_some() {
-        entity_commands.insert(SpinBoxMarker);
-    }
-    if node.try_get::<godot::classes::SplitContainer>().is_some() {
-        entity_commands.insert(SplitContainerMarker);
-    }
-    if node
-        .try_get::<godot::classes::SubViewportContainer>()
-        .is_some()
-    {
-        entity_commands.insert(SubViewportContainerMarker);
-    }
-    if node.try_get::<godot::classes::TabBar>().is_some() {
-        entity_commands.insert(TabBarMarker);
-    }
-    if node.try_get::<godot::classes::TabContainer>().is_some() {
-        entity_commands.insert(TabContainerMarker);
-    }
-    if node.try_get::<godot::classes::TextEdit>().is_some() {
-        entity_commands.insert(TextEditMarker);
-    }
-    if node.try_get::<godot::classes::TextureButton>().is_some() {
-        entity_commands.insert(TextureButtonMarker);
-    }
-    if node
-        .try_get::<godot::classes::TextureProgressBar>()
-        .is_some()
-    {
-        entity_commands.insert(TextureProgressBarMarker);
-    }
-    if node.try_get::<godot::classes::TextureRect>().is_some() {
-        entity_commands.insert(TextureRectMarker);
-    }
-    if node.try_get::<godot::classes::Tree>().is_some() {
-        entity_commands.insert(TreeMarker);
-    }
-    if node.try_get::<godot::classes::VBoxContainer>().is_some() {
-        entity_commands.insert(VBoxContainerMarker);
-    }
-    if node.try_get::<godot::classes::VFlowContainer>().is_some() {
-        entity_commands.insert(VFlowContainerMarker);
-    }
-    if node.try_get::<godot::classes::VScrollBar>().is_some() {
-        entity_commands.insert(VScrollBarMarker);
-    }
-    if node.try_get::<godot::classes::VSeparator>().is_some() {
-        entity_commands.insert(VSeparatorMarker);
-    }
-    if node.try_get::<godot::classes::VSlider>().is_some() {
-        entity_commands.insert(VSliderMarker);
-    }
-    if node.try_get::<godot::classes::VSplitContainer>().is_some() {
-        entity_commands.insert(VSplitContainerMarker);
-    }
-    if node
-        .try_get::<godot::classes::VideoStreamPlayer>()
-        .is_some()
-    {
-        entity_commands.insert(VideoStreamPlayerMarker);
-    }
+    RESOLVED_CLASSES.with_borrow_mut(|resolved| {
+        *resolved.entry(class.to_owned()).or_insert_with(|| {
+            let class_db = ClassDb::singleton();
+            let mut parent = class_db.get_parent_class(class);
+            while parent.len() > 0 {
+                if let Some(class) = node_class(&parent.to_string()) {
+                    return class;
+                }
+                parent = class_db.get_parent_class(&parent);
+            }
+            "Node"
+        })
+    })
 }
 
-fn remove_control_node_types_comprehensive(entity_commands: &mut EntityCommands) {
-    entity_commands
-        .remove::<AspectRatioContainerMarker>()
-        .remove::<BaseButtonMarker>()
-        .remove::<BoxContainerMarker>()
-        .remove::<ButtonMarker>()
-        .remove::<CenterContainerMarker>()
-        .remove::<CheckBoxMarker>()
-        .remove::<CheckButtonMarker>()
-        .remove::<CodeEditMarker>()
-        .remove::<ColorPickerMarker>()
-        .remove::<ColorPickerButtonMarker>()
-        .remove::<ColorRectMarker>()
-        .remove::<ContainerMarker>()
-        .remove::<FlowContainerMarker>()
-        .remove::<GridContainerMarker>()
-        .remove::<HBoxContainerMarker>()
-        .remove::<HFlowContainerMarker>()
-        .remove::<HScrollBarMarker>()
-        .remove::<HSeparatorMarker>()
-        .remove::<HSliderMarker>()
-        .remove::<HSplitContainerMarker>()
-        .remove::<ItemListMarker>()
-        .remove::<LabelMarker>()
-        .remove::<LineEditMarker>()
-        .remove::<LinkButtonMarker>()
-        .remove::<MarginContainerMarker>()
-        .remove::<MenuBarMarker>()
-        .remove::<MenuButtonMarker>()
-        .remove::<NinePatchRectMarker>()
-        .remove::<OptionButtonMarker>()
-        .remove::<PanelMarker>()
-        .remove::<PanelContainerMarker>()
-        .remove::<ProgressBarMarker>()
-        .remove::<RangeMarker>()
-        .remove::<ReferenceRectMarker>()
-        .remove::<RichTextLabelMarker>()
-        .remove::<ScrollBarMarker>()
-        .remove::<ScrollContainerMarker>()
-        .remove::<SeparatorMarker>()
-        .remove::<SliderMarker>()
-        .remove::<SpinBoxMarker>()
-        .remove::<SplitContainerMarker>()
-        .remove::<SubViewportContainerMarker>()
-        .remove::<TabBarMarker>()
-        .remove::<TabContainerMarker>()
-        .remove::<TextEditMarker>()
-        .remove::<TextureButtonMarker>()
-        .remove::<TextureProgressBarMarker>()
-        .remove::<TextureRectMarker>()
-        .remove::<TreeMarker>()
-        .remove::<VBoxContainerMarker>()
-        .remove::<VFlowContainerMarker>()
-        .remove::<VScrollBarMarker>()
-        .remove::<VSeparatorMarker>()
-        .remove::<VSliderMarker>()
-        .remove::<VSplitContainerMarker>()
-        .remove::<VideoStreamPlayerMarker>();
+fn node_class(class: &str) -> Option<&'static str> {
+    let class = match class {
+        "Node" => "Node",
+        "AcceptDialog" => "AcceptDialog",
+        "AnimatableBody2D" => "AnimatableBody2D",
+        "AnimatableBody3D" => "AnimatableBody3D",
+        "AnimatedSprite2D" => "AnimatedSprite2D",
+        "AnimatedSprite3D" => "AnimatedSprite3D",
+        "AnimationMixer" => "AnimationMixer",
+        "AnimationPlayer" => "AnimationPlayer",
+        "AnimationTree" => "AnimationTree",
+        "Area2D" => "Area2D",
+        "Area3D" => "Area3D",
+        "AspectRatioContainer" => "AspectRatioContainer",
+        "AudioListener2D" => "AudioListener2D",
+        "AudioListener3D" => "AudioListener3D",
+        "AudioStreamPlayer" => "AudioStreamPlayer",
+        "AudioStreamPlayer2D" => "AudioStreamPlayer2D",
+        "AudioStreamPlayer3D" => "AudioStreamPlayer3D",
+        "BackBufferCopy" => "BackBufferCopy",
+        "BaseButton" => "BaseButton",
+        "Bone2D" => "Bone2D",
+        "BoneAttachment3D" => "BoneAttachment3D",
+        "BoxContainer" => "BoxContainer",
+        "Button" => "Button",
+        "CPUParticles2D" => "CPUParticles2D",
+        "CPUParticles3D" => "CPUParticles3D",
+        "CSGBox3D" => "CSGBox3D",
+        "CSGCombiner3D" => "CSGCombiner3D",
+        "CSGCylinder3D" => "CSGCylinder3D",
+        "CSGMesh3D" => "CSGMesh3D",
+        "CSGPolygon3D" => "CSGPolygon3D",
+        "CSGPrimitive3D" => "CSGPrimitive3D",
+        "CSGShape3D" => "CSGShape3D",
+        "CSGSphere3D" => "CSGSphere3D",
+        "CSGTorus3D" => "CSGTorus3D",
+        "Camera2D" => "Camera2D",
+        "Camera3D" => "Camera3D",
+        "CanvasGroup" => "CanvasGroup",
+        "CanvasItem" => "CanvasItem",
+        "CanvasLayer" => "CanvasLayer",
+        "CanvasModulate" => "CanvasModulate",
+        "CenterContainer" => "CenterContainer",
+        "CharacterBody2D" => "CharacterBody2D",
+        "CharacterBody3D" => "CharacterBody3D",
+        "CheckBox" => "CheckBox",
+        "CheckButton" => "CheckButton",
+        "CodeEdit" => "CodeEdit",
+        "CollisionObject2D" => "CollisionObject2D",
+        "CollisionObject3D" => "CollisionObject3D",
+        "CollisionPolygon2D" => "CollisionPolygon2D",
+        "CollisionPolygon3D" => "CollisionPolygon3D",
+        "CollisionShape2D" => "CollisionShape2D",
+        "CollisionShape3D" => "CollisionShape3D",
+        "ColorPicker" => "ColorPicker",
+        "ColorPickerButton" => "ColorPickerButton",
+        "ColorRect" => "ColorRect",
+        "ConeTwistJoint3D" => "ConeTwistJoint3D",
+        "ConfirmationDialog" => "ConfirmationDialog",
+        "Container" => "Container",
+        "Control" => "Control",
+        "DampedSpringJoint2D" => "DampedSpringJoint2D",
+        "Decal" => "Decal",
+        "DirectionalLight2D" => "DirectionalLight2D",
+        "DirectionalLight3D" => "DirectionalLight3D",
+        "FileDialog" => "FileDialog",
+        "FileSystemDock" => "FileSystemDock",
+        "FlowContainer" => "FlowContainer",
+        "FogVolume" => "FogVolume",
+        "GPUParticles2D" => "GPUParticles2D",
+        "GPUParticles3D" => "GPUParticles3D",
+        "GPUParticlesAttractor3D" => "GPUParticlesAttractor3D",
+        "GPUParticlesAttractorBox3D" => "GPUParticlesAttractorBox3D",
+        "GPUParticlesAttractorSphere3D" => "GPUParticlesAttractorSphere3D",
+        "GPUParticlesAttractorVectorField3D" => "GPUParticlesAttractorVectorField3D",
+        "GPUParticlesCollision3D" => "GPUParticlesCollision3D",
+        "GPUParticlesCollisionBox3D" => "GPUParticlesCollisionBox3D",
+        "GPUParticlesCollisionHeightField3D" => "GPUParticlesCollisionHeightField3D",
+        "GPUParticlesCollisionSDF3D" => "GPUParticlesCollisionSDF3D",
+        "GPUParticlesCollisionSphere3D" => "GPUParticlesCollisionSphere3D",
+        "Generic6DOFJoint3D" => "Generic6DOFJoint3D",
+        "GeometryInstance3D" => "GeometryInstance3D",
+        "GraphEdit" => "GraphEdit",
+        "GraphElement" => "GraphElement",
+        "GraphFrame" => "GraphFrame",
+        "GraphNode" => "GraphNode",
+        "GridContainer" => "GridContainer",
+        "GridMap" => "GridMap",
+        "GridMapEditorPlugin" => "GridMapEditorPlugin",
+        "GrooveJoint2D" => "GrooveJoint2D",
+        "HBoxContainer" => "HBoxContainer",
+        "HFlowContainer" => "HFlowContainer",
+        "HScrollBar" => "HScrollBar",
+        "HSeparator" => "HSeparator",
+        "HSlider" => "HSlider",
+        "HSplitContainer" => "HSplitContainer",
+        "HTTPRequest" => "HTTPRequest",
+        "HingeJoint3D" => "HingeJoint3D",
+        "InstancePlaceholder" => "InstancePlaceholder",
+        "ItemList" => "ItemList",
+        "Joint2D" => "Joint2D",
+        "Joint3D" => "Joint3D",
+        "Label" => "Label",
+        "Label3D" => "Label3D",
+        "Light2D" => "Light2D",
+        "Light3D" => "Light3D",
+        "LightOccluder2D" => "LightOccluder2D",
+        "LightmapGI" => "LightmapGI",
+        "LightmapProbe" => "LightmapProbe",
+        "Line2D" => "Line2D",
+        "LineEdit" => "LineEdit",
+        "LinkButton" => "LinkButton",
+        "LookAtModifier3D" => "LookAtModifier3D",
+        "MarginContainer" => "MarginContainer",
+        "Marker2D" => "Marker2D",
+        "Marker3D" => "Marker3D",
+        "MenuBar" => "MenuBar",
+        "MenuButton" => "MenuButton",
+        "MeshInstance2D" => "MeshInstance2D",
+        "MeshInstance3D" => "MeshInstance3D",
+        "MultiMeshInstance2D" => "MultiMeshInstance2D",
+        "MultiMeshInstance3D" => "MultiMeshInstance3D",
+        "MultiplayerSpawner" => "MultiplayerSpawner",
+        "MultiplayerSynchronizer" => "MultiplayerSynchronizer",
+        "NavigationAgent2D" => "NavigationAgent2D",
+        "NavigationAgent3D" => "NavigationAgent3D",
+        "NavigationLink2D" => "NavigationLink2D",
+        "NavigationLink3D" => "NavigationLink3D",
+        "NavigationObstacle2D" => "NavigationObstacle2D",
+        "NavigationObstacle3D" => "NavigationObstacle3D",
+        "NavigationRegion2D" => "NavigationRegion2D",
+        "NavigationRegion3D" => "NavigationRegion3D",
+        "NinePatchRect" => "NinePatchRect",
+        "Node2D" => "Node2D",
+        "Node3D" => "Node3D",
+        "OccluderInstance3D" => "OccluderInstance3D",
+        "OmniLight3D" => "OmniLight3D",
+        "OpenXRBindingModifierEditor" => "OpenXRBindingModifierEditor",
+        "OpenXRCompositionLayer" => "OpenXRCompositionLayer",
+        "OpenXRCompositionLayerCylinder" => "OpenXRCompositionLayerCylinder",
+        "OpenXRCompositionLayerEquirect" => "OpenXRCompositionLayerEquirect",
+        "OpenXRCompositionLayerQuad" => "OpenXRCompositionLayerQuad",
+        "OpenXRHand" => "OpenXRHand",
+        "OpenXRInteractionProfileEditor" => "OpenXRInteractionProfileEditor",
+        "OpenXRInteractionProfileEditorBase" => "OpenXRInteractionProfileEditorBase",
+        "OpenXRVisibilityMask" => "OpenXRVisibilityMask",
+        "OptionButton" => "OptionButton",
+        "Panel" => "Panel",
+        "PanelContainer" => "PanelContainer",
+        "Parallax2D" => "Parallax2D",
+        "ParallaxBackground" => "ParallaxBackground",
+        "ParallaxLayer" => "ParallaxLayer",
+        "Path2D" => "Path2D",
+        "Path3D" => "Path3D",
+        "PathFollow2D" => "PathFollow2D",
+        "PathFollow3D" => "PathFollow3D",
+        "PhysicalBone2D" => "PhysicalBone2D",
+        "PhysicalBone3D" => "PhysicalBone3D",
+        "PhysicalBoneSimulator3D" => "PhysicalBoneSimulator3D",
+        "PhysicsBody2D" => "PhysicsBody2D",
+        "PhysicsBody3D" => "PhysicsBody3D",
+        "PinJoint2D" => "PinJoint2D",
+        "PinJoint3D" => "PinJoint3D",
+        "PointLight2D" => "PointLight2D",
+        "Polygon2D" => "Polygon2D",
+        "Popup" => "Popup",
+        "PopupMenu" => "PopupMenu",
+        "PopupPanel" => "PopupPanel",
+        "ProgressBar" => "ProgressBar",
+        "Range" => "Range",
+        "RayCast2D" => "RayCast2D",
+        "RayCast3D" => "RayCast3D",
+        "ReferenceRect" => "ReferenceRect",
+        "ReflectionProbe" => "ReflectionProbe",
+        "RemoteTransform2D" => "RemoteTransform2D",
+        "RemoteTransform3D" => "RemoteTransform3D",
+        "ResourcePreloader" => "ResourcePreloader",
+        "RetargetModifier3D" => "RetargetModifier3D",
+        "RichTextLabel" => "RichTextLabel",
+        "RigidBody2D" => "RigidBody2D",
+        "RigidBody3D" => "RigidBody3D",
+        "RootMotionView" => "RootMotionView",
+        "ScriptCreateDialog" => "ScriptCreateDialog",
+        "ScrollBar" => "ScrollBar",
+        "ScrollContainer" => "ScrollContainer",
+        "Separator" => "Separator",
+        "ShaderGlobalsOverride" => "ShaderGlobalsOverride",
+        "ShapeCast2D" => "ShapeCast2D",
+        "ShapeCast3D" => "ShapeCast3D",
+        "Skeleton2D" => "Skeleton2D",
+        "Skeleton3D" => "Skeleton3D",
+        "SkeletonIK3D" => "SkeletonIK3D",
+        "SkeletonModifier3D" => "SkeletonModifier3D",
+        "Slider" => "Slider",
+        "SliderJoint3D" => "SliderJoint3D",
+        "SoftBody3D" => "SoftBody3D",
+        "SpinBox" => "SpinBox",
+        "SplitContainer" => "SplitContainer",
+        "SpotLight3D" => "SpotLight3D",
+        "SpringArm3D" => "SpringArm3D",
+        "SpringBoneCollision3D" => "SpringBoneCollision3D",
+        "SpringBoneCollisionCapsule3D" => "SpringBoneCollisionCapsule3D",
+        "SpringBoneCollisionPlane3D" => "SpringBoneCollisionPlane3D",
+        "SpringBoneCollisionSphere3D" => "SpringBoneCollisionSphere3D",
+        "SpringBoneSimulator3D" => "SpringBoneSimulator3D",
+        "Sprite2D" => "Sprite2D",
+        "Sprite3D" => "Sprite3D",
+        "SpriteBase3D" => "SpriteBase3D",
+        "StaticBody2D" => "StaticBody2D",
+        "StaticBody3D" => "StaticBody3D",
+        "StatusIndicator" => "StatusIndicator",
+        "SubViewport" => "SubViewport",
+        "SubViewportContainer" => "SubViewportContainer",
+        "TabBar" => "TabBar",
+        "TabContainer" => "TabContainer",
+        "TextEdit" => "TextEdit",
+        "TextureButton" => "TextureButton",
+        "TextureProgressBar" => "TextureProgressBar",
+        "TextureRect" => "TextureRect",
+        "TileMap" => "TileMap",
+        "TileMapLayer" => "TileMapLayer",
+        "Timer" => "Timer",
+        "TouchScreenButton" => "TouchScreenButton",
+        "Tree" => "Tree",
+        "VBoxContainer" => "VBoxContainer",
+        "VFlowContainer" => "VFlowContainer",
+        "VScrollBar" => "VScrollBar",
+        "VSeparator" => "VSeparator",
+        "VSlider" => "VSlider",
+        "VSplitContainer" => "VSplitContainer",
+        "VehicleBody3D" => "VehicleBody3D",
+        "VehicleWheel3D" => "VehicleWheel3D",
+        "VideoStreamPlayer" => "VideoStreamPlayer",
+        "Viewport" => "Viewport",
+        "VisibleOnScreenEnabler2D" => "VisibleOnScreenEnabler2D",
+        "VisibleOnScreenEnabler3D" => "VisibleOnScreenEnabler3D",
+        "VisibleOnScreenNotifier2D" => "VisibleOnScreenNotifier2D",
+        "VisibleOnScreenNotifier3D" => "VisibleOnScreenNotifier3D",
+        "VisualInstance3D" => "VisualInstance3D",
+        "VoxelGI" => "VoxelGI",
+        "Window" => "Window",
+        "WorldEnvironment" => "WorldEnvironment",
+        "XRAnchor3D" => "XRAnchor3D",
+        "XRBodyModifier3D" => "XRBodyModifier3D",
+        "XRCamera3D" => "XRCamera3D",
+        "XRController3D" => "XRController3D",
+        "XRFaceModifier3D" => "XRFaceModifier3D",
+        "XRHandModifier3D" => "XRHandModifier3D",
+        "XRNode3D" => "XRNode3D",
+        "XROrigin3D" => "XROrigin3D",
+        _ => return None,
+    };
+    Some(class)
 }
 
-fn check_universal_node_types_comprehensive(
+fn insert_node_type_markers(
     entity_commands: &mut EntityCommands,
-    node: &mut GodotNodeHandle,
+    class: &'static str,
+    instance_id: InstanceId,
 ) {
-    if node.try_get::<godot::classes::AnimationMixer>().is_some() {
-        entity_commands.insert(AnimationMixerMarker);
-    }
-    if node
-        .try_get::<godot::classes::AudioStreamPlayer>()
-        .is_some()
-    {
-        entity_commands.insert(AudioStreamPlayerMarker);
-    }
-    if node.try_get::<godot::classes::CanvasItem>().is_some() {
-        entity_commands.insert(CanvasItemMarker);
-    }
-    if node.try_get::<godot::classes::CanvasLayer>().is_some() {
-        entity_commands.insert(CanvasLayerMarker);
-    }
-    if node.try_get::<godot::classes::HttpRequest>().is_some() {
-        entity_commands.insert(HTTPRequestMarker);
-    }
-    if node
-        .try_get::<godot::classes::InstancePlaceholder>()
-        .is_some()
-    {
-        entity_commands.insert(InstancePlaceholderMarker);
-    }
-    if node
-        .try_get::<godot::classes::MultiplayerSpawner>()
-        .is_some()
-    {
-        entity_commands.insert(MultiplayerSpawnerMarker);
-    }
-    if node
-        .try_get::<godot::classes::MultiplayerSynchronizer>()
-        .is_some()
-    {
-        entity_commands.insert(MultiplayerSynchronizerMarker);
-    }
-    if node.try_get::<godot::classes::Node3D>().is_some() {
-        entity_commands.insert(Node3DMarker);
-    }
-    if node
-        .try_get::<godot::classes::ResourcePreloader>()
-        .is_some()
-    {
-        entity_commands.insert(ResourcePreloaderMarker);
-    }
-    if node
-        .try_get::<godot::classes::ShaderGlobalsOverride>()
-        .is_some()
-    {
-        entity_commands.insert(ShaderGlobalsOverrideMarker);
-    }
-    if node.try_get::<godot::classes::Timer>().is_some() {
-        entity_commands.insert(TimerMarker);
-    }
-    if node.try_get::<godot::classes::Viewport>().is_some() {
-        entity_commands.insert(ViewportMarker);
-    }
+    let markers = NodeTypeMarkers { class };
+    match class {
+        "AcceptDialog" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            AcceptDialogMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Window>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AcceptDialog>::new_unchecked(instance_id),
+            ),
+        )),
+        "AnimatableBody2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            StaticBody2DMarker,
+            AnimatableBody2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::StaticBody2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AnimatableBody2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "AnimatableBody3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            StaticBody3DMarker,
+            AnimatableBody3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::StaticBody3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AnimatableBody3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "AnimatedSprite2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            AnimatedSprite2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AnimatedSprite2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "AnimatedSprite3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            SpriteBase3DMarker,
+            AnimatedSprite3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpriteBase3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AnimatedSprite3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "AnimationMixer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            AnimationMixerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AnimationMixer>::new_unchecked(instance_id),
+            ),
+        )),
+        "AnimationPlayer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            AnimationMixerMarker,
+            AnimationPlayerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AnimationMixer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AnimationPlayer>::new_unchecked(instance_id),
+            ),
+        )),
+        "AnimationTree" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            AnimationMixerMarker,
+            AnimationTreeMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AnimationMixer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AnimationTree>::new_unchecked(instance_id),
+            ),
+        )),
+        "Area2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            Area2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Area2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Area3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            Area3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Area3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "AspectRatioContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            AspectRatioContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AspectRatioContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "AudioListener2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            AudioListener2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AudioListener2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "AudioListener3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            AudioListener3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AudioListener3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "AudioStreamPlayer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            AudioStreamPlayerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AudioStreamPlayer>::new_unchecked(instance_id),
+            ),
+        )),
+        "AudioStreamPlayer2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            AudioStreamPlayer2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AudioStreamPlayer2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "AudioStreamPlayer3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            AudioStreamPlayer3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AudioStreamPlayer3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "BackBufferCopy" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            BackBufferCopyMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BackBufferCopy>::new_unchecked(instance_id),
+            ),
+        )),
+        "BaseButton" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BaseButton>::new_unchecked(instance_id),
+            ),
+        )),
+        "Bone2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Bone2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Bone2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "BoneAttachment3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            BoneAttachment3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BoneAttachment3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "BoxContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BoxContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "Button" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BaseButton>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Button>::new_unchecked(instance_id),
+            ),
+        )),
+        "CPUParticles2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CPUParticles2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CpuParticles2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CPUParticles3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CPUParticles3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CpuParticles3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CSGBox3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGBox3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgShape3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgPrimitive3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgBox3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CSGCombiner3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGCombiner3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgShape3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgCombiner3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CSGCylinder3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGCylinder3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgShape3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgPrimitive3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgCylinder3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CSGMesh3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGMesh3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgShape3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgPrimitive3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgMesh3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CSGPolygon3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGPolygon3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgShape3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgPrimitive3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgPolygon3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CSGPrimitive3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgShape3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgPrimitive3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CSGShape3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgShape3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CSGSphere3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGSphere3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgShape3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgPrimitive3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgSphere3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CSGTorus3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGTorus3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgShape3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgPrimitive3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CsgTorus3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Camera2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Camera2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Camera2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Camera3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Camera3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Camera3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CanvasGroup" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CanvasGroupMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasGroup>::new_unchecked(instance_id),
+            ),
+        )),
+        "CanvasItem" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+            ),
+        )),
+        "CanvasLayer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasLayerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasLayer>::new_unchecked(instance_id),
+            ),
+        )),
+        "CanvasModulate" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CanvasModulateMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasModulate>::new_unchecked(instance_id),
+            ),
+        )),
+        "CenterContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            CenterContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CenterContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "CharacterBody2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            CharacterBody2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CharacterBody2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CharacterBody3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            CharacterBody3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CharacterBody3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CheckBox" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            CheckBoxMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BaseButton>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Button>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CheckBox>::new_unchecked(instance_id),
+            ),
+        )),
+        "CheckButton" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            CheckButtonMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BaseButton>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Button>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CheckButton>::new_unchecked(instance_id),
+            ),
+        )),
+        "CodeEdit" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TextEditMarker,
+            CodeEditMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TextEdit>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CodeEdit>::new_unchecked(instance_id),
+            ),
+        )),
+        "CollisionObject2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CollisionObject3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CollisionPolygon2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionPolygon2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionPolygon2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CollisionPolygon3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionPolygon3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionPolygon3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CollisionShape2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionShape2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionShape2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "CollisionShape3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionShape3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionShape3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "ColorPicker" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            VBoxContainerMarker,
+            ColorPickerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BoxContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VBoxContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ColorPicker>::new_unchecked(instance_id),
+            ),
+        )),
+        "ColorPickerButton" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            ColorPickerButtonMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BaseButton>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Button>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ColorPickerButton>::new_unchecked(instance_id),
+            ),
+        )),
+        "ColorRect" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ColorRectMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ColorRect>::new_unchecked(instance_id),
+            ),
+        )),
+        "ConeTwistJoint3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            ConeTwistJoint3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ConeTwistJoint3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "ConfirmationDialog" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            AcceptDialogMarker,
+            ConfirmationDialogMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Window>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AcceptDialog>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ConfirmationDialog>::new_unchecked(instance_id),
+            ),
+        )),
+        "Container" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+            ),
+        )),
+        "Control" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+            ),
+        )),
+        "DampedSpringJoint2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Joint2DMarker,
+            DampedSpringJoint2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::DampedSpringJoint2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Decal" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            DecalMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Decal>::new_unchecked(instance_id),
+            ),
+        )),
+        "DirectionalLight2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Light2DMarker,
+            DirectionalLight2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Light2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::DirectionalLight2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "DirectionalLight3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            Light3DMarker,
+            DirectionalLight3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Light3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::DirectionalLight3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "FileDialog" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            AcceptDialogMarker,
+            ConfirmationDialogMarker,
+            FileDialogMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Window>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AcceptDialog>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ConfirmationDialog>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::FileDialog>::new_unchecked(instance_id),
+            ),
+        )),
+        "FileSystemDock" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            VBoxContainerMarker,
+            FileSystemDockMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BoxContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VBoxContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::FileSystemDock>::new_unchecked(instance_id),
+            ),
+        )),
+        "FlowContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            FlowContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::FlowContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "FogVolume" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            FogVolumeMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::FogVolume>::new_unchecked(instance_id),
+            ),
+        )),
+        "GPUParticles2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            GPUParticles2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticles2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "GPUParticles3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            GPUParticles3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticles3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "GPUParticlesAttractor3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesAttractor3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesAttractor3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "GPUParticlesAttractorBox3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesAttractor3DMarker,
+            GPUParticlesAttractorBox3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesAttractor3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesAttractorBox3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "GPUParticlesAttractorSphere3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesAttractor3DMarker,
+            GPUParticlesAttractorSphere3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesAttractor3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesAttractorSphere3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "GPUParticlesAttractorVectorField3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesAttractor3DMarker,
+            GPUParticlesAttractorVectorField3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesAttractor3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesAttractorVectorField3D>::new_unchecked(
+                    instance_id,
+                ),
+            ),
+        )),
+        "GPUParticlesCollision3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesCollision3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "GPUParticlesCollisionBox3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            GPUParticlesCollisionBox3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesCollision3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesCollisionBox3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "GPUParticlesCollisionHeightField3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            GPUParticlesCollisionHeightField3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesCollision3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesCollisionHeightField3D>::new_unchecked(
+                    instance_id,
+                ),
+            ),
+        )),
+        "GPUParticlesCollisionSDF3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            GPUParticlesCollisionSDF3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesCollision3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesCollisionSdf3d>::new_unchecked(instance_id),
+            ),
+        )),
+        "GPUParticlesCollisionSphere3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            GPUParticlesCollisionSphere3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesCollision3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GpuParticlesCollisionSphere3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Generic6DOFJoint3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            Generic6DOFJoint3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Generic6DofJoint3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "GeometryInstance3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "GraphEdit" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            GraphEditMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+            ),
+        )),
+        "GraphElement" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            GraphElementMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+            ),
+        )),
+        "GraphFrame" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            GraphElementMarker,
+            GraphFrameMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+            ),
+        )),
+        "GraphNode" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            GraphElementMarker,
+            GraphNodeMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+            ),
+        )),
+        "GridContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            GridContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GridContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "GridMap" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            GridMapMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GridMap>::new_unchecked(instance_id),
+            ),
+        )),
+        "GridMapEditorPlugin" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            GridMapEditorPluginMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::EditorPlugin>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GridMapEditorPlugin>::new_unchecked(instance_id),
+            ),
+        )),
+        "GrooveJoint2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Joint2DMarker,
+            GrooveJoint2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GrooveJoint2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "HBoxContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            HBoxContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BoxContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HBoxContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "HFlowContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            FlowContainerMarker,
+            HFlowContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::FlowContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HFlowContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "HScrollBar" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            ScrollBarMarker,
+            HScrollBarMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ScrollBar>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HScrollBar>::new_unchecked(instance_id),
+            ),
+        )),
+        "HSeparator" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            SeparatorMarker,
+            HSeparatorMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Separator>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HSeparator>::new_unchecked(instance_id),
+            ),
+        )),
+        "HSlider" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            SliderMarker,
+            HSliderMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Slider>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HSlider>::new_unchecked(instance_id),
+            ),
+        )),
+        "HSplitContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            SplitContainerMarker,
+            HSplitContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SplitContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HSplitContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "HTTPRequest" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            HTTPRequestMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HttpRequest>::new_unchecked(instance_id),
+            ),
+        )),
+        "HingeJoint3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            HingeJoint3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HingeJoint3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "InstancePlaceholder" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            InstancePlaceholderMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::InstancePlaceholder>::new_unchecked(instance_id),
+            ),
+        )),
+        "ItemList" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ItemListMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ItemList>::new_unchecked(instance_id),
+            ),
+        )),
+        "Joint2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Joint2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Joint3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Label" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            LabelMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Label>::new_unchecked(instance_id),
+            ),
+        )),
+        "Label3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            Label3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Label3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Light2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Light2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Light2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Light3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            Light3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Light3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "LightOccluder2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            LightOccluder2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::LightOccluder2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "LightmapGI" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            LightmapGIMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::LightmapGi>::new_unchecked(instance_id),
+            ),
+        )),
+        "LightmapProbe" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            LightmapProbeMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::LightmapProbe>::new_unchecked(instance_id),
+            ),
+        )),
+        "Line2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Line2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Line2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "LineEdit" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            LineEditMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::LineEdit>::new_unchecked(instance_id),
+            ),
+        )),
+        "LinkButton" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            LinkButtonMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BaseButton>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::LinkButton>::new_unchecked(instance_id),
+            ),
+        )),
+        "LookAtModifier3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            LookAtModifier3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SkeletonModifier3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::LookAtModifier3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "MarginContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            MarginContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MarginContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "Marker2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Marker2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Marker2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Marker3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Marker3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Marker3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "MenuBar" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            MenuBarMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MenuBar>::new_unchecked(instance_id),
+            ),
+        )),
+        "MenuButton" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            MenuButtonMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BaseButton>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Button>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MenuButton>::new_unchecked(instance_id),
+            ),
+        )),
+        "MeshInstance2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            MeshInstance2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MeshInstance2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "MeshInstance3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            MeshInstance3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MeshInstance3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "MultiMeshInstance2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            MultiMeshInstance2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MultiMeshInstance2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "MultiMeshInstance3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            MultiMeshInstance3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MultiMeshInstance3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "MultiplayerSpawner" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            MultiplayerSpawnerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MultiplayerSpawner>::new_unchecked(instance_id),
+            ),
+        )),
+        "MultiplayerSynchronizer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            MultiplayerSynchronizerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MultiplayerSynchronizer>::new_unchecked(instance_id),
+            ),
+        )),
+        "NavigationAgent2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            NavigationAgent2DMarker,
+            (TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),),
+        )),
+        "NavigationAgent3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            NavigationAgent3DMarker,
+            (TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),),
+        )),
+        "NavigationLink2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            NavigationLink2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "NavigationLink3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            NavigationLink3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "NavigationObstacle2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            NavigationObstacle2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "NavigationObstacle3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            NavigationObstacle3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "NavigationRegion2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            NavigationRegion2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "NavigationRegion3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            NavigationRegion3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "NinePatchRect" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            NinePatchRectMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::NinePatchRect>::new_unchecked(instance_id),
+            ),
+        )),
+        "Node2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Node3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "OccluderInstance3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            OccluderInstance3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::OccluderInstance3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "OmniLight3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            Light3DMarker,
+            OmniLight3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Light3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::OmniLight3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "OpenXRBindingModifierEditor" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            PanelContainerMarker,
+            OpenXRBindingModifierEditorMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PanelContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "OpenXRCompositionLayer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            OpenXRCompositionLayerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "OpenXRCompositionLayerCylinder" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            OpenXRCompositionLayerMarker,
+            OpenXRCompositionLayerCylinderMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "OpenXRCompositionLayerEquirect" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            OpenXRCompositionLayerMarker,
+            OpenXRCompositionLayerEquirectMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "OpenXRCompositionLayerQuad" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            OpenXRCompositionLayerMarker,
+            OpenXRCompositionLayerQuadMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "OpenXRHand" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            OpenXRHandMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "OpenXRInteractionProfileEditor" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            HBoxContainerMarker,
+            OpenXRInteractionProfileEditorBaseMarker,
+            OpenXRInteractionProfileEditorMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BoxContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HBoxContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "OpenXRInteractionProfileEditorBase" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            HBoxContainerMarker,
+            OpenXRInteractionProfileEditorBaseMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BoxContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::HBoxContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "OpenXRVisibilityMask" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            OpenXRVisibilityMaskMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "OptionButton" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            OptionButtonMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BaseButton>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Button>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::OptionButton>::new_unchecked(instance_id),
+            ),
+        )),
+        "Panel" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            PanelMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Panel>::new_unchecked(instance_id),
+            ),
+        )),
+        "PanelContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            PanelContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PanelContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "Parallax2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Parallax2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "ParallaxBackground" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasLayerMarker,
+            ParallaxBackgroundMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasLayer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ParallaxBackground>::new_unchecked(instance_id),
+            ),
+        )),
+        "ParallaxLayer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            ParallaxLayerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ParallaxLayer>::new_unchecked(instance_id),
+            ),
+        )),
+        "Path2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Path2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Path2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Path3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Path3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Path3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PathFollow2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            PathFollow2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PathFollow2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PathFollow3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            PathFollow3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PathFollow3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PhysicalBone2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            RigidBody2DMarker,
+            PhysicalBone2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RigidBody2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicalBone2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PhysicalBone3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            PhysicalBone3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicalBone3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PhysicalBoneSimulator3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            PhysicalBoneSimulator3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SkeletonModifier3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicalBoneSimulator3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PhysicsBody2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PhysicsBody3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PinJoint2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Joint2DMarker,
+            PinJoint2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PinJoint2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PinJoint3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            PinJoint3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PinJoint3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "PointLight2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Light2DMarker,
+            PointLight2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Light2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PointLight2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Polygon2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Polygon2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Polygon2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Popup" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            PopupMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Window>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Popup>::new_unchecked(instance_id),
+            ),
+        )),
+        "PopupMenu" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            PopupMarker,
+            PopupMenuMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Window>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Popup>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PopupMenu>::new_unchecked(instance_id),
+            ),
+        )),
+        "PopupPanel" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            PopupMarker,
+            PopupPanelMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Window>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Popup>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PopupPanel>::new_unchecked(instance_id),
+            ),
+        )),
+        "ProgressBar" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            ProgressBarMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ProgressBar>::new_unchecked(instance_id),
+            ),
+        )),
+        "Range" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+            ),
+        )),
+        "RayCast2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            RayCast2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RayCast2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "RayCast3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            RayCast3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RayCast3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "ReferenceRect" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ReferenceRectMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ReferenceRect>::new_unchecked(instance_id),
+            ),
+        )),
+        "ReflectionProbe" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            ReflectionProbeMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ReflectionProbe>::new_unchecked(instance_id),
+            ),
+        )),
+        "RemoteTransform2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            RemoteTransform2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RemoteTransform2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "RemoteTransform3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            RemoteTransform3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RemoteTransform3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "ResourcePreloader" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ResourcePreloaderMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ResourcePreloader>::new_unchecked(instance_id),
+            ),
+        )),
+        "RetargetModifier3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            RetargetModifier3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SkeletonModifier3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RetargetModifier3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "RichTextLabel" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RichTextLabelMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RichTextLabel>::new_unchecked(instance_id),
+            ),
+        )),
+        "RigidBody2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            RigidBody2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RigidBody2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "RigidBody3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            RigidBody3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RigidBody3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "RootMotionView" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            RootMotionViewMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RootMotionView>::new_unchecked(instance_id),
+            ),
+        )),
+        "ScriptCreateDialog" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            AcceptDialogMarker,
+            ConfirmationDialogMarker,
+            ScriptCreateDialogMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Window>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::AcceptDialog>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ConfirmationDialog>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ScriptCreateDialog>::new_unchecked(instance_id),
+            ),
+        )),
+        "ScrollBar" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            ScrollBarMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ScrollBar>::new_unchecked(instance_id),
+            ),
+        )),
+        "ScrollContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            ScrollContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ScrollContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "Separator" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            SeparatorMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Separator>::new_unchecked(instance_id),
+            ),
+        )),
+        "ShaderGlobalsOverride" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ShaderGlobalsOverrideMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ShaderGlobalsOverride>::new_unchecked(instance_id),
+            ),
+        )),
+        "ShapeCast2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            ShapeCast2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ShapeCast2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "ShapeCast3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            ShapeCast3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ShapeCast3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Skeleton2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Skeleton2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Skeleton2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Skeleton3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Skeleton3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Skeleton3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SkeletonIK3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            SkeletonIK3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SkeletonModifier3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SkeletonIk3d>::new_unchecked(instance_id),
+            ),
+        )),
+        "SkeletonModifier3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SkeletonModifier3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Slider" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            SliderMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Slider>::new_unchecked(instance_id),
+            ),
+        )),
+        "SliderJoint3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            SliderJoint3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Joint3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SliderJoint3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SoftBody3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            MeshInstance3DMarker,
+            SoftBody3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::MeshInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SoftBody3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SpinBox" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            SpinBoxMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpinBox>::new_unchecked(instance_id),
+            ),
+        )),
+        "SplitContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            SplitContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SplitContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "SpotLight3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            Light3DMarker,
+            SpotLight3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Light3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpotLight3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SpringArm3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SpringArm3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpringArm3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SpringBoneCollision3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SpringBoneCollision3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpringBoneCollision3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SpringBoneCollisionCapsule3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SpringBoneCollision3DMarker,
+            SpringBoneCollisionCapsule3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpringBoneCollision3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpringBoneCollisionCapsule3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SpringBoneCollisionPlane3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SpringBoneCollision3DMarker,
+            SpringBoneCollisionPlane3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpringBoneCollision3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpringBoneCollisionPlane3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SpringBoneCollisionSphere3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SpringBoneCollision3DMarker,
+            SpringBoneCollisionSphere3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpringBoneCollision3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpringBoneCollisionSphere3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SpringBoneSimulator3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            SpringBoneSimulator3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SkeletonModifier3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpringBoneSimulator3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Sprite2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Sprite2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Sprite2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "Sprite3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            SpriteBase3DMarker,
+            Sprite3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpriteBase3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Sprite3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "SpriteBase3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            SpriteBase3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::GeometryInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SpriteBase3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "StaticBody2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            StaticBody2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::StaticBody2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "StaticBody3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            StaticBody3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::StaticBody3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "StatusIndicator" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            StatusIndicatorMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::StatusIndicator>::new_unchecked(instance_id),
+            ),
+        )),
+        "SubViewport" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            SubViewportMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SubViewport>::new_unchecked(instance_id),
+            ),
+        )),
+        "SubViewportContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            SubViewportContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SubViewportContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "TabBar" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TabBarMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TabBar>::new_unchecked(instance_id),
+            ),
+        )),
+        "TabContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            TabContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TabContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "TextEdit" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TextEditMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TextEdit>::new_unchecked(instance_id),
+            ),
+        )),
+        "TextureButton" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            TextureButtonMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BaseButton>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TextureButton>::new_unchecked(instance_id),
+            ),
+        )),
+        "TextureProgressBar" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            TextureProgressBarMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TextureProgressBar>::new_unchecked(instance_id),
+            ),
+        )),
+        "TextureRect" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TextureRectMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TextureRect>::new_unchecked(instance_id),
+            ),
+        )),
+        "TileMap" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            TileMapMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TileMap>::new_unchecked(instance_id),
+            ),
+        )),
+        "TileMapLayer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            TileMapLayerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TileMapLayer>::new_unchecked(instance_id),
+            ),
+        )),
+        "Timer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            TimerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Timer>::new_unchecked(instance_id),
+            ),
+        )),
+        "TouchScreenButton" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            TouchScreenButtonMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::TouchScreenButton>::new_unchecked(instance_id),
+            ),
+        )),
+        "Tree" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TreeMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Tree>::new_unchecked(instance_id),
+            ),
+        )),
+        "VBoxContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            VBoxContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::BoxContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VBoxContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "VFlowContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            FlowContainerMarker,
+            VFlowContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::FlowContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VFlowContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "VScrollBar" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            ScrollBarMarker,
+            VScrollBarMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::ScrollBar>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VScrollBar>::new_unchecked(instance_id),
+            ),
+        )),
+        "VSeparator" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            SeparatorMarker,
+            VSeparatorMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Separator>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VSeparator>::new_unchecked(instance_id),
+            ),
+        )),
+        "VSlider" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            SliderMarker,
+            VSliderMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Range>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Slider>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VSlider>::new_unchecked(instance_id),
+            ),
+        )),
+        "VSplitContainer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            SplitContainerMarker,
+            VSplitContainerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Container>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SplitContainer>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VSplitContainer>::new_unchecked(instance_id),
+            ),
+        )),
+        "VehicleBody3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            RigidBody3DMarker,
+            VehicleBody3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CollisionObject3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::PhysicsBody3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::RigidBody3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VehicleBody3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "VehicleWheel3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VehicleWheel3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VehicleWheel3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "VideoStreamPlayer" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            VideoStreamPlayerMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Control>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VideoStreamPlayer>::new_unchecked(instance_id),
+            ),
+        )),
+        "Viewport" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+            ),
+        )),
+        "VisibleOnScreenEnabler2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            VisibleOnScreenNotifier2DMarker,
+            VisibleOnScreenEnabler2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisibleOnScreenNotifier2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisibleOnScreenEnabler2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "VisibleOnScreenEnabler3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            VisibleOnScreenNotifier3DMarker,
+            VisibleOnScreenEnabler3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisibleOnScreenNotifier3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisibleOnScreenEnabler3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "VisibleOnScreenNotifier2D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            VisibleOnScreenNotifier2DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::CanvasItem>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node2D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisibleOnScreenNotifier2D>::new_unchecked(instance_id),
+            ),
+        )),
+        "VisibleOnScreenNotifier3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            VisibleOnScreenNotifier3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisibleOnScreenNotifier3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "VisualInstance3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "VoxelGI" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            VoxelGIMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VisualInstance3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::VoxelGi>::new_unchecked(instance_id),
+            ),
+        )),
+        "Window" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Viewport>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Window>::new_unchecked(instance_id),
+            ),
+        )),
+        "WorldEnvironment" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            WorldEnvironmentMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::WorldEnvironment>::new_unchecked(instance_id),
+            ),
+        )),
+        "XRAnchor3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            XRNode3DMarker,
+            XRAnchor3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::XrNode3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::XrAnchor3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "XRBodyModifier3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            XRBodyModifier3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SkeletonModifier3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "XRCamera3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            Camera3DMarker,
+            XRCamera3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Camera3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::XrCamera3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "XRController3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            XRNode3DMarker,
+            XRController3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::XrNode3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::XrController3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "XRFaceModifier3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            XRFaceModifier3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "XRHandModifier3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            XRHandModifier3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::SkeletonModifier3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::XrHandModifier3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "XRNode3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            XRNode3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::XrNode3D>::new_unchecked(instance_id),
+            ),
+        )),
+        "XROrigin3D" => entity_commands.insert((
+            markers,
+            NodeMarker,
+            Node3DMarker,
+            XROrigin3DMarker,
+            (
+                TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::Node3D>::new_unchecked(instance_id),
+                TypedNodeHandle::<gd::XrOrigin3D>::new_unchecked(instance_id),
+            ),
+        )),
+        _ => entity_commands.insert((
+            markers,
+            NodeMarker,
+            TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+        )),
+    };
 }
-fn remove_universal_node_types_comprehensive(entity_commands: &mut EntityCommands) {
-    entity_commands
-        .remove::<AnimationMixerMarker>()
-        .remove::<AudioStreamPlayerMarker>()
-        .remove::<CanvasItemMarker>()
-        .remove::<CanvasLayerMarker>()
-        .remove::<HTTPRequestMarker>()
-        .remove::<InstancePlaceholderMarker>()
-        .remove::<MultiplayerSpawnerMarker>()
-        .remove::<MultiplayerSynchronizerMarker>()
-        .remove::<Node3DMarker>()
-        .remove::<ResourcePreloaderMarker>()
-        .remove::<ShaderGlobalsOverrideMarker>()
-        .remove::<TimerMarker>()
-        .remove::<ViewportMarker>();
+
+fn remove_node_type_markers(entity: &mut EntityWorldMut, class: &str) {
+    match class {
+        "AcceptDialog" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            AcceptDialogMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Viewport>,
+                TypedNodeHandle<gd::Window>,
+                TypedNodeHandle<gd::AcceptDialog>,
+            ),
+        )>(),
+        "AnimatableBody2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            StaticBody2DMarker,
+            AnimatableBody2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionObject2D>,
+                TypedNodeHandle<gd::PhysicsBody2D>,
+                TypedNodeHandle<gd::StaticBody2D>,
+                TypedNodeHandle<gd::AnimatableBody2D>,
+            ),
+        )>(),
+        "AnimatableBody3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            StaticBody3DMarker,
+            AnimatableBody3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionObject3D>,
+                TypedNodeHandle<gd::PhysicsBody3D>,
+                TypedNodeHandle<gd::StaticBody3D>,
+                TypedNodeHandle<gd::AnimatableBody3D>,
+            ),
+        )>(),
+        "AnimatedSprite2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            AnimatedSprite2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::AnimatedSprite2D>,
+            ),
+        )>(),
+        "AnimatedSprite3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            SpriteBase3DMarker,
+            AnimatedSprite3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::SpriteBase3D>,
+                TypedNodeHandle<gd::AnimatedSprite3D>,
+            ),
+        )>(),
+        "AnimationMixer" => entity.remove::<(
+            NodeMarker,
+            AnimationMixerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::AnimationMixer>,
+            ),
+        )>(),
+        "AnimationPlayer" => entity.remove::<(
+            NodeMarker,
+            AnimationMixerMarker,
+            AnimationPlayerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::AnimationMixer>,
+                TypedNodeHandle<gd::AnimationPlayer>,
+            ),
+        )>(),
+        "AnimationTree" => entity.remove::<(
+            NodeMarker,
+            AnimationMixerMarker,
+            AnimationTreeMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::AnimationMixer>,
+                TypedNodeHandle<gd::AnimationTree>,
+            ),
+        )>(),
+        "Area2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            Area2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionObject2D>,
+                TypedNodeHandle<gd::Area2D>,
+            ),
+        )>(),
+        "Area3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            Area3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionObject3D>,
+                TypedNodeHandle<gd::Area3D>,
+            ),
+        )>(),
+        "AspectRatioContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            AspectRatioContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::AspectRatioContainer>,
+            ),
+        )>(),
+        "AudioListener2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            AudioListener2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::AudioListener2D>,
+            ),
+        )>(),
+        "AudioListener3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            AudioListener3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::AudioListener3D>,
+            ),
+        )>(),
+        "AudioStreamPlayer" => entity.remove::<(
+            NodeMarker,
+            AudioStreamPlayerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::AudioStreamPlayer>,
+            ),
+        )>(),
+        "AudioStreamPlayer2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            AudioStreamPlayer2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::AudioStreamPlayer2D>,
+            ),
+        )>(),
+        "AudioStreamPlayer3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            AudioStreamPlayer3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::AudioStreamPlayer3D>,
+            ),
+        )>(),
+        "BackBufferCopy" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            BackBufferCopyMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::BackBufferCopy>,
+            ),
+        )>(),
+        "BaseButton" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::BaseButton>,
+            ),
+        )>(),
+        "Bone2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Bone2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Bone2D>,
+            ),
+        )>(),
+        "BoneAttachment3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            BoneAttachment3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::BoneAttachment3D>,
+            ),
+        )>(),
+        "BoxContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::BoxContainer>,
+            ),
+        )>(),
+        "Button" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::BaseButton>,
+                TypedNodeHandle<gd::Button>,
+            ),
+        )>(),
+        "CPUParticles2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CPUParticles2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CpuParticles2D>,
+            ),
+        )>(),
+        "CPUParticles3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CPUParticles3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CpuParticles3D>,
+            ),
+        )>(),
+        "CSGBox3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGBox3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CsgShape3D>,
+                TypedNodeHandle<gd::CsgPrimitive3D>,
+                TypedNodeHandle<gd::CsgBox3D>,
+            ),
+        )>(),
+        "CSGCombiner3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGCombiner3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CsgShape3D>,
+                TypedNodeHandle<gd::CsgCombiner3D>,
+            ),
+        )>(),
+        "CSGCylinder3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGCylinder3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CsgShape3D>,
+                TypedNodeHandle<gd::CsgPrimitive3D>,
+                TypedNodeHandle<gd::CsgCylinder3D>,
+            ),
+        )>(),
+        "CSGMesh3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGMesh3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CsgShape3D>,
+                TypedNodeHandle<gd::CsgPrimitive3D>,
+                TypedNodeHandle<gd::CsgMesh3D>,
+            ),
+        )>(),
+        "CSGPolygon3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGPolygon3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CsgShape3D>,
+                TypedNodeHandle<gd::CsgPrimitive3D>,
+                TypedNodeHandle<gd::CsgPolygon3D>,
+            ),
+        )>(),
+        "CSGPrimitive3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CsgShape3D>,
+                TypedNodeHandle<gd::CsgPrimitive3D>,
+            ),
+        )>(),
+        "CSGShape3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CsgShape3D>,
+            ),
+        )>(),
+        "CSGSphere3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGSphere3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CsgShape3D>,
+                TypedNodeHandle<gd::CsgPrimitive3D>,
+                TypedNodeHandle<gd::CsgSphere3D>,
+            ),
+        )>(),
+        "CSGTorus3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            CSGShape3DMarker,
+            CSGPrimitive3DMarker,
+            CSGTorus3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::CsgShape3D>,
+                TypedNodeHandle<gd::CsgPrimitive3D>,
+                TypedNodeHandle<gd::CsgTorus3D>,
+            ),
+        )>(),
+        "Camera2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Camera2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Camera2D>,
+            ),
+        )>(),
+        "Camera3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Camera3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Camera3D>,
+            ),
+        )>(),
+        "CanvasGroup" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CanvasGroupMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CanvasGroup>,
+            ),
+        )>(),
+        "CanvasItem" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::CanvasItem>),
+        )>(),
+        "CanvasLayer" => entity.remove::<(
+            NodeMarker,
+            CanvasLayerMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::CanvasLayer>),
+        )>(),
+        "CanvasModulate" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CanvasModulateMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CanvasModulate>,
+            ),
+        )>(),
+        "CenterContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            CenterContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::CenterContainer>,
+            ),
+        )>(),
+        "CharacterBody2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            CharacterBody2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionObject2D>,
+                TypedNodeHandle<gd::PhysicsBody2D>,
+                TypedNodeHandle<gd::CharacterBody2D>,
+            ),
+        )>(),
+        "CharacterBody3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            CharacterBody3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionObject3D>,
+                TypedNodeHandle<gd::PhysicsBody3D>,
+                TypedNodeHandle<gd::CharacterBody3D>,
+            ),
+        )>(),
+        "CheckBox" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            CheckBoxMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::BaseButton>,
+                TypedNodeHandle<gd::Button>,
+                TypedNodeHandle<gd::CheckBox>,
+            ),
+        )>(),
+        "CheckButton" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            CheckButtonMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::BaseButton>,
+                TypedNodeHandle<gd::Button>,
+                TypedNodeHandle<gd::CheckButton>,
+            ),
+        )>(),
+        "CodeEdit" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TextEditMarker,
+            CodeEditMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::TextEdit>,
+                TypedNodeHandle<gd::CodeEdit>,
+            ),
+        )>(),
+        "CollisionObject2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionObject2D>,
+            ),
+        )>(),
+        "CollisionObject3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionObject3D>,
+            ),
+        )>(),
+        "CollisionPolygon2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionPolygon2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionPolygon2D>,
+            ),
+        )>(),
+        "CollisionPolygon3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionPolygon3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionPolygon3D>,
+            ),
+        )>(),
+        "CollisionShape2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionShape2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionShape2D>,
+            ),
+        )>(),
+        "CollisionShape3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionShape3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionShape3D>,
+            ),
+        )>(),
+        "ColorPicker" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            VBoxContainerMarker,
+            ColorPickerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::BoxContainer>,
+                TypedNodeHandle<gd::VBoxContainer>,
+                TypedNodeHandle<gd::ColorPicker>,
+            ),
+        )>(),
+        "ColorPickerButton" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            ColorPickerButtonMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::BaseButton>,
+                TypedNodeHandle<gd::Button>,
+                TypedNodeHandle<gd::ColorPickerButton>,
+            ),
+        )>(),
+        "ColorRect" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ColorRectMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::ColorRect>,
+            ),
+        )>(),
+        "ConeTwistJoint3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            ConeTwistJoint3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Joint3D>,
+                TypedNodeHandle<gd::ConeTwistJoint3D>,
+            ),
+        )>(),
+        "ConfirmationDialog" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            AcceptDialogMarker,
+            ConfirmationDialogMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Viewport>,
+                TypedNodeHandle<gd::Window>,
+                TypedNodeHandle<gd::AcceptDialog>,
+                TypedNodeHandle<gd::ConfirmationDialog>,
+            ),
+        )>(),
+        "Container" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+            ),
+        )>(),
+        "Control" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+            ),
+        )>(),
+        "DampedSpringJoint2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Joint2DMarker,
+            DampedSpringJoint2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Joint2D>,
+                TypedNodeHandle<gd::DampedSpringJoint2D>,
+            ),
+        )>(),
+        "Decal" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            DecalMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::Decal>,
+            ),
+        )>(),
+        "DirectionalLight2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Light2DMarker,
+            DirectionalLight2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Light2D>,
+                TypedNodeHandle<gd::DirectionalLight2D>,
+            ),
+        )>(),
+        "DirectionalLight3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            Light3DMarker,
+            DirectionalLight3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::Light3D>,
+                TypedNodeHandle<gd::DirectionalLight3D>,
+            ),
+        )>(),
+        "FileDialog" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            AcceptDialogMarker,
+            ConfirmationDialogMarker,
+            FileDialogMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Viewport>,
+                TypedNodeHandle<gd::Window>,
+                TypedNodeHandle<gd::AcceptDialog>,
+                TypedNodeHandle<gd::ConfirmationDialog>,
+                TypedNodeHandle<gd::FileDialog>,
+            ),
+        )>(),
+        "FileSystemDock" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            VBoxContainerMarker,
+            FileSystemDockMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::BoxContainer>,
+                TypedNodeHandle<gd::VBoxContainer>,
+                TypedNodeHandle<gd::FileSystemDock>,
+            ),
+        )>(),
+        "FlowContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            FlowContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::FlowContainer>,
+            ),
+        )>(),
+        "FogVolume" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            FogVolumeMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::FogVolume>,
+            ),
+        )>(),
+        "GPUParticles2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            GPUParticles2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::GpuParticles2D>,
+            ),
+        )>(),
+        "GPUParticles3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            GPUParticles3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::GpuParticles3D>,
+            ),
+        )>(),
+        "GPUParticlesAttractor3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesAttractor3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GpuParticlesAttractor3D>,
+            ),
+        )>(),
+        "GPUParticlesAttractorBox3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesAttractor3DMarker,
+            GPUParticlesAttractorBox3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GpuParticlesAttractor3D>,
+                TypedNodeHandle<gd::GpuParticlesAttractorBox3D>,
+            ),
+        )>(),
+        "GPUParticlesAttractorSphere3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesAttractor3DMarker,
+            GPUParticlesAttractorSphere3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GpuParticlesAttractor3D>,
+                TypedNodeHandle<gd::GpuParticlesAttractorSphere3D>,
+            ),
+        )>(),
+        "GPUParticlesAttractorVectorField3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesAttractor3DMarker,
+            GPUParticlesAttractorVectorField3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GpuParticlesAttractor3D>,
+                TypedNodeHandle<gd::GpuParticlesAttractorVectorField3D>,
+            ),
+        )>(),
+        "GPUParticlesCollision3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GpuParticlesCollision3D>,
+            ),
+        )>(),
+        "GPUParticlesCollisionBox3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            GPUParticlesCollisionBox3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GpuParticlesCollision3D>,
+                TypedNodeHandle<gd::GpuParticlesCollisionBox3D>,
+            ),
+        )>(),
+        "GPUParticlesCollisionHeightField3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            GPUParticlesCollisionHeightField3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GpuParticlesCollision3D>,
+                TypedNodeHandle<gd::GpuParticlesCollisionHeightField3D>,
+            ),
+        )>(),
+        "GPUParticlesCollisionSDF3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            GPUParticlesCollisionSDF3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GpuParticlesCollision3D>,
+                TypedNodeHandle<gd::GpuParticlesCollisionSdf3d>,
+            ),
+        )>(),
+        "GPUParticlesCollisionSphere3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GPUParticlesCollision3DMarker,
+            GPUParticlesCollisionSphere3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GpuParticlesCollision3D>,
+                TypedNodeHandle<gd::GpuParticlesCollisionSphere3D>,
+            ),
+        )>(),
+        "Generic6DOFJoint3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            Generic6DOFJoint3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Joint3D>,
+                TypedNodeHandle<gd::Generic6DofJoint3D>,
+            ),
+        )>(),
+        "GeometryInstance3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+            ),
+        )>(),
+        "GraphEdit" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            GraphEditMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+            ),
+        )>(),
+        "GraphElement" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            GraphElementMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+            ),
+        )>(),
+        "GraphFrame" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            GraphElementMarker,
+            GraphFrameMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+            ),
+        )>(),
+        "GraphNode" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            GraphElementMarker,
+            GraphNodeMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+            ),
+        )>(),
+        "GridContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            GridContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::GridContainer>,
+            ),
+        )>(),
+        "GridMap" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            GridMapMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::GridMap>,
+            ),
+        )>(),
+        "GridMapEditorPlugin" => entity.remove::<(
+            NodeMarker,
+            GridMapEditorPluginMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::EditorPlugin>,
+                TypedNodeHandle<gd::GridMapEditorPlugin>,
+            ),
+        )>(),
+        "GrooveJoint2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Joint2DMarker,
+            GrooveJoint2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Joint2D>,
+                TypedNodeHandle<gd::GrooveJoint2D>,
+            ),
+        )>(),
+        "HBoxContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            HBoxContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::BoxContainer>,
+                TypedNodeHandle<gd::HBoxContainer>,
+            ),
+        )>(),
+        "HFlowContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            FlowContainerMarker,
+            HFlowContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::FlowContainer>,
+                TypedNodeHandle<gd::HFlowContainer>,
+            ),
+        )>(),
+        "HScrollBar" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            ScrollBarMarker,
+            HScrollBarMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+                TypedNodeHandle<gd::ScrollBar>,
+                TypedNodeHandle<gd::HScrollBar>,
+            ),
+        )>(),
+        "HSeparator" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            SeparatorMarker,
+            HSeparatorMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Separator>,
+                TypedNodeHandle<gd::HSeparator>,
+            ),
+        )>(),
+        "HSlider" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            SliderMarker,
+            HSliderMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+                TypedNodeHandle<gd::Slider>,
+                TypedNodeHandle<gd::HSlider>,
+            ),
+        )>(),
+        "HSplitContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            SplitContainerMarker,
+            HSplitContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::SplitContainer>,
+                TypedNodeHandle<gd::HSplitContainer>,
+            ),
+        )>(),
+        "HTTPRequest" => entity.remove::<(
+            NodeMarker,
+            HTTPRequestMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::HttpRequest>),
+        )>(),
+        "HingeJoint3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            HingeJoint3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Joint3D>,
+                TypedNodeHandle<gd::HingeJoint3D>,
+            ),
+        )>(),
+        "InstancePlaceholder" => entity.remove::<(
+            NodeMarker,
+            InstancePlaceholderMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::InstancePlaceholder>,
+            ),
+        )>(),
+        "ItemList" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ItemListMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::ItemList>,
+            ),
+        )>(),
+        "Joint2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Joint2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Joint2D>,
+            ),
+        )>(),
+        "Joint3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Joint3D>,
+            ),
+        )>(),
+        "Label" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            LabelMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Label>,
+            ),
+        )>(),
+        "Label3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            Label3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::Label3D>,
+            ),
+        )>(),
+        "Light2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Light2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Light2D>,
+            ),
+        )>(),
+        "Light3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            Light3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::Light3D>,
+            ),
+        )>(),
+        "LightOccluder2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            LightOccluder2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::LightOccluder2D>,
+            ),
+        )>(),
+        "LightmapGI" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            LightmapGIMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::LightmapGi>,
+            ),
+        )>(),
+        "LightmapProbe" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            LightmapProbeMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::LightmapProbe>,
+            ),
+        )>(),
+        "Line2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Line2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Line2D>,
+            ),
+        )>(),
+        "LineEdit" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            LineEditMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::LineEdit>,
+            ),
+        )>(),
+        "LinkButton" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            LinkButtonMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::BaseButton>,
+                TypedNodeHandle<gd::LinkButton>,
+            ),
+        )>(),
+        "LookAtModifier3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            LookAtModifier3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SkeletonModifier3D>,
+                TypedNodeHandle<gd::LookAtModifier3D>,
+            ),
+        )>(),
+        "MarginContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            MarginContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::MarginContainer>,
+            ),
+        )>(),
+        "Marker2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Marker2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Marker2D>,
+            ),
+        )>(),
+        "Marker3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Marker3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Marker3D>,
+            ),
+        )>(),
+        "MenuBar" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            MenuBarMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::MenuBar>,
+            ),
+        )>(),
+        "MenuButton" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            MenuButtonMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::BaseButton>,
+                TypedNodeHandle<gd::Button>,
+                TypedNodeHandle<gd::MenuButton>,
+            ),
+        )>(),
+        "MeshInstance2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            MeshInstance2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::MeshInstance2D>,
+            ),
+        )>(),
+        "MeshInstance3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            MeshInstance3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::MeshInstance3D>,
+            ),
+        )>(),
+        "MultiMeshInstance2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            MultiMeshInstance2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::MultiMeshInstance2D>,
+            ),
+        )>(),
+        "MultiMeshInstance3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            MultiMeshInstance3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::MultiMeshInstance3D>,
+            ),
+        )>(),
+        "MultiplayerSpawner" => entity.remove::<(
+            NodeMarker,
+            MultiplayerSpawnerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::MultiplayerSpawner>,
+            ),
+        )>(),
+        "MultiplayerSynchronizer" => entity.remove::<(
+            NodeMarker,
+            MultiplayerSynchronizerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::MultiplayerSynchronizer>,
+            ),
+        )>(),
+        "NavigationAgent2D" => entity.remove::<(
+            NodeMarker,
+            NavigationAgent2DMarker,
+            (TypedNodeHandle<gd::Node>,),
+        )>(),
+        "NavigationAgent3D" => entity.remove::<(
+            NodeMarker,
+            NavigationAgent3DMarker,
+            (TypedNodeHandle<gd::Node>,),
+        )>(),
+        "NavigationLink2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            NavigationLink2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+            ),
+        )>(),
+        "NavigationLink3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            NavigationLink3DMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "NavigationObstacle2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            NavigationObstacle2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+            ),
+        )>(),
+        "NavigationObstacle3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            NavigationObstacle3DMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "NavigationRegion2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            NavigationRegion2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+            ),
+        )>(),
+        "NavigationRegion3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            NavigationRegion3DMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "NinePatchRect" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            NinePatchRectMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::NinePatchRect>,
+            ),
+        )>(),
+        "Node2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+            ),
+        )>(),
+        "Node3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "OccluderInstance3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            OccluderInstance3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::OccluderInstance3D>,
+            ),
+        )>(),
+        "OmniLight3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            Light3DMarker,
+            OmniLight3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::Light3D>,
+                TypedNodeHandle<gd::OmniLight3D>,
+            ),
+        )>(),
+        "OpenXRBindingModifierEditor" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            PanelContainerMarker,
+            OpenXRBindingModifierEditorMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::PanelContainer>,
+            ),
+        )>(),
+        "OpenXRCompositionLayer" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            OpenXRCompositionLayerMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "OpenXRCompositionLayerCylinder" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            OpenXRCompositionLayerMarker,
+            OpenXRCompositionLayerCylinderMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "OpenXRCompositionLayerEquirect" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            OpenXRCompositionLayerMarker,
+            OpenXRCompositionLayerEquirectMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "OpenXRCompositionLayerQuad" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            OpenXRCompositionLayerMarker,
+            OpenXRCompositionLayerQuadMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "OpenXRHand" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            OpenXRHandMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "OpenXRInteractionProfileEditor" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            HBoxContainerMarker,
+            OpenXRInteractionProfileEditorBaseMarker,
+            OpenXRInteractionProfileEditorMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::BoxContainer>,
+                TypedNodeHandle<gd::HBoxContainer>,
+            ),
+        )>(),
+        "OpenXRInteractionProfileEditorBase" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            HBoxContainerMarker,
+            OpenXRInteractionProfileEditorBaseMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::BoxContainer>,
+                TypedNodeHandle<gd::HBoxContainer>,
+            ),
+        )>(),
+        "OpenXRVisibilityMask" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            OpenXRVisibilityMaskMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+            ),
+        )>(),
+        "OptionButton" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            ButtonMarker,
+            OptionButtonMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::BaseButton>,
+                TypedNodeHandle<gd::Button>,
+                TypedNodeHandle<gd::OptionButton>,
+            ),
+        )>(),
+        "Panel" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            PanelMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Panel>,
+            ),
+        )>(),
+        "PanelContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            PanelContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::PanelContainer>,
+            ),
+        )>(),
+        "Parallax2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Parallax2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+            ),
+        )>(),
+        "ParallaxBackground" => entity.remove::<(
+            NodeMarker,
+            CanvasLayerMarker,
+            ParallaxBackgroundMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasLayer>,
+                TypedNodeHandle<gd::ParallaxBackground>,
+            ),
+        )>(),
+        "ParallaxLayer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            ParallaxLayerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::ParallaxLayer>,
+            ),
+        )>(),
+        "Path2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Path2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Path2D>,
+            ),
+        )>(),
+        "Path3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Path3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Path3D>,
+            ),
+        )>(),
+        "PathFollow2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            PathFollow2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::PathFollow2D>,
+            ),
+        )>(),
+        "PathFollow3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            PathFollow3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::PathFollow3D>,
+            ),
+        )>(),
+        "PhysicalBone2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            RigidBody2DMarker,
+            PhysicalBone2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionObject2D>,
+                TypedNodeHandle<gd::PhysicsBody2D>,
+                TypedNodeHandle<gd::RigidBody2D>,
+                TypedNodeHandle<gd::PhysicalBone2D>,
+            ),
+        )>(),
+        "PhysicalBone3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            PhysicalBone3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionObject3D>,
+                TypedNodeHandle<gd::PhysicsBody3D>,
+                TypedNodeHandle<gd::PhysicalBone3D>,
+            ),
+        )>(),
+        "PhysicalBoneSimulator3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            PhysicalBoneSimulator3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SkeletonModifier3D>,
+                TypedNodeHandle<gd::PhysicalBoneSimulator3D>,
+            ),
+        )>(),
+        "PhysicsBody2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionObject2D>,
+                TypedNodeHandle<gd::PhysicsBody2D>,
+            ),
+        )>(),
+        "PhysicsBody3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionObject3D>,
+                TypedNodeHandle<gd::PhysicsBody3D>,
+            ),
+        )>(),
+        "PinJoint2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Joint2DMarker,
+            PinJoint2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Joint2D>,
+                TypedNodeHandle<gd::PinJoint2D>,
+            ),
+        )>(),
+        "PinJoint3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            PinJoint3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Joint3D>,
+                TypedNodeHandle<gd::PinJoint3D>,
+            ),
+        )>(),
+        "PointLight2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Light2DMarker,
+            PointLight2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Light2D>,
+                TypedNodeHandle<gd::PointLight2D>,
+            ),
+        )>(),
+        "Polygon2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Polygon2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Polygon2D>,
+            ),
+        )>(),
+        "Popup" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            PopupMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Viewport>,
+                TypedNodeHandle<gd::Window>,
+                TypedNodeHandle<gd::Popup>,
+            ),
+        )>(),
+        "PopupMenu" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            PopupMarker,
+            PopupMenuMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Viewport>,
+                TypedNodeHandle<gd::Window>,
+                TypedNodeHandle<gd::Popup>,
+                TypedNodeHandle<gd::PopupMenu>,
+            ),
+        )>(),
+        "PopupPanel" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            PopupMarker,
+            PopupPanelMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Viewport>,
+                TypedNodeHandle<gd::Window>,
+                TypedNodeHandle<gd::Popup>,
+                TypedNodeHandle<gd::PopupPanel>,
+            ),
+        )>(),
+        "ProgressBar" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            ProgressBarMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+                TypedNodeHandle<gd::ProgressBar>,
+            ),
+        )>(),
+        "Range" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+            ),
+        )>(),
+        "RayCast2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            RayCast2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::RayCast2D>,
+            ),
+        )>(),
+        "RayCast3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            RayCast3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::RayCast3D>,
+            ),
+        )>(),
+        "ReferenceRect" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ReferenceRectMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::ReferenceRect>,
+            ),
+        )>(),
+        "ReflectionProbe" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            ReflectionProbeMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::ReflectionProbe>,
+            ),
+        )>(),
+        "RemoteTransform2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            RemoteTransform2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::RemoteTransform2D>,
+            ),
+        )>(),
+        "RemoteTransform3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            RemoteTransform3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::RemoteTransform3D>,
+            ),
+        )>(),
+        "ResourcePreloader" => entity.remove::<(
+            NodeMarker,
+            ResourcePreloaderMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::ResourcePreloader>,
+            ),
+        )>(),
+        "RetargetModifier3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            RetargetModifier3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SkeletonModifier3D>,
+                TypedNodeHandle<gd::RetargetModifier3D>,
+            ),
+        )>(),
+        "RichTextLabel" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RichTextLabelMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::RichTextLabel>,
+            ),
+        )>(),
+        "RigidBody2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            RigidBody2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionObject2D>,
+                TypedNodeHandle<gd::PhysicsBody2D>,
+                TypedNodeHandle<gd::RigidBody2D>,
+            ),
+        )>(),
+        "RigidBody3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            RigidBody3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionObject3D>,
+                TypedNodeHandle<gd::PhysicsBody3D>,
+                TypedNodeHandle<gd::RigidBody3D>,
+            ),
+        )>(),
+        "RootMotionView" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            RootMotionViewMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::RootMotionView>,
+            ),
+        )>(),
+        "ScriptCreateDialog" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            AcceptDialogMarker,
+            ConfirmationDialogMarker,
+            ScriptCreateDialogMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Viewport>,
+                TypedNodeHandle<gd::Window>,
+                TypedNodeHandle<gd::AcceptDialog>,
+                TypedNodeHandle<gd::ConfirmationDialog>,
+                TypedNodeHandle<gd::ScriptCreateDialog>,
+            ),
+        )>(),
+        "ScrollBar" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            ScrollBarMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+                TypedNodeHandle<gd::ScrollBar>,
+            ),
+        )>(),
+        "ScrollContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            ScrollContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::ScrollContainer>,
+            ),
+        )>(),
+        "Separator" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            SeparatorMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Separator>,
+            ),
+        )>(),
+        "ShaderGlobalsOverride" => entity.remove::<(
+            NodeMarker,
+            ShaderGlobalsOverrideMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::ShaderGlobalsOverride>,
+            ),
+        )>(),
+        "ShapeCast2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            ShapeCast2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::ShapeCast2D>,
+            ),
+        )>(),
+        "ShapeCast3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            ShapeCast3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::ShapeCast3D>,
+            ),
+        )>(),
+        "Skeleton2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Skeleton2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Skeleton2D>,
+            ),
+        )>(),
+        "Skeleton3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Skeleton3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Skeleton3D>,
+            ),
+        )>(),
+        "SkeletonIK3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            SkeletonIK3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SkeletonModifier3D>,
+                TypedNodeHandle<gd::SkeletonIk3d>,
+            ),
+        )>(),
+        "SkeletonModifier3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SkeletonModifier3D>,
+            ),
+        )>(),
+        "Slider" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            SliderMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+                TypedNodeHandle<gd::Slider>,
+            ),
+        )>(),
+        "SliderJoint3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Joint3DMarker,
+            SliderJoint3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Joint3D>,
+                TypedNodeHandle<gd::SliderJoint3D>,
+            ),
+        )>(),
+        "SoftBody3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            MeshInstance3DMarker,
+            SoftBody3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::MeshInstance3D>,
+                TypedNodeHandle<gd::SoftBody3D>,
+            ),
+        )>(),
+        "SpinBox" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            SpinBoxMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+                TypedNodeHandle<gd::SpinBox>,
+            ),
+        )>(),
+        "SplitContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            SplitContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::SplitContainer>,
+            ),
+        )>(),
+        "SpotLight3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            Light3DMarker,
+            SpotLight3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::Light3D>,
+                TypedNodeHandle<gd::SpotLight3D>,
+            ),
+        )>(),
+        "SpringArm3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SpringArm3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SpringArm3D>,
+            ),
+        )>(),
+        "SpringBoneCollision3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SpringBoneCollision3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SpringBoneCollision3D>,
+            ),
+        )>(),
+        "SpringBoneCollisionCapsule3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SpringBoneCollision3DMarker,
+            SpringBoneCollisionCapsule3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SpringBoneCollision3D>,
+                TypedNodeHandle<gd::SpringBoneCollisionCapsule3D>,
+            ),
+        )>(),
+        "SpringBoneCollisionPlane3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SpringBoneCollision3DMarker,
+            SpringBoneCollisionPlane3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SpringBoneCollision3D>,
+                TypedNodeHandle<gd::SpringBoneCollisionPlane3D>,
+            ),
+        )>(),
+        "SpringBoneCollisionSphere3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SpringBoneCollision3DMarker,
+            SpringBoneCollisionSphere3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SpringBoneCollision3D>,
+                TypedNodeHandle<gd::SpringBoneCollisionSphere3D>,
+            ),
+        )>(),
+        "SpringBoneSimulator3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            SpringBoneSimulator3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SkeletonModifier3D>,
+                TypedNodeHandle<gd::SpringBoneSimulator3D>,
+            ),
+        )>(),
+        "Sprite2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            Sprite2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::Sprite2D>,
+            ),
+        )>(),
+        "Sprite3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            SpriteBase3DMarker,
+            Sprite3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::SpriteBase3D>,
+                TypedNodeHandle<gd::Sprite3D>,
+            ),
+        )>(),
+        "SpriteBase3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            GeometryInstance3DMarker,
+            SpriteBase3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::GeometryInstance3D>,
+                TypedNodeHandle<gd::SpriteBase3D>,
+            ),
+        )>(),
+        "StaticBody2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            CollisionObject2DMarker,
+            PhysicsBody2DMarker,
+            StaticBody2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::CollisionObject2D>,
+                TypedNodeHandle<gd::PhysicsBody2D>,
+                TypedNodeHandle<gd::StaticBody2D>,
+            ),
+        )>(),
+        "StaticBody3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            StaticBody3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionObject3D>,
+                TypedNodeHandle<gd::PhysicsBody3D>,
+                TypedNodeHandle<gd::StaticBody3D>,
+            ),
+        )>(),
+        "StatusIndicator" => entity.remove::<(
+            NodeMarker,
+            StatusIndicatorMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::StatusIndicator>,
+            ),
+        )>(),
+        "SubViewport" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            SubViewportMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Viewport>,
+                TypedNodeHandle<gd::SubViewport>,
+            ),
+        )>(),
+        "SubViewportContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            SubViewportContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::SubViewportContainer>,
+            ),
+        )>(),
+        "TabBar" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TabBarMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::TabBar>,
+            ),
+        )>(),
+        "TabContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            TabContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::TabContainer>,
+            ),
+        )>(),
+        "TextEdit" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TextEditMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::TextEdit>,
+            ),
+        )>(),
+        "TextureButton" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            BaseButtonMarker,
+            TextureButtonMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::BaseButton>,
+                TypedNodeHandle<gd::TextureButton>,
+            ),
+        )>(),
+        "TextureProgressBar" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            TextureProgressBarMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+                TypedNodeHandle<gd::TextureProgressBar>,
+            ),
+        )>(),
+        "TextureRect" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TextureRectMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::TextureRect>,
+            ),
+        )>(),
+        "TileMap" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            TileMapMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::TileMap>,
+            ),
+        )>(),
+        "TileMapLayer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            TileMapLayerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::TileMapLayer>,
+            ),
+        )>(),
+        "Timer" => entity.remove::<(
+            NodeMarker,
+            TimerMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Timer>),
+        )>(),
+        "TouchScreenButton" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            TouchScreenButtonMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::TouchScreenButton>,
+            ),
+        )>(),
+        "Tree" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            TreeMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Tree>,
+            ),
+        )>(),
+        "VBoxContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            BoxContainerMarker,
+            VBoxContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::BoxContainer>,
+                TypedNodeHandle<gd::VBoxContainer>,
+            ),
+        )>(),
+        "VFlowContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            FlowContainerMarker,
+            VFlowContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::FlowContainer>,
+                TypedNodeHandle<gd::VFlowContainer>,
+            ),
+        )>(),
+        "VScrollBar" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            ScrollBarMarker,
+            VScrollBarMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+                TypedNodeHandle<gd::ScrollBar>,
+                TypedNodeHandle<gd::VScrollBar>,
+            ),
+        )>(),
+        "VSeparator" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            SeparatorMarker,
+            VSeparatorMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Separator>,
+                TypedNodeHandle<gd::VSeparator>,
+            ),
+        )>(),
+        "VSlider" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            RangeMarker,
+            SliderMarker,
+            VSliderMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Range>,
+                TypedNodeHandle<gd::Slider>,
+                TypedNodeHandle<gd::VSlider>,
+            ),
+        )>(),
+        "VSplitContainer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            ContainerMarker,
+            SplitContainerMarker,
+            VSplitContainerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::Container>,
+                TypedNodeHandle<gd::SplitContainer>,
+                TypedNodeHandle<gd::VSplitContainer>,
+            ),
+        )>(),
+        "VehicleBody3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            CollisionObject3DMarker,
+            PhysicsBody3DMarker,
+            RigidBody3DMarker,
+            VehicleBody3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::CollisionObject3D>,
+                TypedNodeHandle<gd::PhysicsBody3D>,
+                TypedNodeHandle<gd::RigidBody3D>,
+                TypedNodeHandle<gd::VehicleBody3D>,
+            ),
+        )>(),
+        "VehicleWheel3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VehicleWheel3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VehicleWheel3D>,
+            ),
+        )>(),
+        "VideoStreamPlayer" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            ControlMarker,
+            VideoStreamPlayerMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Control>,
+                TypedNodeHandle<gd::VideoStreamPlayer>,
+            ),
+        )>(),
+        "Viewport" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Viewport>),
+        )>(),
+        "VisibleOnScreenEnabler2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            VisibleOnScreenNotifier2DMarker,
+            VisibleOnScreenEnabler2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::VisibleOnScreenNotifier2D>,
+                TypedNodeHandle<gd::VisibleOnScreenEnabler2D>,
+            ),
+        )>(),
+        "VisibleOnScreenEnabler3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            VisibleOnScreenNotifier3DMarker,
+            VisibleOnScreenEnabler3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::VisibleOnScreenNotifier3D>,
+                TypedNodeHandle<gd::VisibleOnScreenEnabler3D>,
+            ),
+        )>(),
+        "VisibleOnScreenNotifier2D" => entity.remove::<(
+            NodeMarker,
+            CanvasItemMarker,
+            Node2DMarker,
+            VisibleOnScreenNotifier2DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::CanvasItem>,
+                TypedNodeHandle<gd::Node2D>,
+                TypedNodeHandle<gd::VisibleOnScreenNotifier2D>,
+            ),
+        )>(),
+        "VisibleOnScreenNotifier3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            VisibleOnScreenNotifier3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::VisibleOnScreenNotifier3D>,
+            ),
+        )>(),
+        "VisualInstance3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+            ),
+        )>(),
+        "VoxelGI" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            VisualInstance3DMarker,
+            VoxelGIMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::VisualInstance3D>,
+                TypedNodeHandle<gd::VoxelGi>,
+            ),
+        )>(),
+        "Window" => entity.remove::<(
+            NodeMarker,
+            ViewportMarker,
+            WindowMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Viewport>,
+                TypedNodeHandle<gd::Window>,
+            ),
+        )>(),
+        "WorldEnvironment" => entity.remove::<(
+            NodeMarker,
+            WorldEnvironmentMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::WorldEnvironment>,
+            ),
+        )>(),
+        "XRAnchor3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            XRNode3DMarker,
+            XRAnchor3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::XrNode3D>,
+                TypedNodeHandle<gd::XrAnchor3D>,
+            ),
+        )>(),
+        "XRBodyModifier3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            XRBodyModifier3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SkeletonModifier3D>,
+            ),
+        )>(),
+        "XRCamera3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            Camera3DMarker,
+            XRCamera3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::Camera3D>,
+                TypedNodeHandle<gd::XrCamera3D>,
+            ),
+        )>(),
+        "XRController3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            XRNode3DMarker,
+            XRController3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::XrNode3D>,
+                TypedNodeHandle<gd::XrController3D>,
+            ),
+        )>(),
+        "XRFaceModifier3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            XRFaceModifier3DMarker,
+            (TypedNodeHandle<gd::Node>, TypedNodeHandle<gd::Node3D>),
+        )>(),
+        "XRHandModifier3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            SkeletonModifier3DMarker,
+            XRHandModifier3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::SkeletonModifier3D>,
+                TypedNodeHandle<gd::XrHandModifier3D>,
+            ),
+        )>(),
+        "XRNode3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            XRNode3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::XrNode3D>,
+            ),
+        )>(),
+        "XROrigin3D" => entity.remove::<(
+            NodeMarker,
+            Node3DMarker,
+            XROrigin3DMarker,
+            (
+                TypedNodeHandle<gd::Node>,
+                TypedNodeHandle<gd::Node3D>,
+                TypedNodeHandle<gd::XrOrigin3D>,
+            ),
+        )>(),
+        _ => entity.remove::<(NodeMarker, TypedNodeHandle<gd::Node>)>(),
+    };
 }
@@ -1,7 +1,16 @@
+use super::filter::SceneTreeFilter;
+use super::groups::{Groups, groups_sync_enabled, read_groups_from_godot, write_groups_to_godot};
+use super::hierarchy_sync::{
+    HierarchySyncMetadata, hierarchy_sync_enabled, write_hierarchy_to_godot,
+};
+use super::name_sync::{NameSyncMetadata, name_sync_enabled, write_names_to_godot};
+use super::node_index::{GodotNodeIndex, index_inserted_node_handle, unindex_replaced_node_handle};
 use super::node_type_checking_generated::{
     add_comprehensive_node_type_markers, remove_comprehensive_node_type_markers,
 };
-use crate::plugins::core::SceneTreeComponentRegistry;
+use super::script_class::GodotScriptClass;
+use super::sync_metadata::record_godot_sync;
+use crate::plugins::core::{GodotSyncSet, SceneTreeComponentRegistry};
 use crate::prelude::{GodotScene, main_thread_system};
 use crate::{
     interop::GodotNodeHandle,
@@ -11,14 +20,18 @@ use crate::{
     },
 };
 use bevy::{
-    app::{App, First, Plugin, PreStartup},
+    app::{App, First, Plugin, PostUpdate, PreStartup},
     ecs::{
         component::Component,
         entity::Entity,
         event::{Event, EventReader, EventWriter, event_update_system},
+        hierarchy::ChildOf,
         name::Name,
+        observer::Trigger,
+        query::{Has, With},
         schedule::IntoScheduleConfigs,
-        system::{Commands, NonSendMut, Query, Res, SystemParam},
+        system::{Commands, NonSendMut, Query, Res, ResMut, SystemParam},
+        world::OnRemove,
     },
     prelude::Resource,
 };
@@ -26,17 +39,18 @@ use godot::{
     builtin::GString,
     classes::{Engine, Node, SceneTree},
     meta::ToGodot,
-    obj::{Gd, Inherits},
+    obj::{Gd, InstanceId},
     prelude::GodotConvert,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use tracing::{debug, trace, warn};
 
 /// Unified scene tree plugin that provides:
 /// - SceneTreeRef for accessing the Godot scene tree
-/// - Scene tree events (NodeAdded, NodeRemoved, NodeRenamed)
+/// - Scene tree events (NodeAdded, NodeRemoved, NodeRenamed, NodeReparented)
 /// - Automatic entity creation and mirroring for scene tree nodes
+/// - GodotNodeIndex for looking up the entity that mirrors a node
 ///
 /// This plugin is always included in the core plugins and provides
 /// complete scene tree integration out of the box.
@@ -47,12 +61,37 @@ pub struct GodotSceneTreePlugin {
     /// as it is incompatible, i.e., Avian Physics has its own notions
     /// for what parent/child entity relatonships mean
     pub add_child_relationship: bool,
+    /// When true, removing an entity's `GodotNodeHandle` (including despawning the entity)
+    /// calls `queue_free` on its Godot node. Can be overridden per entity with
+    /// [`NodeDespawnPolicy`].
+    pub free_node_on_despawn: bool,
+    /// When true, changing an entity's `ChildOf` in Bevy (e.g. with `set_parent`) reparents its
+    /// Godot node under the parent entity's node, and removing `ChildOf` moves the node to the
+    /// scene root. Changes that came from Godot are not echoed back.
+    pub sync_hierarchy_to_godot: bool,
+    /// Whether nodes reparented through `sync_hierarchy_to_godot` keep their global transform
+    /// (Godot's `reparent` default) or their local one.
+    pub keep_global_transform_on_reparent: bool,
+    /// When true, group changes made on the Godot side are read into [`Groups`] every frame.
+    /// Changes made through [`Groups::add`] and [`Groups::remove`] are always written back.
+    pub sync_groups_from_godot: bool,
+    /// When true, changing an entity's `Name` in Bevy renames its Godot node. Names adjusted by
+    /// Godot (invalid characters, duplicate sibling names) are reported back into `Name`.
+    pub sync_names_to_godot: bool,
+    /// Decides which nodes are mirrored into entities; mirrors every node by default.
+    pub filter: SceneTreeFilter,
 }
 
 impl Default for GodotSceneTreePlugin {
     fn default() -> Self {
         Self {
             add_child_relationship: true,
+            free_node_on_despawn: false,
+            sync_hierarchy_to_godot: false,
+            keep_global_transform_on_reparent: true,
+            sync_groups_from_godot: false,
+            sync_names_to_godot: false,
+            filter: SceneTreeFilter::default(),
         }
     }
 }
@@ -66,6 +105,25 @@ pub struct SceneTreeConfig {
     /// as it is incompatible, i.e., Avian Physics has its own notions
     /// for what parent/child entity relatonships mean
     pub add_child_relationship: bool,
+    /// When true, removing an entity's `GodotNodeHandle` (including despawning the entity)
+    /// calls `queue_free` on its Godot node. Can be overridden per entity with
+    /// [`NodeDespawnPolicy`].
+    pub free_node_on_despawn: bool,
+    /// When true, changing an entity's `ChildOf` in Bevy (e.g. with `set_parent`) reparents its
+    /// Godot node under the parent entity's node, and removing `ChildOf` moves the node to the
+    /// scene root. Changes that came from Godot are not echoed back.
+    pub sync_hierarchy_to_godot: bool,
+    /// Whether nodes reparented through `sync_hierarchy_to_godot` keep their global transform
+    /// (Godot's `reparent` default) or their local one.
+    pub keep_global_transform_on_reparent: bool,
+    /// When true, group changes made on the Godot side are read into [`Groups`] every frame.
+    /// Changes made through [`Groups::add`] and [`Groups::remove`] are always written back.
+    pub sync_groups_from_godot: bool,
+    /// When true, changing an entity's `Name` in Bevy renames its Godot node. Names adjusted by
+    /// Godot (invalid characters, duplicate sibling names) are reported back into `Name`.
+    pub sync_names_to_godot: bool,
+    /// Decides which nodes are mirrored into entities; mirrors every node by default.
+    pub filter: SceneTreeFilter,
 }
 
 impl Plugin for GodotSceneTreePlugin {
@@ -76,8 +134,19 @@ impl Plugin for GodotSceneTreePlugin {
         app.init_non_send_resource::<SceneTreeRefImpl>()
             .insert_resource(SceneTreeConfig {
                 add_child_relationship: self.add_child_relationship,
+                free_node_on_despawn: self.free_node_on_despawn,
+                sync_hierarchy_to_godot: self.sync_hierarchy_to_godot,
+                keep_global_transform_on_reparent: self.keep_global_transform_on_reparent,
+                sync_groups_from_godot: self.sync_groups_from_godot,
+                sync_names_to_godot: self.sync_names_to_godot,
+                filter: self.filter.clone(),
             })
+            .init_resource::<GodotNodeIndex>()
+            .add_observer(index_inserted_node_handle)
+            .add_observer(unindex_replaced_node_handle)
+            .add_observer(free_node_on_handle_removed)
             .add_event::<SceneTreeEvent>()
+            .add_event::<NodeReparented>()
             .add_systems(
                 PreStartup,
                 (connect_scene_tree, initialize_scene_tree).chain(),
@@ -87,7 +156,20 @@ impl Plugin for GodotSceneTreePlugin {
                 (
                     write_scene_tree_events.before(event_update_system),
                     read_scene_tree_events.before(event_update_system),
-                ),
+                    read_groups_from_godot
+                        .after(read_scene_tree_events)
+                        .run_if(groups_sync_enabled),
+                )
+                    .in_set(GodotSyncSet::ReadSceneTree),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    write_hierarchy_to_godot.run_if(hierarchy_sync_enabled),
+                    write_groups_to_godot,
+                    write_names_to_godot.run_if(name_sync_enabled),
+                )
+                    .in_set(GodotSyncSet::WriteProperties),
             );
     }
 }
@@ -127,32 +209,40 @@ impl Default for SceneTreeRefImpl {
 fn initialize_scene_tree(
     mut commands: Commands,
     mut scene_tree: SceneTreeRef,
-    mut entities: Query<(&mut GodotNodeHandle, Entity, Option<&ProtectedNodeEntity>)>,
+    mut node_index: ResMut<GodotNodeIndex>,
+    protected: Query<(), With<ProtectedNodeEntity>>,
     config: Res<SceneTreeConfig>,
     component_registry: Res<SceneTreeComponentRegistry>,
+    mut reparented: EventWriter<NodeReparented>,
 ) {
-    fn traverse(node: Gd<Node>, events: &mut Vec<SceneTreeEvent>) {
+    fn traverse(node: Gd<Node>, filter: &SceneTreeFilter, events: &mut Vec<SceneTreeEvent>) {
         events.push(SceneTreeEvent {
             node: GodotNodeHandle::from_instance_id(node.instance_id()),
             event_type: SceneTreeEventType::NodeAdded,
         });
 
+        if filter.skips_children_of(&node) {
+            return;
+        }
+
         for child in node.get_children().iter_shared() {
-            traverse(child, events);
+            traverse(child, filter, events);
         }
     }
 
     let root = scene_tree.get().get_root().unwrap();
     let mut events = vec![];
-    traverse(root.upcast(), &mut events);
+    traverse(root.upcast(), &config.filter, &mut events);
 
     create_scene_tree_entity(
         &mut commands,
         events,
         &mut scene_tree,
-        &mut entities,
+        &mut node_index,
+        &protected,
         &config,
         &component_registry,
+        &mut reparented,
     );
 }
 
@@ -162,12 +252,29 @@ pub struct SceneTreeEvent {
     pub event_type: SceneTreeEventType,
 }
 
-#[derive(Copy, Clone, Debug, GodotConvert)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, GodotConvert)]
 #[godot(via = GString)]
 pub enum SceneTreeEventType {
     NodeAdded,
     NodeRemoved,
     NodeRenamed,
+    /// The node left the tree and re-entered it within the same frame, e.g. through
+    /// `reparent()` or `remove_child`/`add_child`. Its entity is kept and re-linked to the new
+    /// parent instead of being despawned and spawned again.
+    NodeReparented,
+}
+
+/// Sent when a mirrored node is moved to a new parent in the Godot scene tree.
+///
+/// Only the root of a moved subtree is reported; its descendants keep their parents. When
+/// `add_child_relationship` is enabled, the entity's `ChildOf` has already been updated to
+/// `parent` by the time this event is read.
+#[derive(Debug, Clone, Event)]
+pub struct NodeReparented {
+    pub entity: Entity,
+    pub node: GodotNodeHandle,
+    /// Entity of the node's new parent, if that parent is mirrored
+    pub parent: Option<Entity>,
 }
 
 #[main_thread_system]
@@ -201,31 +308,6 @@ fn connect_scene_tree(mut scene_tree: SceneTreeRef) {
     );
 }
 
-#[derive(Component, Debug)]
-pub struct Groups {
-    groups: Vec<String>,
-}
-
-impl Groups {
-    pub fn is(&self, group_name: &str) -> bool {
-        self.groups.iter().any(|name| name == group_name)
-    }
-}
-
-impl<T: Inherits<Node>> From<&Gd<T>> for Groups {
-    fn from(node: &Gd<T>) -> Self {
-        Groups {
-            groups: node
-                .clone()
-                .upcast::<Node>()
-                .get_groups()
-                .iter_shared()
-                .map(|variant| variant.to_string())
-                .collect(),
-        }
-    }
-}
-
 #[doc(hidden)]
 pub struct SceneTreeEventReader(pub std::sync::mpsc::Receiver<SceneTreeEvent>);
 
@@ -233,7 +315,55 @@ fn write_scene_tree_events(
     event_reader: NonSendMut<SceneTreeEventReader>,
     mut event_writer: EventWriter<SceneTreeEvent>,
 ) {
-    event_writer.write_batch(event_reader.0.try_iter());
+    let events = event_reader.0.try_iter().collect();
+    event_writer.write_batch(coalesce_reparent_events(events, |instance_id| {
+        Gd::<Node>::try_from_instance_id(instance_id).is_ok_and(|node| node.is_inside_tree())
+    }));
+}
+
+/// Godot reports a moved node as a `node_removed` followed by a `node_added`. When both arrive in
+/// the same batch and the node is still in the tree, drop the removal and turn the addition into
+/// a `NodeReparented` so the entity survives the move.
+fn coalesce_reparent_events(
+    mut events: Vec<SceneTreeEvent>,
+    is_inside_tree: impl Fn(InstanceId) -> bool,
+) -> Vec<SceneTreeEvent> {
+    // Index of each node's latest removal that hasn't been followed by an addition yet
+    let mut pending_removals = HashMap::new();
+    let mut moves = vec![];
+    for (index, event) in events.iter().enumerate() {
+        let instance_id = event.node.instance_id();
+        match event.event_type {
+            SceneTreeEventType::NodeRemoved => {
+                pending_removals.insert(instance_id, index);
+            }
+            SceneTreeEventType::NodeAdded => {
+                if let Some(removed_index) = pending_removals.remove(&instance_id) {
+                    moves.push((instance_id, removed_index, index));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if moves.is_empty() {
+        return events;
+    }
+
+    let mut dropped = HashSet::new();
+    for (instance_id, removed_index, added_index) in moves {
+        if is_inside_tree(instance_id) {
+            dropped.insert(removed_index);
+            events[added_index].event_type = SceneTreeEventType::NodeReparented;
+        }
+    }
+
+    events
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| !dropped.contains(index))
+        .map(|(_, event)| event)
+        .collect()
 }
 
 /// Marks an entity so it is not despawned when its corresponding Godot Node is freed, breaking
@@ -243,18 +373,65 @@ fn write_scene_tree_events(
 #[derive(Component)]
 pub struct ProtectedNodeEntity;
 
+/// Per-entity override of [`SceneTreeConfig::free_node_on_despawn`].
+///
+/// Decides whether the entity's Godot node is freed (`queue_free`) when the entity is despawned
+/// or its `GodotNodeHandle` is removed. Nodes that are leaving the Godot tree on their own are
+/// never freed by this, so the usual Godot-to-Bevy despawn does not echo back.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeDespawnPolicy {
+    /// Free the Godot node together with the entity
+    FreeNode,
+    /// Leave the Godot node alive when the entity goes away
+    KeepNode,
+}
+
+/// Set on entities whose node has left the Godot tree right before their `GodotNodeHandle` is
+/// removed, so `free_node_on_handle_removed` knows the removal came from Godot.
+#[derive(Component)]
+pub(crate) struct NodeLeftTree;
+
+fn free_node_on_handle_removed(
+    trigger: Trigger<OnRemove, GodotNodeHandle>,
+    entities: Query<(
+        &GodotNodeHandle,
+        Option<&NodeDespawnPolicy>,
+        Has<NodeLeftTree>,
+    )>,
+    config: Res<SceneTreeConfig>,
+) {
+    let Ok((handle, policy, left_tree)) = entities.get(trigger.target()) else {
+        return;
+    };
+
+    if left_tree {
+        return;
+    }
+
+    let free_node = match policy {
+        Some(NodeDespawnPolicy::FreeNode) => true,
+        Some(NodeDespawnPolicy::KeepNode) => false,
+        None => config.free_node_on_despawn,
+    };
+
+    if free_node && let Some(mut node) = handle.clone().try_get::<Node>() {
+        trace!(target: "godot_scene_tree_events",
+               node_id = node.instance_id().to_string(),
+               "freeing node of despawned entity");
+        node.queue_free();
+    }
+}
+
 fn create_scene_tree_entity(
     commands: &mut Commands,
     events: impl IntoIterator<Item = SceneTreeEvent>,
     scene_tree: &mut SceneTreeRef,
-    entities: &mut Query<(&mut GodotNodeHandle, Entity, Option<&ProtectedNodeEntity>)>,
+    node_index: &mut GodotNodeIndex,
+    protected: &Query<(), With<ProtectedNodeEntity>>,
     config: &SceneTreeConfig,
     component_registry: &SceneTreeComponentRegistry,
+    reparented: &mut EventWriter<NodeReparented>,
 ) {
-    let mut ent_mapping = entities
-        .iter()
-        .map(|(reference, ent, protected)| (reference.instance_id(), (ent, protected)))
-        .collect::<HashMap<_, _>>();
     let scene_root = scene_tree.get().get_root().unwrap();
     let collision_watcher = scene_tree
         .get()
@@ -262,20 +439,48 @@ fn create_scene_tree_entity(
         .unwrap()
         .get_node_as::<Node>("/root/BevyAppSingleton/CollisionWatcher");
 
-    for event in events.into_iter() {
+    let events = events.into_iter().collect::<Vec<_>>();
+
+    // Every node in a moved subtree is reported as reparented, but only the subtree root actually
+    // changed parents; the others are carried along with their (also moved) parent
+    let moved = events
+        .iter()
+        .filter(|event| event.event_type == SceneTreeEventType::NodeReparented)
+        .map(|event| event.node.instance_id())
+        .collect::<HashSet<_>>();
+
+    for event in events {
         trace!(target: "godot_scene_tree_events", event = ?event);
 
         let mut node = event.node.clone();
-        let ent = ent_mapping.get(&node.instance_id()).cloned();
+        let ent = node_index.entity_for(node.instance_id());
+
+        // A reparented node that was never mirrored is handled like a newly added one
+        let event_type = match (event.event_type, ent) {
+            (SceneTreeEventType::NodeReparented, None) => SceneTreeEventType::NodeAdded,
+            // Moved into a part of the tree that is filtered out
+            (SceneTreeEventType::NodeReparented, Some(_))
+                if !config.filter.should_mirror(&node.clone().get::<Node>()) =>
+            {
+                SceneTreeEventType::NodeRemoved
+            }
+            (event_type, _) => event_type,
+        };
 
-        match event.event_type {
+        match event_type {
             SceneTreeEventType::NodeAdded => {
                 // Skip nodes that have been freed before we process them (can happen in tests)
                 if !node.instance_id().lookup_validity() {
                     continue;
                 }
 
-                let mut ent = if let Some((ent, _)) = ent {
+                // Entities that already hold this node (e.g. a spawned `GodotScene`) are always
+                // mirrored, the filter only keeps new entities from being created
+                if ent.is_none() && !config.filter.should_mirror(&node.clone().get::<Node>()) {
+                    continue;
+                }
+
+                let mut ent = if let Some(ent) = ent {
                     commands.entity(ent)
                 } else {
                     commands.spawn_empty()
@@ -348,60 +553,133 @@ fn create_scene_tree_entity(
 
                 ent.insert(Groups::from(&node));
 
+                if let Some(script_class) = GodotScriptClass::from_node(&node) {
+                    ent.insert(script_class);
+                }
+
                 // Add all components registered by plugins
                 component_registry.add_to_entity(&mut ent, &event.node);
 
+                // Index the entity right away so children later in this batch can find it; the
+                // GodotNodeHandle observer will index it again once the commands are applied
                 let ent = ent.id();
-                ent_mapping.insert(node.instance_id(), (ent, None));
+                node_index.insert(node.instance_id(), ent);
 
                 // Try to add any registered bundles for this node type
                 super::autosync::try_add_bundles_for_node(commands, ent, &event.node);
 
+                if config.sync_names_to_godot {
+                    record_godot_sync::<NameSyncMetadata>(commands, ent);
+                }
+
                 if config.add_child_relationship
                     && node.instance_id() != scene_root.instance_id()
                     && let Some(parent) = node.get_parent()
                 {
                     let parent_id = parent.instance_id();
-                    if let Some((parent_entity, _)) = ent_mapping.get(&parent_id) {
-                        commands.entity(*parent_entity).add_children(&[ent]);
-                    } else {
+                    if let Some(parent_entity) =
+                        mirrored_ancestor_entity(parent, node_index, &config.filter)
+                    {
+                        commands.entity(parent_entity).add_children(&[ent]);
+                        record_godot_sync::<HierarchySyncMetadata>(commands, ent);
+                    } else if config.filter.is_empty() {
                         warn!(target: "godot_scene_tree_events",
-                            "Parent entity with ID {} not found in ent_mapping. This might indicate a missing or incorrect mapping.",
+                            "Parent entity with ID {} not found in GodotNodeIndex. This might indicate a missing or incorrect mapping.",
                             parent_id);
                     }
                 }
             }
             SceneTreeEventType::NodeRemoved => {
-                if let Some((ent, prot_opt)) = ent {
-                    let protected = prot_opt.is_some();
-                    if !protected {
-                        commands.entity(ent).despawn();
+                if let Some(ent) = ent {
+                    if !protected.contains(ent) {
+                        commands.entity(ent).insert(NodeLeftTree).despawn();
                     } else {
                         _strip_godot_components(commands, ent);
                     }
-                    ent_mapping.remove(&node.instance_id());
+                    node_index.remove(node.instance_id());
                 } else {
                     // Entity was already despawned (common when using queue_free)
                     trace!(target: "godot_scene_tree_events", "Entity for removed node was already despawned");
                 }
             }
             SceneTreeEventType::NodeRenamed => {
-                if let Some((ent, _)) = ent {
+                if let Some(ent) = ent {
                     commands
                         .entity(ent)
                         .insert(Name::from(node.get::<Node>().get_name().to_string()));
+
+                    if config.sync_names_to_godot {
+                        record_godot_sync::<NameSyncMetadata>(commands, ent);
+                    }
                 } else {
                     trace!(target: "godot_scene_tree_events", "Entity for renamed node was already despawned");
                 }
             }
+            SceneTreeEventType::NodeReparented => {
+                let Some(ent) = ent else {
+                    continue;
+                };
+
+                let parent = node.get::<Node>().get_parent();
+                if parent
+                    .as_ref()
+                    .is_some_and(|parent| moved.contains(&parent.instance_id()))
+                {
+                    continue;
+                }
+
+                let parent_entity = parent.and_then(|parent| {
+                    mirrored_ancestor_entity(parent, node_index, &config.filter)
+                });
+
+                if config.add_child_relationship {
+                    match parent_entity {
+                        Some(parent_entity) => {
+                            commands.entity(parent_entity).add_child(ent);
+                        }
+                        None => {
+                            commands.entity(ent).remove::<ChildOf>();
+                        }
+                    }
+                    record_godot_sync::<HierarchySyncMetadata>(commands, ent);
+                }
+
+                reparented.write(NodeReparented {
+                    entity: ent,
+                    node: event.node.clone(),
+                    parent: parent_entity,
+                });
+            }
+        }
+    }
+}
+
+/// Finds the entity of `parent`, or with a filter in place, of its closest mirrored ancestor
+fn mirrored_ancestor_entity(
+    mut parent: Gd<Node>,
+    node_index: &GodotNodeIndex,
+    filter: &SceneTreeFilter,
+) -> Option<Entity> {
+    loop {
+        if let Some(entity) = node_index.entity_for(parent.instance_id()) {
+            return Some(entity);
         }
+
+        if filter.is_empty() {
+            return None;
+        }
+
+        parent = parent.get_parent()?;
     }
 }
 
 fn _strip_godot_components(commands: &mut Commands, ent: Entity) {
     let mut entity_commands = commands.entity(ent);
-    // Remove GodotNodeHandle components
-    entity_commands.remove::<GodotNodeHandle>();
+    // Remove GodotNodeHandle components, the node is already leaving the tree so don't free it
+    entity_commands
+        .insert(NodeLeftTree)
+        .remove::<GodotNodeHandle>()
+        .remove::<NodeLeftTree>();
 
     // Remove all GodotScene components
     entity_commands.remove::<GodotScene>();
@@ -409,24 +687,110 @@ fn _strip_godot_components(commands: &mut Commands, ent: Entity) {
     // Remove automatic markers
     entity_commands.remove::<Name>();
     entity_commands.remove::<Groups>();
+    entity_commands.remove::<GodotScriptClass>();
     remove_comprehensive_node_type_markers(&mut entity_commands);
 }
 
 #[main_thread_system]
-fn read_scene_tree_events(
+pub(crate) fn read_scene_tree_events(
     mut commands: Commands,
     mut scene_tree: SceneTreeRef,
     mut event_reader: EventReader<SceneTreeEvent>,
-    mut entities: Query<(&mut GodotNodeHandle, Entity, Option<&ProtectedNodeEntity>)>,
+    mut node_index: ResMut<GodotNodeIndex>,
+    protected: Query<(), With<ProtectedNodeEntity>>,
     config: Res<SceneTreeConfig>,
     component_registry: Res<SceneTreeComponentRegistry>,
+    mut reparented: EventWriter<NodeReparented>,
 ) {
     create_scene_tree_entity(
         &mut commands,
         event_reader.read().cloned(),
         &mut scene_tree,
-        &mut entities,
+        &mut node_index,
+        &protected,
         &config,
         &component_registry,
+        &mut reparented,
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(id: i64, event_type: SceneTreeEventType) -> SceneTreeEvent {
+        SceneTreeEvent {
+            node: GodotNodeHandle::from_instance_id(InstanceId::from_i64(id)),
+            event_type,
+        }
+    }
+
+    fn event_types(events: &[SceneTreeEvent]) -> Vec<(i64, SceneTreeEventType)> {
+        events
+            .iter()
+            .map(|event| (event.node.instance_id().to_i64(), event.event_type))
+            .collect()
+    }
+
+    #[test]
+    fn test_coalesce_turns_remove_then_add_into_reparent() {
+        use SceneTreeEventType::*;
+
+        let events = vec![
+            event(1, NodeRemoved),
+            event(2, NodeRemoved),
+            event(3, NodeAdded),
+            event(1, NodeAdded),
+            event(2, NodeAdded),
+        ];
+
+        let coalesced = coalesce_reparent_events(events, |_| true);
+
+        assert_eq!(
+            event_types(&coalesced),
+            vec![(3, NodeAdded), (1, NodeReparented), (2, NodeReparented)]
+        );
+    }
+
+    #[test]
+    fn test_coalesce_keeps_first_addition_of_new_node() {
+        use SceneTreeEventType::*;
+
+        let events = vec![
+            event(1, NodeAdded),
+            event(1, NodeRemoved),
+            event(1, NodeAdded),
+        ];
+
+        let coalesced = coalesce_reparent_events(events, |_| true);
+
+        assert_eq!(
+            event_types(&coalesced),
+            vec![(1, NodeAdded), (1, NodeReparented)]
+        );
+    }
+
+    #[test]
+    fn test_coalesce_keeps_nodes_that_left_the_tree() {
+        use SceneTreeEventType::*;
+
+        let events = vec![
+            event(1, NodeRemoved),
+            event(1, NodeAdded),
+            event(2, NodeAdded),
+            event(2, NodeRemoved),
+        ];
+
+        let coalesced = coalesce_reparent_events(events, |id| id.to_i64() != 1);
+
+        assert_eq!(
+            event_types(&coalesced),
+            vec![
+                (1, NodeRemoved),
+                (1, NodeAdded),
+                (2, NodeAdded),
+                (2, NodeRemoved)
+            ]
+        );
+    }
+}
@@ -0,0 +1,37 @@
+//! Global class names of the scripts attached to mirrored nodes.
+
+use bevy::ecs::component::Component;
+use godot::{
+    builtin::StringName,
+    classes::{Node, Script},
+    obj::Gd,
+};
+
+/// Global class name of the script attached to a node, as declared with `class_name` in GDScript.
+///
+/// Added to entities mirroring nodes whose script, or one of its base scripts, declares a global
+/// class name. The most derived name is used, so a node running `class_name Boss extends Enemy`
+/// gets `GodotScriptClass("Boss")`. Like [`Groups`](super::Groups), this is read when the node is
+/// mirrored and does not follow later `set_script` calls.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct GodotScriptClass(pub StringName);
+
+impl GodotScriptClass {
+    /// Reads the script class of `node`, if its script declares a global class name
+    pub fn from_node(node: &Gd<Node>) -> Option<Self> {
+        script_class_names(node).next().map(Self)
+    }
+
+    pub fn name(&self) -> &StringName {
+        &self.0
+    }
+}
+
+/// Global class names of the script attached to `node` and of its base scripts, most derived
+/// first
+pub(crate) fn script_class_names(node: &Gd<Node>) -> impl Iterator<Item = StringName> {
+    let script = node.get_script().try_to::<Gd<Script>>().ok();
+    std::iter::successors(script, |script| script.get_base_script())
+        .map(|script| script.get_global_name())
+        .filter(|name| name.len() > 0)
+}
@@ -0,0 +1,34 @@
+//! Sync ticks for components the scene tree plugin writes from Godot.
+//!
+//! Like [`TransformSyncMetadata`] for transforms, each component written back to Godot has a
+//! metadata component recording the tick of the last value that came from Godot, so the
+//! write-back systems can skip it.
+//!
+//! [`TransformSyncMetadata`]: crate::plugins::transforms::TransformSyncMetadata
+
+use bevy::ecs::{
+    component::{Component, Tick},
+    entity::Entity,
+    error::ignore,
+    system::Commands,
+    world::EntityWorldMut,
+};
+
+/// Metadata component holding the tick a component was last written from Godot
+pub(crate) trait GodotSyncMetadata: Component {
+    fn synced_at(tick: Tick) -> Self;
+}
+
+/// Records that a component of `entity` was just written to match Godot, in its metadata `M`.
+///
+/// Must be queued after the command that changed the component, so the recorded tick is not
+/// older than the change.
+pub(crate) fn record_godot_sync<M: GodotSyncMetadata>(commands: &mut Commands, entity: Entity) {
+    commands.entity(entity).queue_handled(
+        |mut entity: EntityWorldMut| {
+            let tick = entity.world().read_change_tick();
+            entity.insert(M::synced_at(tick));
+        },
+        ignore,
+    );
+}
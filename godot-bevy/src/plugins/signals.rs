@@ -15,14 +15,20 @@ use godot::{
 use std::sync::mpsc::Sender;
 
 use crate::interop::GodotNodeHandle;
+use crate::plugins::core::GodotSyncSet;
 
 #[derive(Default)]
 pub struct GodotSignalsPlugin;
 
 impl Plugin for GodotSignalsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(First, write_godot_signal_events.before(event_update_system))
-            .add_event::<GodotSignal>();
+        app.add_systems(
+            First,
+            write_godot_signal_events
+                .before(event_update_system)
+                .in_set(GodotSyncSet::ReadSignals),
+        )
+        .add_event::<GodotSignal>();
     }
 }
 
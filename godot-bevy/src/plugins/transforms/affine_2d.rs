@@ -0,0 +1,251 @@
+use bevy::ecs::change_detection::{DetectChanges, Mut, Ref};
+use bevy::ecs::component::Component;
+use bevy::ecs::query::{Changed, With};
+use bevy::ecs::system::{Query, Res, SystemChangeTick};
+use bevy::math::Affine2;
+use bevy::prelude::{Deref, DerefMut};
+use godot::classes::Node2D;
+
+use crate::interop::GodotNodeHandle;
+use crate::interop::node_markers::Node2DMarker;
+use crate::prelude::main_thread_system;
+
+use super::change_filter::TransformSyncMetadata;
+use super::config::{GodotTransformConfig, TransformSync, TransformSyncSpace};
+use super::conversions::{IntoAffine2, IntoGodotTransform2D};
+
+/// Lossless transform of a Node2D entity, synced in place of `Transform`.
+///
+/// `Transform` can't hold skew, and keeps reflections only as a negative scale, while Godot's
+/// `Transform2D` is any 2D affine transform. The automatic sync systems sync this component with
+/// the node instead of `Transform`, following the same `sync_mode`, [`TransformSync`] overrides
+/// and `sync_space`. The entity's `Transform` is left alone, and is not interpolated or read
+/// through [`TransformReadMode::OnTransformChanged`](super::TransformReadMode).
+///
+/// Inserted on every Node2D entity when
+/// [`GodotTransformSyncPlugin::affine_transform_2d`](super::GodotTransformSyncPlugin::affine_transform_2d)
+/// is enabled, or insert it on the entities that need it.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Deref, DerefMut)]
+pub struct AffineTransform2D(pub Affine2);
+
+/// Reads the local or global transform of a Node2D as an [`AffineTransform2D`]
+pub(super) fn read_affine_transform_2d(
+    reference: &mut GodotNodeHandle,
+    global: bool,
+) -> Option<AffineTransform2D> {
+    let node = reference.try_get::<Node2D>()?;
+    let transform = if global {
+        node.get_global_transform()
+    } else {
+        node.get_transform()
+    };
+    Some(AffineTransform2D(transform.to_affine2()))
+}
+
+#[main_thread_system]
+#[tracing::instrument]
+pub fn pre_update_godot_affine_transforms(
+    config: Res<GodotTransformConfig>,
+    mut entities: Query<
+        (
+            &mut AffineTransform2D,
+            &mut GodotNodeHandle,
+            &mut TransformSyncMetadata,
+            Option<&TransformSync>,
+        ),
+        With<Node2DMarker>,
+    >,
+) {
+    let global = config.sync_space == TransformSyncSpace::Global;
+
+    for (mut affine, mut reference, mut metadata, sync) in entities.iter_mut() {
+        if !sync
+            .map_or(config.sync_mode, |sync| sync.0)
+            .reads_from_godot()
+        {
+            continue;
+        }
+
+        let Some(new_affine) = read_affine_transform_2d(&mut reference, global) else {
+            continue;
+        };
+
+        apply_read_affine(&mut affine, &mut metadata, new_affine);
+    }
+}
+
+/// Copies an affine transform read from Godot to the entity, tagging it so it isn't written back
+fn apply_read_affine(
+    affine: &mut Mut<AffineTransform2D>,
+    metadata: &mut Mut<TransformSyncMetadata>,
+    new_affine: AffineTransform2D,
+) {
+    // Only write if actually different - avoids triggering change detection
+    if **affine != new_affine {
+        **affine = new_affine;
+        metadata.last_sync_tick = Some(affine.last_changed());
+    }
+}
+
+#[main_thread_system]
+#[tracing::instrument]
+pub fn post_update_godot_affine_transforms(
+    config: Res<GodotTransformConfig>,
+    change_tick: SystemChangeTick,
+    mut entities: Query<
+        (
+            Ref<AffineTransform2D>,
+            &mut GodotNodeHandle,
+            &TransformSyncMetadata,
+            Option<&TransformSync>,
+        ),
+        (Changed<AffineTransform2D>, With<Node2DMarker>),
+    >,
+) {
+    let global = config.sync_space == TransformSyncSpace::Global;
+
+    for (affine, mut reference, metadata, sync) in entities.iter_mut() {
+        if !sync
+            .map_or(config.sync_mode, |sync| sync.0)
+            .writes_to_godot()
+        {
+            continue;
+        }
+
+        // Skip changes that were read from Godot
+        if let Some(sync_tick) = metadata.last_sync_tick
+            && !affine
+                .last_changed()
+                .is_newer_than(sync_tick, change_tick.this_run())
+        {
+            continue;
+        }
+
+        let Some(mut node) = reference.try_get::<Node2D>() else {
+            continue;
+        };
+        let transform = affine.0.to_godot_transform_2d();
+        if global {
+            node.set_global_transform(transform);
+        } else {
+            node.set_transform(transform);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::core::MainThreadMarker;
+    use crate::plugins::scene_tree::GodotNodeIndex;
+    use crate::plugins::transforms::TransformSyncMode;
+    use crate::plugins::transforms::conversions::IntoBevyTransform;
+    use crate::plugins::transforms::sync_systems::{
+        post_update_godot_transforms, pre_update_godot_transforms,
+    };
+    use bevy::ecs::{system::RunSystemOnce, world::World};
+    use bevy::math::Vec2;
+    use bevy::prelude::Transform;
+    use godot::obj::InstanceId;
+
+    fn skewed() -> AffineTransform2D {
+        AffineTransform2D(Affine2::from_cols(
+            Vec2::new(2.0, 0.5),
+            Vec2::new(0.3, -1.5),
+            Vec2::new(10.0, -4.0),
+        ))
+    }
+
+    #[test]
+    fn test_affine_entities_are_skipped_by_the_transform_sync() {
+        let mut world = World::new();
+        world.insert_resource(GodotTransformConfig {
+            sync_mode: TransformSyncMode::TwoWay,
+            ..Default::default()
+        });
+        world.init_resource::<GodotNodeIndex>();
+        world.init_non_send_resource::<MainThreadMarker>();
+        let transform = Transform::from_xyz(1.0, 2.0, 0.0);
+        let entity = world
+            .spawn((
+                transform,
+                skewed(),
+                // There is no node behind the handle: the `Transform` systems must not look it up
+                GodotNodeHandle::from_instance_id(InstanceId::from_i64(1)),
+                TransformSyncMetadata::default(),
+                Node2DMarker,
+            ))
+            .id();
+
+        world.run_system_once(pre_update_godot_transforms).unwrap();
+        world.run_system_once(post_update_godot_transforms).unwrap();
+
+        assert_eq!(world.get::<Transform>(entity), Some(&transform));
+        assert_eq!(world.get::<AffineTransform2D>(entity), Some(&skewed()));
+        let metadata = world.get::<TransformSyncMetadata>(entity).unwrap();
+        assert_eq!(metadata.last_sync_tick, None);
+    }
+
+    #[test]
+    fn test_skew_round_trips_through_the_entity() {
+        let mut world = World::new();
+        let entity = world
+            .spawn((skewed(), TransformSyncMetadata::default()))
+            .id();
+
+        // What the write system sets on the node, read back by the read system
+        let node_transform = skewed().to_godot_transform_2d();
+        assert_ne!(
+            node_transform.to_bevy_transform().to_godot_transform_2d(),
+            node_transform,
+            "`Transform` can't hold the skew"
+        );
+
+        let changed = world
+            .run_system_once(
+                move |mut entities: Query<(&mut AffineTransform2D, &mut TransformSyncMetadata)>| {
+                    let (mut affine, mut metadata) = entities.single_mut().unwrap();
+                    let before = affine.last_changed();
+                    let read = AffineTransform2D(node_transform.to_affine2());
+                    apply_read_affine(&mut affine, &mut metadata, read);
+                    affine.last_changed() != before
+                },
+            )
+            .unwrap();
+
+        // Read back unchanged, so nothing is written to the entity or back to the node
+        assert!(!changed);
+        assert_eq!(world.get::<AffineTransform2D>(entity), Some(&skewed()));
+        let metadata = world.get::<TransformSyncMetadata>(entity).unwrap();
+        assert_eq!(metadata.last_sync_tick, None);
+    }
+
+    #[test]
+    fn test_affine_changed_in_godot_is_not_written_back() {
+        let mut world = World::new();
+        let entity = world
+            .spawn((
+                AffineTransform2D::default(),
+                TransformSyncMetadata::default(),
+            ))
+            .id();
+
+        world
+            .run_system_once(
+                |mut entities: Query<(&mut AffineTransform2D, &mut TransformSyncMetadata)>| {
+                    let (mut affine, mut metadata) = entities.single_mut().unwrap();
+                    apply_read_affine(&mut affine, &mut metadata, skewed());
+                },
+            )
+            .unwrap();
+
+        assert_eq!(world.get::<AffineTransform2D>(entity), Some(&skewed()));
+        let affine_changed = world
+            .entity(entity)
+            .get_ref::<AffineTransform2D>()
+            .unwrap()
+            .last_changed();
+        let metadata = world.get::<TransformSyncMetadata>(entity).unwrap();
+        assert_eq!(metadata.last_sync_tick, Some(affine_changed));
+    }
+}
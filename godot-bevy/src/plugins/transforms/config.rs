@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use super::math::NonZRotationPolicy;
+
 /// Transform synchronization modes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TransformSyncMode {
@@ -13,12 +15,78 @@ pub enum TransformSyncMode {
     /// Two-way sync: ECS ↔ Godot
     /// Best for: Hybrid apps migrating from GDScript to ECS
     TwoWay,
+    /// One-way sync: Godot → ECS only
+    /// Best for: Nodes moved by Godot physics or GDScript that ECS systems only read
+    GodotToBevy,
+}
+
+impl TransformSyncMode {
+    /// Whether node transforms are copied to `Transform`
+    pub fn reads_from_godot(self) -> bool {
+        matches!(self, Self::TwoWay | Self::GodotToBevy)
+    }
+
+    /// Whether `Transform` changes are copied to nodes
+    pub fn writes_to_godot(self) -> bool {
+        matches!(self, Self::OneWay | Self::TwoWay)
+    }
+}
+
+/// Per-entity override of [`GodotTransformConfig::sync_mode`] for the automatic sync systems.
+///
+/// The component is immutable, so the plugin can keep count of the overrides instead of checking
+/// every entity each frame. Insert a new `TransformSync` to change an entity's mode.
+///
+/// ```ignore
+/// // Moved by GDScript and `move_and_slide`, only read by ECS systems
+/// commands.entity(player).insert(TransformSync(TransformSyncMode::GodotToBevy));
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[component(immutable)]
+pub struct TransformSync(pub TransformSyncMode);
+
+/// Which node transform `Transform` is synced with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransformSyncSpace {
+    /// `Transform` is relative to the node's Godot parent (`get_transform`/`set_transform`)
+    #[default]
+    Local,
+    /// `Transform` is in world space (`get_global_transform`/`set_global_transform`), regardless
+    /// of the node hierarchy.
+    /// Best for: Physics engines running in Bevy, scenes mirrored without `ChildOf`
+    Global,
+}
+
+/// How the automatic sync systems find the nodes to read transforms from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransformReadMode {
+    /// Read every node that syncs from Godot, every frame
+    #[default]
+    EveryFrame,
+    /// Only read nodes that reported a transform change to the
+    /// [`TransformWatcher`](crate::watchers::transform_watcher::TransformWatcher). Nodes that
+    /// sync from Godot get an internal notifier child that reports them whenever they move,
+    /// no script needed.
+    /// Best for: Mostly static levels with a few nodes moved by Godot
+    OnTransformChanged,
 }
 
 /// Configuration resource for transform syncing behavior
 #[derive(Default, Resource, Debug, Clone)]
 pub struct GodotTransformConfig {
     pub sync_mode: TransformSyncMode,
+    pub sync_space: TransformSyncSpace,
+    pub read_mode: TransformReadMode,
+    /// How rotations around X/Y are written to 2D nodes
+    pub non_z_rotation: NonZRotationPolicy,
+    /// Fill `GlobalTransform` with the node's global transform at the start of every frame (in
+    /// `PreUpdate`), for read-only use. Works with every `sync_mode`, including `Disabled`
+    pub mirror_global_transform: bool,
+    /// Write a blend of the transforms after the last two physics ticks to Node2D/Node3D nodes,
+    /// instead of the latest `Transform`, for entities moved in `PhysicsUpdate`. Entities whose
+    /// sync mode reads from Godot are not interpolated. See
+    /// [`TransformInterpolation`](super::TransformInterpolation)
+    pub interpolate_transforms: bool,
 }
 
 impl GodotTransformConfig {
@@ -26,6 +94,7 @@ impl GodotTransformConfig {
     pub fn disabled() -> Self {
         Self {
             sync_mode: TransformSyncMode::Disabled,
+            ..Default::default()
         }
     }
 
@@ -33,6 +102,7 @@ impl GodotTransformConfig {
     pub fn one_way() -> Self {
         Self {
             sync_mode: TransformSyncMode::OneWay,
+            ..Default::default()
         }
     }
 
@@ -40,6 +110,15 @@ impl GodotTransformConfig {
     pub fn two_way() -> Self {
         Self {
             sync_mode: TransformSyncMode::TwoWay,
+            ..Default::default()
+        }
+    }
+
+    /// Enable read-only sync (Godot → ECS)
+    pub fn godot_to_bevy() -> Self {
+        Self {
+            sync_mode: TransformSyncMode::GodotToBevy,
+            ..Default::default()
         }
     }
 }
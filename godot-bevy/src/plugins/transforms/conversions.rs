@@ -1,8 +1,13 @@
-use bevy::math::{Quat, Vec3, vec3};
+use bevy::math::{Affine2, Quat, Vec2, Vec3, vec2, vec3};
 use bevy::prelude::Transform as BevyTransform;
 use godot::builtin::{Basis, Quaternion, Transform2D as GodotTransform2D, Vector3};
 use godot::builtin::{Transform3D as GodotTransform3D, Vector2};
 
+use super::math::{
+    NonZRotationPolicy, decompose_2d_matrix, decompose_2d_matrix_with_hint, matrix_2d_approx_eq,
+    transform_to_2d_matrix,
+};
+
 pub trait IntoBevyTransform {
     fn to_bevy_transform(self) -> BevyTransform;
 }
@@ -26,19 +31,53 @@ impl IntoBevyTransform for GodotTransform2D {
         // Extract 2D position
         let translation = self.origin.to_vec3();
 
-        // Extract 2D rotation (z-axis rotation from the 2D transform matrix)
-        let rotation_angle = self.a.y.atan2(self.a.x);
-        let rotation = Quat::from_rotation_z(rotation_angle);
-
-        // Extract 2D scale from the transform matrix
-        let scale_x = self.a.length();
-        let scale_y = self.b.length();
-        let scale = Vec3::new(scale_x, scale_y, 1.0);
+        // Reflections are kept as a negative y scale, skew is lost
+        let (rotation_angle, scale) = decompose_2d_matrix(self.a.to_vec2(), self.b.to_vec2());
 
         BevyTransform {
             translation,
-            rotation,
-            scale,
+            rotation: Quat::from_rotation_z(rotation_angle),
+            scale: scale.extend(1.0),
+        }
+    }
+}
+
+/// Conversion of a transform read back from Godot, given the `Transform` it's synced with
+pub trait IntoBevyTransformWith {
+    /// Convert, keeping `current` when it gives the same Godot transform with `non_z_rotation`.
+    /// Otherwise like [`IntoBevyTransform::to_bevy_transform`]
+    fn to_bevy_transform_with(
+        self,
+        current: &BevyTransform,
+        non_z_rotation: NonZRotationPolicy,
+    ) -> BevyTransform;
+}
+
+impl IntoBevyTransformWith for GodotTransform2D {
+    /// A flipped or tilted `Transform` has other ways of giving the same matrix, which Godot would
+    /// read back as a change. Reflections that did change keep the sign of `current`'s x scale,
+    /// and the z translation, which 2D nodes don't have, is kept
+    fn to_bevy_transform_with(
+        self,
+        current: &BevyTransform,
+        non_z_rotation: NonZRotationPolicy,
+    ) -> BevyTransform {
+        let (a, b, origin) = (self.a.to_vec2(), self.b.to_vec2(), self.origin.to_vec2());
+
+        let (current_a, current_b) = transform_to_2d_matrix(current, non_z_rotation);
+        if matrix_2d_approx_eq(
+            [a, b, origin],
+            [current_a, current_b, current.translation.truncate()],
+        ) {
+            return *current;
+        }
+
+        let (rotation_angle, scale) = decompose_2d_matrix_with_hint(a, b, current.scale.truncate());
+
+        BevyTransform {
+            translation: origin.extend(current.translation.z),
+            rotation: Quat::from_rotation_z(rotation_angle),
+            scale: scale.extend(1.0),
         }
     }
 }
@@ -47,8 +86,13 @@ pub trait IntoGodotTransform {
     fn to_godot_transform(self) -> GodotTransform3D;
 }
 
-pub trait IntoGodotTransform2D {
-    fn to_godot_transform_2d(self) -> GodotTransform2D;
+pub trait IntoGodotTransform2D: Sized {
+    /// Convert with the default [`NonZRotationPolicy`]
+    fn to_godot_transform_2d(self) -> GodotTransform2D {
+        self.to_godot_transform_2d_with(NonZRotationPolicy::default())
+    }
+
+    fn to_godot_transform_2d_with(self, non_z_rotation: NonZRotationPolicy) -> GodotTransform2D;
 }
 
 impl IntoGodotTransform for BevyTransform {
@@ -66,20 +110,32 @@ impl IntoGodotTransform for BevyTransform {
 }
 
 impl IntoGodotTransform2D for BevyTransform {
-    fn to_godot_transform_2d(self) -> GodotTransform2D {
-        // Extract the Z rotation component from the quaternion
-        let (_, _, rotation_z) = self.rotation.to_euler(bevy::math::EulerRot::XYZ);
+    fn to_godot_transform_2d_with(self, non_z_rotation: NonZRotationPolicy) -> GodotTransform2D {
+        let (a, b) = transform_to_2d_matrix(&self, non_z_rotation);
+        let origin = Vector2::new(self.translation.x, self.translation.y);
 
-        // Create 2D rotation matrix
-        let cos_rot = rotation_z.cos();
-        let sin_rot = rotation_z.sin();
+        GodotTransform2D::from_cols(a.to_vector2(), b.to_vector2(), origin)
+    }
+}
+
+impl IntoGodotTransform2D for Affine2 {
+    /// Lossless, there is no rotation policy to apply
+    fn to_godot_transform_2d_with(self, _non_z_rotation: NonZRotationPolicy) -> GodotTransform2D {
+        GodotTransform2D::from_cols(
+            self.matrix2.x_axis.to_vector2(),
+            self.matrix2.y_axis.to_vector2(),
+            self.translation.to_vector2(),
+        )
+    }
+}
 
-        // Apply scale to rotation matrix
-        let a = godot::builtin::Vector2::new(cos_rot * self.scale.x, sin_rot * self.scale.x);
-        let b = godot::builtin::Vector2::new(-sin_rot * self.scale.y, cos_rot * self.scale.y);
-        let origin = godot::builtin::Vector2::new(self.translation.x, self.translation.y);
+pub trait IntoAffine2 {
+    fn to_affine2(self) -> Affine2;
+}
 
-        GodotTransform2D { a, b, origin }
+impl IntoAffine2 for GodotTransform2D {
+    fn to_affine2(self) -> Affine2 {
+        Affine2::from_cols(self.a.to_vec2(), self.b.to_vec2(), self.origin.to_vec2())
     }
 }
 
@@ -105,6 +161,28 @@ impl IntoVec3 for Vector3 {
     }
 }
 
+pub trait IntoVector2 {
+    fn to_vector2(self) -> Vector2;
+}
+
+impl IntoVector2 for Vec2 {
+    #[inline]
+    fn to_vector2(self) -> Vector2 {
+        Vector2::new(self.x, self.y)
+    }
+}
+
+pub trait IntoVec2 {
+    fn to_vec2(self) -> Vec2;
+}
+
+impl IntoVec2 for Vector2 {
+    #[inline]
+    fn to_vec2(self) -> Vec2 {
+        vec2(self.x, self.y)
+    }
+}
+
 impl IntoVec3 for Vector2 {
     #[inline]
     fn to_vec3(self) -> Vec3 {
@@ -133,3 +211,94 @@ impl IntoQuaternion for Quat {
         Quaternion::new(self.x, self.y, self.z, self.w)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::math::matrix_2d_approx_eq;
+    use super::super::math::strategies::{angle, tilt, transform_2d};
+    use super::*;
+    use proptest::prelude::*;
+
+    fn columns(transform: GodotTransform2D) -> [Vec2; 3] {
+        [
+            transform.a.to_vec2(),
+            transform.b.to_vec2(),
+            transform.origin.to_vec2(),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_node_matrix_converts_back_to_the_same_matrix(transform in transform_2d()) {
+            let node_transform = transform.to_godot_transform_2d();
+
+            let read_back = node_transform.to_bevy_transform().to_godot_transform_2d();
+            prop_assert!(matrix_2d_approx_eq(columns(read_back), columns(node_transform)));
+        }
+
+        #[test]
+        fn test_written_transform_reads_back_unchanged(
+            transform in transform_2d(),
+            tilt in tilt(),
+        ) {
+            // Negative scales and, with `Project`, rotations around X/Y have other decompositions
+            // giving the same matrix
+            for (transform, non_z_rotation) in [
+                (transform, NonZRotationPolicy::Twist),
+                (transform, NonZRotationPolicy::Project),
+                (
+                    BevyTransform {
+                        rotation: tilt * transform.rotation,
+                        ..transform
+                    },
+                    NonZRotationPolicy::Project,
+                ),
+            ] {
+                let node_transform = transform.to_godot_transform_2d_with(non_z_rotation);
+                prop_assert_eq!(
+                    node_transform.to_bevy_transform_with(&transform, non_z_rotation),
+                    transform
+                );
+            }
+        }
+
+        #[test]
+        fn test_moved_node_keeps_the_flip_of_the_transform(
+            transform in transform_2d(),
+            angle in angle(),
+            offset in (-100.0_f32..100.0, -100.0_f32..100.0),
+        ) {
+            // Moved and turned on the Godot side, flipped the same way
+            let moved = BevyTransform {
+                translation: transform.translation + Vec3::new(offset.0, offset.1, 0.0),
+                rotation: Quat::from_rotation_z(angle),
+                ..transform
+            };
+
+            let read_back = moved
+                .to_godot_transform_2d()
+                .to_bevy_transform_with(&transform, NonZRotationPolicy::Twist);
+            prop_assert!(read_back.translation.abs_diff_eq(moved.translation, 1e-3));
+            prop_assert!(
+                read_back.rotation.abs_diff_eq(moved.rotation, 1e-4)
+                    || read_back.rotation.abs_diff_eq(-moved.rotation, 1e-4)
+            );
+            prop_assert!(read_back.scale.abs_diff_eq(moved.scale, 1e-3));
+        }
+
+        #[test]
+        fn test_affine_round_trips_exactly(
+            a in (-10.0_f32..10.0, -10.0_f32..10.0),
+            b in (-10.0_f32..10.0, -10.0_f32..10.0),
+            origin in (-1000.0_f32..1000.0, -1000.0_f32..1000.0),
+        ) {
+            // Any matrix, including skew and reflections
+            let affine = Affine2::from_cols(
+                Vec2::new(a.0, a.1),
+                Vec2::new(b.0, b.1),
+                Vec2::new(origin.0, origin.1),
+            );
+            prop_assert_eq!(affine.to_godot_transform_2d().to_affine2(), affine);
+        }
+    }
+}
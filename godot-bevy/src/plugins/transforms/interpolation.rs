@@ -0,0 +1,234 @@
+use bevy::ecs::change_detection::DetectChanges;
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
+use bevy::ecs::query::{Added, AnyOf, Changed, Has, Or, With, Without};
+use bevy::ecs::removal_detection::RemovedComponents;
+use bevy::ecs::system::{Commands, Query, Res};
+use bevy::prelude::Transform as BevyTransform;
+use godot::classes::Engine;
+
+use crate::interop::GodotNodeHandle;
+use crate::interop::node_markers::{Node2DMarker, Node3DMarker};
+use crate::prelude::main_thread_system;
+
+use super::affine_2d::AffineTransform2D;
+use super::config::{GodotTransformConfig, TransformSync, TransformSyncMode, TransformSyncSpace};
+use super::math::interpolate_transform;
+use super::sync_systems::write_godot_transform;
+
+/// Render interpolation for an entity moved in `PhysicsUpdate`.
+///
+/// Instead of the latest `Transform`, the node gets a blend of the transforms after the last two
+/// physics ticks, using Godot's physics interpolation fraction, so motion stays smooth when the
+/// display refreshes faster than the physics tick rate. This delays the node by up to one tick.
+///
+/// Interpolated nodes are written every frame the blend changes, and never read back into
+/// `Transform`, since they don't hold the entity's actual transform.
+///
+/// Only entities whose sync mode writes to Godot without reading from it are interpolated: the
+/// component is inserted on those when [`GodotTransformConfig::interpolate_transforms`] is
+/// enabled, and removed from entities whose [`TransformSync`] override reads from Godot, since
+/// the node would be read back into `Transform`.
+#[derive(Component, Debug, Default, Clone)]
+pub struct TransformInterpolation {
+    /// `Transform` at the start of the latest physics tick
+    previous: Option<BevyTransform>,
+    /// Transform last written to the node
+    written: Option<BevyTransform>,
+}
+
+impl TransformInterpolation {
+    /// Snap to the current `Transform` instead of blending from the previous tick, e.g. after
+    /// teleporting the entity
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// Whether entities synced with `mode` are interpolated
+fn interpolates(mode: TransformSyncMode) -> bool {
+    mode.writes_to_godot() && !mode.reads_from_godot()
+}
+
+/// Inserts [`TransformInterpolation`] on the entities to interpolate, and removes it from
+/// entities whose sync mode doesn't allow it
+pub fn update_interpolated_entities(
+    mut commands: Commands,
+    config: Res<GodotTransformConfig>,
+    mut removed_overrides: RemovedComponents<TransformSync>,
+    entities: Query<
+        (Entity, Option<&TransformSync>, Has<TransformInterpolation>),
+        (
+            Or<(With<Node2DMarker>, With<Node3DMarker>)>,
+            Without<AffineTransform2D>,
+        ),
+    >,
+    changed: Query<
+        Entity,
+        Or<(
+            Added<Node2DMarker>,
+            Added<Node3DMarker>,
+            Changed<TransformSync>,
+            Added<TransformInterpolation>,
+        )>,
+    >,
+) {
+    // Every entity when the default mode changed or interpolation was just switched on
+    let updated = if config.is_changed() {
+        entities.iter().collect::<Vec<_>>()
+    } else {
+        entities
+            .iter_many(changed.iter().chain(removed_overrides.read()))
+            .collect()
+    };
+
+    for (entity, sync, interpolated) in updated {
+        let interpolate = interpolates(sync.map_or(config.sync_mode, |sync| sync.0));
+        if interpolate && !interpolated && config.interpolate_transforms {
+            commands
+                .entity(entity)
+                .insert(TransformInterpolation::default());
+        } else if !interpolate && interpolated {
+            commands.entity(entity).remove::<TransformInterpolation>();
+        }
+    }
+}
+
+/// Records the transforms the next physics tick starts from
+pub fn record_physics_transforms(
+    mut entities: Query<(&BevyTransform, &mut TransformInterpolation)>,
+) {
+    for (transform, mut interpolation) in entities.iter_mut() {
+        interpolation.previous = Some(*transform);
+    }
+}
+
+/// Writes the blended transforms of interpolated entities to their nodes
+#[main_thread_system]
+#[tracing::instrument]
+pub fn write_interpolated_transforms(
+    config: Res<GodotTransformConfig>,
+    mut entities: Query<
+        (
+            &BevyTransform,
+            &mut TransformInterpolation,
+            &mut GodotNodeHandle,
+            AnyOf<(&Node2DMarker, &Node3DMarker)>,
+            Option<&TransformSync>,
+        ),
+        Without<AffineTransform2D>,
+    >,
+) {
+    let fraction = Engine::singleton().get_physics_interpolation_fraction() as f32;
+    let global = config.sync_space == TransformSyncSpace::Global;
+
+    for (transform, mut interpolation, mut reference, (node2d, _), sync) in entities.iter_mut() {
+        if !interpolates(sync.map_or(config.sync_mode, |sync| sync.0)) {
+            continue;
+        }
+
+        let blended = match &interpolation.previous {
+            Some(previous) => interpolate_transform(previous, transform, fraction),
+            None => *transform,
+        };
+
+        // The blend only stops changing once the entity has been still for a whole tick
+        if interpolation.written == Some(blended) {
+            continue;
+        }
+
+        interpolation.written = Some(blended);
+        write_godot_transform(
+            &mut reference,
+            &blended,
+            node2d.is_some(),
+            global,
+            config.non_z_rotation,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::schedule::Schedule;
+    use bevy::ecs::world::World;
+
+    fn setup() -> (World, Schedule) {
+        let mut world = World::new();
+        world.insert_resource(GodotTransformConfig {
+            interpolate_transforms: true,
+            ..Default::default()
+        });
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_interpolated_entities);
+        (world, schedule)
+    }
+
+    #[test]
+    fn test_interpolation_skips_entities_read_from_godot() {
+        let (mut world, mut schedule) = setup();
+        let moved_by_bevy = world.spawn((Node2DMarker, BevyTransform::default())).id();
+        let moved_by_godot = world
+            .spawn((
+                Node2DMarker,
+                BevyTransform::default(),
+                TransformSync(TransformSyncMode::GodotToBevy),
+            ))
+            .id();
+        let two_way = world
+            .spawn((
+                Node3DMarker,
+                BevyTransform::default(),
+                TransformSync(TransformSyncMode::TwoWay),
+            ))
+            .id();
+        schedule.run(&mut world);
+
+        assert!(world.get::<TransformInterpolation>(moved_by_bevy).is_some());
+        assert!(
+            world
+                .get::<TransformInterpolation>(moved_by_godot)
+                .is_none()
+        );
+        assert!(world.get::<TransformInterpolation>(two_way).is_none());
+    }
+
+    #[test]
+    fn test_interpolation_follows_override_changes() {
+        let (mut world, mut schedule) = setup();
+        let entity = world.spawn((Node2DMarker, BevyTransform::default())).id();
+        schedule.run(&mut world);
+        assert!(world.get::<TransformInterpolation>(entity).is_some());
+
+        // Handed over to Godot, e.g. a CharacterBody2D moved with `move_and_slide`
+        world
+            .entity_mut(entity)
+            .insert(TransformSync(TransformSyncMode::GodotToBevy));
+        schedule.run(&mut world);
+        assert!(world.get::<TransformInterpolation>(entity).is_none());
+
+        world.entity_mut(entity).remove::<TransformSync>();
+        schedule.run(&mut world);
+        assert!(world.get::<TransformInterpolation>(entity).is_some());
+    }
+
+    #[test]
+    fn test_manually_inserted_interpolation_is_removed_when_read_from_godot() {
+        let (mut world, mut schedule) = setup();
+        world
+            .resource_mut::<GodotTransformConfig>()
+            .interpolate_transforms = false;
+        let entity = world
+            .spawn((
+                Node2DMarker,
+                BevyTransform::default(),
+                TransformSync(TransformSyncMode::GodotToBevy),
+                TransformInterpolation::default(),
+            ))
+            .id();
+        schedule.run(&mut world);
+
+        assert!(world.get::<TransformInterpolation>(entity).is_none());
+    }
+}
@@ -2,18 +2,93 @@
 ///
 /// These functions provide testable implementations of core mathematical
 /// operations used in transform conversion traits.
-use bevy::prelude::{Quat, Transform};
+use bevy::prelude::{Mat3, Quat, Transform, Vec2};
+use std::f32::consts::PI;
+
+/// How rotations around X and Y are handled when a `Transform` is written to a 2D node, which can
+/// only rotate around Z
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonZRotationPolicy {
+    /// Keep the rotation around Z (the twist of the rotation around the Z axis) and drop the
+    /// rest, so a node tilted around X/Y keeps its size
+    #[default]
+    Twist,
+    /// Project the 3D transform onto the XY plane, so a node tilted around X/Y looks squashed,
+    /// like it would when seen from the front. Tilting around both axes gives skew
+    Project,
+}
 
 /// Extract rotation angle from 2D transform matrix components
 pub fn extract_rotation_from_2d_matrix(a_x: f32, a_y: f32) -> f32 {
     a_y.atan2(a_x)
 }
 
-/// Extract scale from 2D transform matrix components
+/// Extract scale from 2D transform matrix components. Like Godot's `Transform2D::get_scale`, a
+/// reflection gives a negative y scale
 pub fn extract_scale_from_2d_matrix(a_x: f32, a_y: f32, b_x: f32, b_y: f32) -> (f32, f32) {
     let scale_x = (a_x * a_x + a_y * a_y).sqrt();
     let scale_y = (b_x * b_x + b_y * b_y).sqrt();
-    (scale_x, scale_y)
+    let determinant = a_x * b_y - a_y * b_x;
+    (scale_x, scale_y.copysign(determinant))
+}
+
+/// Split the 2D matrix with columns `a` and `b` into a rotation and a (possibly negative) scale
+/// that compose back to it with [`create_2d_rotation_matrix`]. Skew can't be represented and is
+/// dropped, `b` keeps its length but is made perpendicular to `a`
+pub fn decompose_2d_matrix(a: Vec2, b: Vec2) -> (f32, Vec2) {
+    let rotation = extract_rotation_from_2d_matrix(a.x, a.y);
+    let (scale_x, scale_y) = extract_scale_from_2d_matrix(a.x, a.y, b.x, b.y);
+    (rotation, Vec2::new(scale_x, scale_y))
+}
+
+/// Like [`decompose_2d_matrix`], but when `scale_hint.x` is negative the reflection comes back as
+/// a negative x scale and the rotation turned by PI, which gives the same matrix. A `Transform`
+/// flipped around X on the Bevy side then reads back with its own rotation and scale signs
+pub fn decompose_2d_matrix_with_hint(a: Vec2, b: Vec2, scale_hint: Vec2) -> (f32, Vec2) {
+    let (rotation, scale) = decompose_2d_matrix(a, b);
+    if scale_hint.x >= 0.0 {
+        return (rotation, scale);
+    }
+
+    let rotation = if rotation > 0.0 {
+        rotation - PI
+    } else {
+        rotation + PI
+    };
+    (rotation, -scale)
+}
+
+/// Whether the 2D matrices with columns `lhs` and `rhs` (x axis, y axis, origin) only differ by
+/// the float error of a round trip through Godot, relative to the size of each column
+pub fn matrix_2d_approx_eq(lhs: [Vec2; 3], rhs: [Vec2; 3]) -> bool {
+    lhs.into_iter().zip(rhs).all(|(lhs, rhs)| {
+        let tolerance = ROUND_TRIP_EPSILON * lhs.abs().max_element().max(1.0);
+        lhs.abs_diff_eq(rhs, tolerance)
+    })
+}
+
+/// Relative error tolerated by [`matrix_2d_approx_eq`]
+const ROUND_TRIP_EPSILON: f32 = 1e-5;
+
+/// Columns of the 2D matrix matching `transform`, with X/Y rotations handled by `non_z_rotation`
+pub fn transform_to_2d_matrix(
+    transform: &Transform,
+    non_z_rotation: NonZRotationPolicy,
+) -> (Vec2, Vec2) {
+    match non_z_rotation {
+        NonZRotationPolicy::Twist => {
+            let ((a_x, a_y), (b_x, b_y)) = create_2d_rotation_matrix(
+                extract_z_rotation_from_quat(transform.rotation),
+                transform.scale.x,
+                transform.scale.y,
+            );
+            (Vec2::new(a_x, a_y), Vec2::new(b_x, b_y))
+        }
+        NonZRotationPolicy::Project => {
+            let matrix = Mat3::from_quat(transform.rotation) * Mat3::from_diagonal(transform.scale);
+            (matrix.x_axis.truncate(), matrix.y_axis.truncate())
+        }
+    }
 }
 
 /// Create 2D rotation matrix components from angle and scale
@@ -43,18 +118,36 @@ pub fn validate_transform_for_conversion(transform: &Transform) -> bool {
         return false;
     }
 
-    // Check scale is finite and positive
-    if !transform.scale.is_finite() || transform.scale.min_element() <= 0.0 {
+    // Check scale is finite and invertible, negative scale (reflection) is supported
+    if !transform.scale.is_finite() || transform.scale.abs().min_element() == 0.0 {
         return false;
     }
 
     true
 }
 
-/// Extract Z-axis rotation from quaternion (for 2D conversion)
+/// Extract Z-axis rotation from quaternion (for 2D conversion), in `-PI..=PI`.
+///
+/// This is the twist of the rotation around the Z axis, which unlike Euler angles doesn't depend
+/// on the rotations around X and Y. Rotations that turn the Z axis upside down have no twist and
+/// give 0
 pub fn extract_z_rotation_from_quat(quat: Quat) -> f32 {
-    let (_, _, rotation_z) = quat.to_euler(bevy::math::EulerRot::XYZ);
-    rotation_z
+    // `quat` and `-quat` are the same rotation, pick the one giving an angle in -PI..=PI
+    let (z, w) = if quat.w < 0.0 {
+        (-quat.z, -quat.w)
+    } else {
+        (quat.z, quat.w)
+    };
+    2.0 * z.atan2(w)
+}
+
+/// Blend two transforms, `t` = 0 gives `previous` and `t` = 1 gives `current`
+pub fn interpolate_transform(previous: &Transform, current: &Transform, t: f32) -> Transform {
+    Transform {
+        translation: previous.translation.lerp(current.translation, t),
+        rotation: previous.rotation.slerp(current.rotation, t),
+        scale: previous.scale.lerp(current.scale, t),
+    }
 }
 
 #[cfg(test)]
@@ -78,6 +171,13 @@ mod tests {
         let (scale_x, scale_y) = extract_scale_from_2d_matrix(2.0, 0.0, 0.0, 3.0);
         assert!((scale_x - 2.0).abs() < 1e-6);
         assert!((scale_y - 3.0).abs() < 1e-6);
+
+        // Reflections give a negative y scale, whichever axis was flipped
+        for (a_x, b_y) in [(-2.0, 3.0), (2.0, -3.0)] {
+            let (scale_x, scale_y) = extract_scale_from_2d_matrix(a_x, 0.0, 0.0, b_y);
+            assert!((scale_x - 2.0).abs() < 1e-6);
+            assert!((scale_y + 3.0).abs() < 1e-6);
+        }
     }
 
     #[test]
@@ -107,6 +207,127 @@ mod tests {
             scale: Vec3::new(1.0, 1.0, 1.0),
         };
         assert!(!validate_transform_for_conversion(&invalid_transform));
+
+        // Negative scale is a reflection, zero scale can't be converted back
+        let mut transform = valid_transform;
+        transform.scale = Vec3::new(-1.0, 1.0, 1.0);
+        assert!(validate_transform_for_conversion(&transform));
+        transform.scale = Vec3::new(0.0, 1.0, 1.0);
+        assert!(!validate_transform_for_conversion(&transform));
+    }
+
+    /// Difference between two angles, ignoring whole turns
+    fn angle_difference(a: f32, b: f32) -> f32 {
+        let difference = (a - b).rem_euclid(2.0 * PI);
+        difference.min(2.0 * PI - difference)
+    }
+
+    fn sweep_angles() -> impl Iterator<Item = f32> {
+        (-24..24).map(|step| step as f32 * PI / 24.0)
+    }
+
+    const SWEEP_SCALES: [f32; 6] = [-2.5, -1.0, -0.5, 0.5, 1.0, 2.5];
+
+    #[test]
+    fn test_2d_matrix_round_trip() {
+        for angle in sweep_angles() {
+            for scale_x in SWEEP_SCALES {
+                for scale_y in SWEEP_SCALES {
+                    let transform = Transform {
+                        rotation: Quat::from_rotation_z(angle),
+                        scale: Vec3::new(scale_x, scale_y, 1.0),
+                        ..Default::default()
+                    };
+                    let (a, b) = transform_to_2d_matrix(&transform, NonZRotationPolicy::Twist);
+
+                    // Both policies agree on rotations around Z
+                    let (projected_a, projected_b) =
+                        transform_to_2d_matrix(&transform, NonZRotationPolicy::Project);
+                    assert!(projected_a.abs_diff_eq(a, 1e-5));
+                    assert!(projected_b.abs_diff_eq(b, 1e-5));
+
+                    // The decomposition gives the same matrix, with the hint it gives back the
+                    // same rotation and scale
+                    let (rotation, scale) = decompose_2d_matrix(a, b);
+                    let decomposed = Transform {
+                        rotation: Quat::from_rotation_z(rotation),
+                        scale: scale.extend(1.0),
+                        ..Default::default()
+                    };
+                    let (round_trip_a, round_trip_b) =
+                        transform_to_2d_matrix(&decomposed, NonZRotationPolicy::Twist);
+                    assert!(
+                        round_trip_a.abs_diff_eq(a, 1e-5) && round_trip_b.abs_diff_eq(b, 1e-5),
+                        "angle {angle}, scale ({scale_x}, {scale_y}): {a} {b} came back as \
+                         {round_trip_a} {round_trip_b}"
+                    );
+
+                    let (rotation, scale) =
+                        decompose_2d_matrix_with_hint(a, b, transform.scale.truncate());
+                    assert!(
+                        angle_difference(rotation, angle) < 1e-5
+                            && scale.abs_diff_eq(transform.scale.truncate(), 1e-5),
+                        "angle {angle}, scale ({scale_x}, {scale_y}) came back as angle \
+                         {rotation}, scale {scale}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_2d_matrix_decomposition_drops_skew() {
+        for angle in sweep_angles() {
+            let (sin, cos) = angle.sin_cos();
+            let a = Vec2::new(cos, sin) * 2.0;
+            // `b` leans 0.3 radians towards `a`
+            let (skew_sin, skew_cos) = 0.3_f32.sin_cos();
+            let b = (Vec2::new(-sin, cos) * skew_cos + Vec2::new(cos, sin) * skew_sin) * 3.0;
+
+            let (rotation, scale) = decompose_2d_matrix(a, b);
+            assert!(angle_difference(rotation, angle) < 1e-5);
+            assert!(scale.abs_diff_eq(Vec2::new(2.0, 3.0), 1e-5));
+        }
+    }
+
+    #[test]
+    fn test_non_z_rotation_policies() {
+        let transform = Transform::from_rotation(Quat::from_rotation_y(PI / 3.0));
+
+        let (a, b) = transform_to_2d_matrix(&transform, NonZRotationPolicy::Twist);
+        assert!(a.abs_diff_eq(Vec2::X, 1e-6));
+        assert!(b.abs_diff_eq(Vec2::Y, 1e-6));
+
+        // Seen from the front, the tilted X axis is half as long
+        let (a, b) = transform_to_2d_matrix(&transform, NonZRotationPolicy::Project);
+        assert!(a.abs_diff_eq(Vec2::new(0.5, 0.0), 1e-6));
+        assert!(b.abs_diff_eq(Vec2::Y, 1e-6));
+    }
+
+    #[test]
+    fn test_interpolate_transform() {
+        let previous = Transform::from_xyz(0.0, 0.0, 0.0);
+        let current = Transform {
+            translation: Vec3::new(10.0, -4.0, 2.0),
+            rotation: Quat::from_rotation_z(PI / 2.0),
+            scale: Vec3::new(3.0, 1.0, 1.0),
+        };
+
+        for (t, expected) in [(0.0, previous), (1.0, current)] {
+            let blended = interpolate_transform(&previous, &current, t);
+            assert!(blended.translation.abs_diff_eq(expected.translation, 1e-6));
+            assert!(blended.rotation.abs_diff_eq(expected.rotation, 1e-6));
+            assert!(blended.scale.abs_diff_eq(expected.scale, 1e-6));
+        }
+
+        let halfway = interpolate_transform(&previous, &current, 0.5);
+        assert!(
+            halfway
+                .translation
+                .abs_diff_eq(Vec3::new(5.0, -2.0, 1.0), 1e-6)
+        );
+        assert!((extract_z_rotation_from_quat(halfway.rotation) - PI / 4.0).abs() < 1e-6);
+        assert!(halfway.scale.abs_diff_eq(Vec3::new(2.0, 1.0, 1.0), 1e-6));
     }
 
     #[test]
@@ -117,5 +338,167 @@ mod tests {
         // Test Z rotation
         let z_rot_quat = Quat::from_rotation_z(PI / 4.0);
         assert!((extract_z_rotation_from_quat(z_rot_quat) - PI / 4.0).abs() < 1e-6);
+
+        // Rotations around X and Y on top don't change the rotation around Z
+        let tilt = Quat::from_axis_angle(Vec3::new(1.0, 2.0, 0.0).normalize(), 0.7);
+        for angle in sweep_angles() {
+            for quat in [
+                tilt * Quat::from_rotation_z(angle),
+                -(tilt * Quat::from_rotation_z(angle)),
+            ] {
+                let rotation_z = extract_z_rotation_from_quat(quat);
+                assert!((-PI..=PI).contains(&rotation_z));
+                assert!(angle_difference(rotation_z, angle) < 1e-5);
+            }
+        }
+    }
+
+    mod properties {
+        use super::super::strategies::{angle, scale_2d, tilt, transform_2d};
+        use super::*;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn test_decomposition_composes_back_to_the_matrix(
+                angle in angle(),
+                scale in scale_2d(),
+            ) {
+                let ((a_x, a_y), (b_x, b_y)) = create_2d_rotation_matrix(angle, scale.x, scale.y);
+                let (a, b) = (Vec2::new(a_x, a_y), Vec2::new(b_x, b_y));
+
+                let (rotation, decomposed_scale) = decompose_2d_matrix(a, b);
+                let ((a_x, a_y), (b_x, b_y)) =
+                    create_2d_rotation_matrix(rotation, decomposed_scale.x, decomposed_scale.y);
+                prop_assert!(matrix_2d_approx_eq(
+                    [Vec2::new(a_x, a_y), Vec2::new(b_x, b_y), Vec2::ZERO],
+                    [a, b, Vec2::ZERO],
+                ));
+                prop_assert!((-PI..=PI).contains(&rotation));
+                prop_assert!(decomposed_scale.x > 0.0);
+            }
+
+            #[test]
+            fn test_hinted_decomposition_gives_back_rotation_and_scale(
+                angle in angle(),
+                scale in scale_2d(),
+            ) {
+                let ((a_x, a_y), (b_x, b_y)) = create_2d_rotation_matrix(angle, scale.x, scale.y);
+
+                let (rotation, decomposed_scale) =
+                    decompose_2d_matrix_with_hint(Vec2::new(a_x, a_y), Vec2::new(b_x, b_y), scale);
+                prop_assert!(angle_difference(rotation, angle) < 1e-4);
+                prop_assert!(decomposed_scale.abs_diff_eq(scale, 1e-4 * scale.abs().max_element()));
+            }
+
+            #[test]
+            fn test_z_rotation_ignores_tilt(angle in angle(), tilt in tilt()) {
+                let rotation = tilt * Quat::from_rotation_z(angle);
+                for quat in [rotation, -rotation] {
+                    let rotation_z = extract_z_rotation_from_quat(quat);
+                    prop_assert!((-PI..=PI).contains(&rotation_z));
+                    prop_assert!(angle_difference(rotation_z, angle) < 1e-4);
+                }
+            }
+
+            #[test]
+            fn test_twist_keeps_the_z_rotation_and_scale(
+                transform in transform_2d(),
+                tilt in tilt(),
+            ) {
+                let tilted = Transform {
+                    rotation: tilt * transform.rotation,
+                    ..transform
+                };
+
+                let (a, b) = transform_to_2d_matrix(&tilted, NonZRotationPolicy::Twist);
+                let (expected_a, expected_b) =
+                    transform_to_2d_matrix(&transform, NonZRotationPolicy::Twist);
+                prop_assert!(matrix_2d_approx_eq(
+                    [a, b, Vec2::ZERO],
+                    [expected_a, expected_b, Vec2::ZERO],
+                ));
+            }
+
+            #[test]
+            fn test_project_matches_the_3d_transform_seen_from_the_front(
+                transform in transform_2d(),
+                tilt in tilt(),
+                point in (-10.0_f32..10.0, -10.0_f32..10.0),
+            ) {
+                let transform = Transform {
+                    rotation: tilt * transform.rotation,
+                    ..transform
+                };
+                let point = Vec2::new(point.0, point.1);
+
+                let (a, b) = transform_to_2d_matrix(&transform, NonZRotationPolicy::Project);
+                let projected = a * point.x + b * point.y + transform.translation.truncate();
+                let expected = transform.transform_point(point.extend(0.0)).truncate();
+                prop_assert!(projected.abs_diff_eq(expected, 1e-3));
+            }
+
+            #[test]
+            fn test_policies_agree_on_z_rotations(transform in transform_2d()) {
+                let (twist_a, twist_b) =
+                    transform_to_2d_matrix(&transform, NonZRotationPolicy::Twist);
+                let (project_a, project_b) =
+                    transform_to_2d_matrix(&transform, NonZRotationPolicy::Project);
+                prop_assert!(matrix_2d_approx_eq(
+                    [twist_a, twist_b, Vec2::ZERO],
+                    [project_a, project_b, Vec2::ZERO],
+                ));
+            }
+        }
+    }
+}
+
+/// Proptest strategies for the transforms tested here and in the conversions
+#[cfg(test)]
+pub(crate) mod strategies {
+    use bevy::prelude::{Quat, Transform, Vec2, Vec3};
+    use proptest::prelude::*;
+    use std::f32::consts::PI;
+
+    pub fn angle() -> impl Strategy<Value = f32> {
+        -PI..PI
+    }
+
+    /// Nonzero scale, possibly negative
+    pub fn scale() -> impl Strategy<Value = f32> {
+        (0.1_f32..10.0, any::<bool>()).prop_map(
+            |(scale, negative)| {
+                if negative { -scale } else { scale }
+            },
+        )
+    }
+
+    pub fn scale_2d() -> impl Strategy<Value = Vec2> {
+        (scale(), scale()).prop_map(|(x, y)| Vec2::new(x, y))
+    }
+
+    /// Transform a 2D node can have: a translation in the XY plane, a rotation around Z and a
+    /// possibly negative scale
+    pub fn transform_2d() -> impl Strategy<Value = Transform> {
+        (
+            -1000.0_f32..1000.0,
+            -1000.0_f32..1000.0,
+            angle(),
+            scale_2d(),
+        )
+            .prop_map(|(x, y, angle, scale)| Transform {
+                translation: Vec3::new(x, y, 0.0),
+                rotation: Quat::from_rotation_z(angle),
+                scale: scale.extend(1.0),
+            })
+    }
+
+    /// Rotation around an axis in the XY plane, by less than a half turn so Z isn't turned
+    /// upside down. Rotations around Z done before it keep their twist around Z
+    pub fn tilt() -> impl Strategy<Value = Quat> {
+        (angle(), 0.0_f32..PI * 0.9).prop_map(|(axis_angle, tilt_angle)| {
+            let (sin, cos) = axis_angle.sin_cos();
+            Quat::from_axis_angle(Vec3::new(cos, sin, 0.0), tilt_angle)
+        })
     }
 }
@@ -1,16 +1,24 @@
+pub mod affine_2d;
 pub mod change_filter;
 pub mod config;
 pub mod conversions;
 pub mod custom_sync;
+pub mod interpolation;
 pub mod math;
 pub mod plugin;
 pub mod sync_systems;
 
 // Re-export main components and types
+pub use affine_2d::AffineTransform2D;
 pub use change_filter::TransformSyncMetadata;
-pub use config::{GodotTransformConfig, TransformSyncMode};
-pub use conversions::{IntoBevyTransform, IntoGodotTransform, IntoGodotTransform2D};
+pub use config::{
+    GodotTransformConfig, TransformReadMode, TransformSync, TransformSyncMode, TransformSyncSpace,
+};
+pub use conversions::{
+    IntoAffine2, IntoBevyTransform, IntoBevyTransformWith, IntoGodotTransform, IntoGodotTransform2D,
+};
 pub use custom_sync::{GodotTransformSyncPluginExt, add_transform_sync_systems};
+pub use interpolation::TransformInterpolation;
 pub use plugin::GodotTransformSyncPlugin;
 
 // Re-export math utilities for advanced users
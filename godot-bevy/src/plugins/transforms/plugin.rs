@@ -1,22 +1,58 @@
 use bevy::{
     app::{App, Last, Plugin, PreUpdate},
-    ecs::{schedule::IntoScheduleConfigs, system::Res},
-    prelude::Transform,
+    ecs::{
+        observer::Trigger,
+        resource::Resource,
+        schedule::IntoScheduleConfigs,
+        system::{Query, Res, ResMut},
+        world::{OnInsert, OnReplace},
+    },
+    prelude::{GlobalTransform, Transform},
 };
 use godot::classes::{Node2D, Node3D};
 
-use crate::plugins::core::AppSceneTreeExt;
-use crate::plugins::transforms::IntoBevyTransform;
-use crate::plugins::transforms::{GodotTransformConfig, TransformSyncMode};
+use crate::plugins::core::{AppSceneTreeExt, GodotSyncSet, PrePhysicsUpdate};
+use crate::plugins::transforms::{
+    GodotTransformConfig, TransformReadMode, TransformSync, TransformSyncMode, TransformSyncSpace,
+};
+use crate::plugins::transforms::{IntoAffine2, IntoBevyTransform, NonZRotationPolicy};
 
+use super::affine_2d::{
+    AffineTransform2D, post_update_godot_affine_transforms, pre_update_godot_affine_transforms,
+};
 use super::change_filter::TransformSyncMetadata;
-use super::sync_systems::{post_update_godot_transforms, pre_update_godot_transforms};
+use super::interpolation::{
+    record_physics_transforms, update_interpolated_entities, write_interpolated_transforms,
+};
+use super::sync_systems::{
+    enable_transform_notifications, mirror_godot_global_transforms, post_update_godot_transforms,
+    pre_update_godot_transforms,
+};
 
 pub struct GodotTransformSyncPlugin {
-    /// The mode for syncing transforms between Godot and Bevy.
+    /// The mode for syncing transforms between Godot and Bevy, for entities without a
+    /// [`TransformSync`] override.
     /// Note: This setting is only relevant when `auto_sync` is true.
     /// When `auto_sync` is false, this value is ignored since no automatic sync systems run.
     pub sync_mode: TransformSyncMode,
+    /// Whether `Transform` holds the node's local transform (default) or its global transform.
+    /// Used by the automatic sync systems and the ones from `add_transform_sync_systems!`.
+    pub sync_space: TransformSyncSpace,
+    /// Whether nodes are read every frame (default) or only after reporting a transform change.
+    /// Only used by the automatic sync systems.
+    pub read_mode: TransformReadMode,
+    /// How rotations around X/Y are written to 2D nodes, see [`NonZRotationPolicy`]
+    pub non_z_rotation: NonZRotationPolicy,
+    /// When true, `GlobalTransform` is filled with the node's global transform for read-only
+    /// use, see [`GodotTransformConfig::mirror_global_transform`]
+    pub mirror_global_transform: bool,
+    /// When true, Node2D/Node3D entities that only write to Godot get
+    /// [`TransformInterpolation`](super::TransformInterpolation) and their nodes are written a blend of the last two physics ticks, see
+    /// [`GodotTransformConfig::interpolate_transforms`]. Only used by the automatic sync systems.
+    pub interpolate_transforms: bool,
+    /// When true, Node2D entities also get an [`AffineTransform2D`], which is synced in place of
+    /// `Transform` and keeps skew and reflections exactly.
+    pub affine_transform_2d: bool,
     /// When true (default), enables automatic transform syncing systems.
     /// When false, still registers Transform and TransformSyncMetadata components
     /// but allows defining custom sync systems using the add_transform_sync_systems_*! macros.
@@ -27,6 +63,12 @@ impl Default for GodotTransformSyncPlugin {
     fn default() -> Self {
         Self {
             sync_mode: TransformSyncMode::default(),
+            sync_space: TransformSyncSpace::default(),
+            read_mode: TransformReadMode::default(),
+            non_z_rotation: NonZRotationPolicy::default(),
+            mirror_global_transform: false,
+            interpolate_transforms: false,
+            affine_transform_2d: false,
             auto_sync: true,
         }
     }
@@ -34,13 +76,38 @@ impl Default for GodotTransformSyncPlugin {
 
 impl Plugin for GodotTransformSyncPlugin {
     fn build(&self, app: &mut App) {
+        let global = self.sync_space == TransformSyncSpace::Global;
+        let mirror_global_transform = self.mirror_global_transform;
+        let affine_transform_2d = self.affine_transform_2d;
+
         // Register Transform component with custom initialization that reads from Godot
-        app.register_scene_tree_component_with_init::<Transform, _>(|entity, node| {
+        app.register_scene_tree_component_with_init::<Transform, _>(move |entity, node| {
             let mut node_handle = node.clone(); // Clone to get mutable access
             if let Some(node3d) = node_handle.try_get::<Node3D>() {
-                entity.insert(node3d.get_transform().to_bevy_transform());
+                let global_transform = node3d.get_global_transform().to_bevy_transform();
+                if global {
+                    entity.insert(global_transform);
+                } else {
+                    entity.insert(node3d.get_transform().to_bevy_transform());
+                }
+                if mirror_global_transform {
+                    entity.insert(GlobalTransform::from(global_transform));
+                }
             } else if let Some(node2d) = node_handle.try_get::<Node2D>() {
-                entity.insert(node2d.get_transform().to_bevy_transform());
+                let transform_2d = if global {
+                    node2d.get_global_transform()
+                } else {
+                    node2d.get_transform()
+                };
+                entity.insert(transform_2d.to_bevy_transform());
+                if affine_transform_2d {
+                    entity.insert(AffineTransform2D(transform_2d.to_affine2()));
+                }
+                if mirror_global_transform {
+                    entity.insert(GlobalTransform::from(
+                        node2d.get_global_transform().to_bevy_transform(),
+                    ));
+                }
             } else {
                 // Fallback to default for non-spatial nodes
                 entity.insert(Transform::default());
@@ -52,30 +119,198 @@ impl Plugin for GodotTransformSyncPlugin {
         // Register the transform configuration resource with the plugin's config
         app.insert_resource(GodotTransformConfig {
             sync_mode: self.sync_mode,
+            sync_space: self.sync_space,
+            read_mode: self.read_mode,
+            non_z_rotation: self.non_z_rotation,
+            mirror_global_transform: self.mirror_global_transform,
+            interpolate_transforms: self.interpolate_transforms,
         });
 
+        app.init_resource::<TransformSyncOverrides>()
+            .add_observer(count_inserted_override)
+            .add_observer(uncount_replaced_override);
+
+        app.add_systems(
+            PreUpdate,
+            mirror_godot_global_transforms
+                .run_if(global_transform_mirroring_enabled)
+                .in_set(GodotSyncSet::ReadTransforms),
+        );
+
         // Only add automatic sync systems if auto_sync is enabled
         if self.auto_sync {
-            // Add systems that sync godot -> bevy transforms when two-way syncing enabled
+            // Add systems that sync godot -> bevy transforms when the default mode or a
+            // `TransformSync` override reads from Godot
             app.add_systems(
                 PreUpdate,
-                pre_update_godot_transforms.run_if(transform_sync_twoway_enabled),
+                (
+                    pre_update_godot_transforms,
+                    pre_update_godot_affine_transforms,
+                )
+                    .run_if(transform_reads_enabled)
+                    .in_set(GodotSyncSet::ReadTransforms),
             );
 
-            // Add systems that sync bevy -> godot transforms when one or two-way syncing enabled
+            // Outside of the read condition, so nodes that stopped reading lose their notifiers
+            app.add_systems(
+                PreUpdate,
+                enable_transform_notifications
+                    .run_if(transform_notifications_enabled)
+                    .before(pre_update_godot_transforms)
+                    .in_set(GodotSyncSet::ReadTransforms),
+            );
+
+            // Add systems that sync bevy -> godot transforms when the default mode or a
+            // `TransformSync` override writes to Godot
             app.add_systems(
                 Last,
-                post_update_godot_transforms.run_if(transform_sync_enabled),
+                (
+                    post_update_godot_transforms,
+                    post_update_godot_affine_transforms,
+                )
+                    .run_if(transform_writes_enabled)
+                    .in_set(GodotSyncSet::WriteTransforms),
+            );
+
+            // Render interpolation: pick the entities to interpolate, remember where each physics
+            // tick started, and write the blend of the last two ticks every frame
+            app.add_systems(
+                PreUpdate,
+                update_interpolated_entities.before(GodotSyncSet::ReadTransforms),
+            )
+            .add_systems(PrePhysicsUpdate, record_physics_transforms)
+            .add_systems(
+                Last,
+                write_interpolated_transforms
+                    .run_if(transform_writes_enabled)
+                    .in_set(GodotSyncSet::WriteTransforms),
             );
         }
     }
 }
 
-fn transform_sync_enabled(config: Res<GodotTransformConfig>) -> bool {
-    // aka one way or two way
-    config.sync_mode != TransformSyncMode::Disabled
+/// How many [`TransformSync`] overrides read from and write to Godot, so the sync conditions don't
+/// have to check every entity each frame
+#[derive(Resource, Default, Debug)]
+struct TransformSyncOverrides {
+    reading: usize,
+    writing: usize,
+}
+
+impl TransformSyncOverrides {
+    fn add(&mut self, mode: TransformSyncMode) {
+        self.reading += usize::from(mode.reads_from_godot());
+        self.writing += usize::from(mode.writes_to_godot());
+    }
+
+    fn remove(&mut self, mode: TransformSyncMode) {
+        self.reading -= usize::from(mode.reads_from_godot());
+        self.writing -= usize::from(mode.writes_to_godot());
+    }
+}
+
+fn count_inserted_override(
+    trigger: Trigger<OnInsert, TransformSync>,
+    overrides: Query<&TransformSync>,
+    mut counts: ResMut<TransformSyncOverrides>,
+) {
+    if let Ok(sync) = overrides.get(trigger.target()) {
+        counts.add(sync.0);
+    }
 }
 
-fn transform_sync_twoway_enabled(config: Res<GodotTransformConfig>) -> bool {
-    config.sync_mode == TransformSyncMode::TwoWay
+/// Also triggered before the component is removed, or its entity despawned
+fn uncount_replaced_override(
+    trigger: Trigger<OnReplace, TransformSync>,
+    overrides: Query<&TransformSync>,
+    mut counts: ResMut<TransformSyncOverrides>,
+) {
+    if let Ok(sync) = overrides.get(trigger.target()) {
+        counts.remove(sync.0);
+    }
+}
+
+fn transform_writes_enabled(
+    config: Res<GodotTransformConfig>,
+    overrides: Res<TransformSyncOverrides>,
+) -> bool {
+    config.sync_mode.writes_to_godot() || overrides.writing > 0
+}
+
+fn transform_notifications_enabled(config: Res<GodotTransformConfig>) -> bool {
+    // Also right after switching away, to free the notifiers
+    config.read_mode == TransformReadMode::OnTransformChanged || config.is_changed()
+}
+
+fn global_transform_mirroring_enabled(config: Res<GodotTransformConfig>) -> bool {
+    config.mirror_global_transform
+}
+
+fn transform_reads_enabled(
+    config: Res<GodotTransformConfig>,
+    overrides: Res<TransformSyncOverrides>,
+) -> bool {
+    config.sync_mode.reads_from_godot() || overrides.reading > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::{system::RunSystemOnce, world::World};
+
+    fn world_with_overrides(sync_mode: TransformSyncMode) -> World {
+        let mut world = World::new();
+        world.insert_resource(GodotTransformConfig {
+            sync_mode,
+            ..Default::default()
+        });
+        world.init_resource::<TransformSyncOverrides>();
+        world.add_observer(count_inserted_override);
+        world.add_observer(uncount_replaced_override);
+        world
+    }
+
+    /// `(transform_reads_enabled, transform_writes_enabled)`
+    fn enabled(world: &mut World) -> (bool, bool) {
+        let reads = world.run_system_once(transform_reads_enabled).unwrap();
+        let writes = world.run_system_once(transform_writes_enabled).unwrap();
+        (reads, writes)
+    }
+
+    #[test]
+    fn test_overrides_enable_sync() {
+        let mut world = world_with_overrides(TransformSyncMode::Disabled);
+        assert_eq!(enabled(&mut world), (false, false));
+
+        let entity = world
+            .spawn(TransformSync(TransformSyncMode::GodotToBevy))
+            .id();
+        assert_eq!(enabled(&mut world), (true, false));
+
+        // Replaced, not added on top
+        world
+            .entity_mut(entity)
+            .insert(TransformSync(TransformSyncMode::OneWay));
+        assert_eq!(enabled(&mut world), (false, true));
+
+        let other = world.spawn(TransformSync(TransformSyncMode::TwoWay)).id();
+        assert_eq!(enabled(&mut world), (true, true));
+
+        world.entity_mut(entity).remove::<TransformSync>();
+        assert_eq!(enabled(&mut world), (true, true));
+        world.despawn(other);
+        assert_eq!(enabled(&mut world), (false, false));
+        assert_eq!(world.resource::<TransformSyncOverrides>().reading, 0);
+        assert_eq!(world.resource::<TransformSyncOverrides>().writing, 0);
+    }
+
+    #[test]
+    fn test_default_mode_enables_sync_without_overrides() {
+        let mut world = world_with_overrides(TransformSyncMode::TwoWay);
+        assert_eq!(enabled(&mut world), (true, true));
+
+        // Disabling a single entity leaves the default on
+        world.spawn(TransformSync(TransformSyncMode::Disabled));
+        assert_eq!(enabled(&mut world), (true, true));
+    }
 }
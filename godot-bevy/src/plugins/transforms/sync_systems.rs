@@ -1,229 +1,270 @@
 use crate::interop::GodotNodeHandle;
 use crate::interop::node_markers::{Node2DMarker, Node3DMarker};
-use crate::plugins::transforms::{IntoBevyTransform, IntoGodotTransform, IntoGodotTransform2D};
+use crate::plugins::scene_tree::GodotNodeIndex;
+use crate::plugins::transforms::{
+    IntoBevyTransform, IntoBevyTransformWith, IntoGodotTransform, IntoGodotTransform2D,
+};
 use crate::prelude::main_thread_system;
-use bevy::ecs::change_detection::{DetectChanges, Ref};
-use bevy::ecs::query::{AnyOf, Changed};
-use bevy::ecs::system::{Query, SystemChangeTick};
-use bevy::prelude::Transform as BevyTransform;
-use godot::classes::{Engine, Node2D, Node3D, Object, SceneTree};
-use godot::prelude::{Gd, ToGodot};
+use crate::watchers::transform_watcher::set_transform_notifier;
+use bevy::ecs::change_detection::{DetectChanges, Mut, Ref};
+use bevy::ecs::entity::Entity;
+use bevy::ecs::query::{Added, AnyOf, Changed, Or, Without};
+use bevy::ecs::removal_detection::RemovedComponents;
+use bevy::ecs::system::{NonSend, Query, Res, SystemChangeTick};
+use bevy::prelude::{GlobalTransform, Transform as BevyTransform};
+use godot::builtin::{Transform2D as GodotTransform2D, Transform3D as GodotTransform3D};
+use godot::classes::{Node, Node2D, Node3D};
+use godot::obj::InstanceId;
+use std::collections::HashSet;
+use std::sync::mpsc::{Receiver, Sender};
 
+use super::affine_2d::AffineTransform2D;
 use super::change_filter::TransformSyncMetadata;
+use super::config::{GodotTransformConfig, TransformReadMode, TransformSync, TransformSyncSpace};
+use super::interpolation::TransformInterpolation;
+use super::math::NonZRotationPolicy;
 
-#[main_thread_system]
-#[tracing::instrument]
-pub fn pre_update_godot_transforms(
-    mut entities: Query<(
-        &mut BevyTransform,
-        &mut GodotNodeHandle,
-        &mut TransformSyncMetadata,
-        AnyOf<(&Node2DMarker, &Node3DMarker)>,
-    )>,
-) {
-    for (mut bevy_transform, mut reference, mut metadata, (node2d, node3d)) in entities.iter_mut() {
-        let new_bevy_transform = if node2d.is_some() {
-            reference
-                .get::<Node2D>()
-                .get_transform()
-                .to_bevy_transform()
-        } else if node3d.is_some() {
-            reference
-                .get::<Node3D>()
-                .get_transform()
-                .to_bevy_transform()
-        } else {
-            panic!("Expected AnyOf to match either a Node2D or a Node3D, is there a bug in bevy?");
-        };
+/// Receives the instance ids of nodes whose transform changed, reported by their transform
+/// notifiers or the [`TransformWatcher`](crate::watchers::transform_watcher::TransformWatcher)
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct TransformChangedReader(pub Receiver<InstanceId>);
 
-        // Only write if actually different - avoids triggering change detection
-        if *bevy_transform != new_bevy_transform {
-            *bevy_transform = new_bevy_transform;
-
-            // Store the last changed tick for this entity, this helps us in the post_ operations
-            // to disambiguate our change (syncing from Godot to Bevy above) versus changes that
-            // *user* systems do this frame. It's only the latter that we may need to copy back to
-            // Godot
-            metadata.last_sync_tick = Some(bevy_transform.last_changed());
-        }
-    }
-}
+/// Handed to the transform notifiers added by [`enable_transform_notifications`]
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct TransformChangedSender(pub Sender<InstanceId>);
 
 #[main_thread_system]
 #[tracing::instrument]
-pub fn post_update_godot_transforms(
-    change_tick: SystemChangeTick,
-    entities: Query<
+pub fn pre_update_godot_transforms(
+    config: Res<GodotTransformConfig>,
+    changed_nodes: Option<NonSend<TransformChangedReader>>,
+    node_index: Res<GodotNodeIndex>,
+    // Entities with `AffineTransform2D` sync that instead. Interpolated entities don't read from
+    // Godot, so their nodes' blended transforms are never read back
+    mut entities: Query<
         (
-            Ref<BevyTransform>,
+            &mut BevyTransform,
             &mut GodotNodeHandle,
-            &TransformSyncMetadata,
+            &mut TransformSyncMetadata,
             AnyOf<(&Node2DMarker, &Node3DMarker)>,
+            Option<&TransformSync>,
         ),
-        Changed<BevyTransform>,
+        Without<AffineTransform2D>,
     >,
 ) {
-    // Try to get the BevyAppSingleton autoload for bulk optimization
-    let engine = Engine::singleton();
-    if let Some(scene_tree) = engine
-        .get_main_loop()
-        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
-        && let Some(root) = scene_tree.get_root()
-        && let Some(bevy_app) = root.get_node_or_null("BevyAppSingleton")
-    {
-        // Check if this BevyApp has the raw array methods (prefer these over bulk Dictionary methods)
-        if bevy_app.has_method("bulk_update_transforms_3d") {
-            // Use bulk optimization path
-            let _bulk_span = tracing::info_span!("using_bulk_optimization").entered();
-            post_update_godot_transforms_bulk(change_tick, entities, bevy_app.upcast::<Object>());
-            return;
+    // Always drained, so reports don't pile up while reading every frame
+    let changed = reported_entities(changed_nodes.as_deref(), &node_index);
+
+    match config.read_mode {
+        TransformReadMode::EveryFrame => {
+            for entity in entities.iter_mut() {
+                read_entity_transform(entity, &config);
+            }
+        }
+        TransformReadMode::OnTransformChanged => {
+            for entity in changed {
+                if let Ok(entity) = entities.get_mut(entity) {
+                    read_entity_transform(entity, &config);
+                }
+            }
         }
     }
+}
 
-    // Fallback to individual FFI calls
-    post_update_godot_transforms_individual(change_tick, entities);
+/// Drains the reported nodes, returning the entities mirroring them
+fn reported_entities(
+    changed_nodes: Option<&TransformChangedReader>,
+    node_index: &GodotNodeIndex,
+) -> HashSet<Entity> {
+    changed_nodes
+        .map(|changed_nodes| {
+            changed_nodes
+                .0
+                .try_iter()
+                .filter_map(|instance_id| node_index.entity_for(instance_id))
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
-fn post_update_godot_transforms_bulk(
-    change_tick: SystemChangeTick,
-    mut entities: Query<
-        (
-            Ref<BevyTransform>,
-            &mut GodotNodeHandle,
-            &TransformSyncMetadata,
-            AnyOf<(&Node2DMarker, &Node3DMarker)>,
-        ),
-        Changed<BevyTransform>,
-    >,
-    mut batch_singleton: Gd<Object>,
+fn read_entity_transform(
+    (mut bevy_transform, mut reference, mut metadata, (node2d, _), sync): (
+        Mut<BevyTransform>,
+        Mut<GodotNodeHandle>,
+        Mut<TransformSyncMetadata>,
+        (Option<&Node2DMarker>, Option<&Node3DMarker>),
+        Option<&TransformSync>,
+    ),
+    config: &GodotTransformConfig,
 ) {
-    let _span = tracing::info_span!("bulk_data_preparation_optimized").entered();
+    if !sync
+        .map_or(config.sync_mode, |sync| sync.0)
+        .reads_from_godot()
+    {
+        return;
+    }
 
-    // Pre-allocate vectors with estimated capacity to avoid reallocations
-    let entity_count = entities.iter().count();
-    let mut instance_ids_3d = Vec::with_capacity(entity_count);
-    let mut positions_3d = Vec::with_capacity(entity_count);
-    let mut rotations_3d = Vec::with_capacity(entity_count);
-    let mut scales_3d = Vec::with_capacity(entity_count);
+    let Some(new_bevy_transform) =
+        read_synced_transform(&mut reference, &bevy_transform, node2d.is_some(), config)
+    else {
+        return;
+    };
 
-    let mut instance_ids_2d = Vec::with_capacity(entity_count);
-    let mut positions_2d = Vec::with_capacity(entity_count);
-    let mut rotations_2d = Vec::with_capacity(entity_count);
-    let mut scales_2d = Vec::with_capacity(entity_count);
+    // Only write if actually different - avoids triggering change detection
+    if *bevy_transform != new_bevy_transform {
+        *bevy_transform = new_bevy_transform;
 
-    // Collect raw transform data (no FFI allocations)
-    let _collect_span = tracing::info_span!("collect_raw_arrays").entered();
-    for (transform_ref, reference, metadata, (node2d, node3d)) in entities.iter_mut() {
-        // Check if we have sync information for this entity
-        if let Some(sync_tick) = metadata.last_sync_tick
-            && !transform_ref
-                .last_changed()
-                .is_newer_than(sync_tick, change_tick.this_run())
-        {
-            // This change was from our Godot sync, skip it
-            continue;
-        }
+        // Store the last changed tick for this entity, this helps us in the post_ operations
+        // to disambiguate our change (syncing from Godot to Bevy above) versus changes that
+        // *user* systems do this frame. It's only the latter that we may need to copy back to
+        // Godot
+        metadata.last_sync_tick = Some(bevy_transform.last_changed());
+    }
+}
 
-        let instance_id = reference.instance_id();
+/// Gives the nodes that sync from Godot an internal
+/// [`TransformNotifier2D`](crate::watchers::transform_watcher::TransformNotifier2D)/
+/// [`TransformNotifier3D`](crate::watchers::transform_watcher::TransformNotifier3D) child
+/// reporting their transform changes, and frees it from the others, for
+/// [`TransformReadMode::OnTransformChanged`]
+#[main_thread_system]
+pub fn enable_transform_notifications(
+    config: Res<GodotTransformConfig>,
+    changed_sender: Option<NonSend<TransformChangedSender>>,
+    mut removed_overrides: RemovedComponents<TransformSync>,
+    entities: Query<(
+        &GodotNodeHandle,
+        AnyOf<(&Node2DMarker, &Node3DMarker)>,
+        Option<&TransformSync>,
+    )>,
+    changed: Query<
+        Entity,
+        (
+            AnyOf<(&Node2DMarker, &Node3DMarker)>,
+            Or<(Added<GodotNodeHandle>, Changed<TransformSync>)>,
+        ),
+    >,
+) {
+    let Some(changed_sender) = changed_sender else {
+        return;
+    };
 
-        if node2d.is_some() {
-            // Direct field access - avoid transform conversion overhead
-            instance_ids_2d.push(instance_id.to_i64());
-            positions_2d.push(godot::prelude::Vector2::new(
-                transform_ref.translation.x,
-                transform_ref.translation.y,
-            ));
-            // For 2D, rotation is just Z component
-            let (_, _, z) = transform_ref.rotation.to_euler(bevy::math::EulerRot::XYZ);
-            rotations_2d.push(z);
-            scales_2d.push(godot::prelude::Vector2::new(
-                transform_ref.scale.x,
-                transform_ref.scale.y,
-            ));
-        } else if node3d.is_some() {
-            // Use Bevy transform components directly (avoid complex conversions)
-            instance_ids_3d.push(instance_id.to_i64());
-            positions_3d.push(godot::prelude::Vector3::new(
-                transform_ref.translation.x,
-                transform_ref.translation.y,
-                transform_ref.translation.z,
-            ));
+    // Every node when the default mode changed or this mode was just switched on or off
+    let updated = if config.is_changed() {
+        entities.iter().collect::<Vec<_>>()
+    } else {
+        entities
+            .iter_many(changed.iter().chain(removed_overrides.read()))
+            .collect()
+    };
 
-            // Convert Bevy rotation (quaternion) to Euler angles
-            let (x, y, z) = transform_ref.rotation.to_euler(bevy::math::EulerRot::XYZ);
-            rotations_3d.push(godot::prelude::Vector3::new(x, y, z));
+    let watching = config.read_mode == TransformReadMode::OnTransformChanged;
+    let local_only = config.sync_space == TransformSyncSpace::Local;
+    for (handle, (node2d, _), sync) in updated {
+        let enable = watching
+            && sync
+                .map_or(config.sync_mode, |sync| sync.0)
+                .reads_from_godot();
 
-            scales_3d.push(godot::prelude::Vector3::new(
-                transform_ref.scale.x,
-                transform_ref.scale.y,
-                transform_ref.scale.z,
-            ));
+        let mut handle = handle.clone();
+        if let Some(node) = handle.try_get::<Node>() {
+            set_transform_notifier(
+                node,
+                node2d.is_some(),
+                enable,
+                local_only,
+                &changed_sender.0,
+            );
         }
     }
-    drop(_collect_span);
-
-    let has_3d_updates = !instance_ids_3d.is_empty();
-    let has_2d_updates = !instance_ids_2d.is_empty();
-
-    // End data preparation phase
-    drop(_span);
+}
 
-    // Make raw array FFI calls if we have updates
-    let total_updates = instance_ids_3d.len() + instance_ids_2d.len();
-    if total_updates > 0 {
-        let _ffi_calls_span =
-            tracing::info_span!("raw_array_ffi_calls", total_entities = total_updates).entered();
+/// Reads the transform of a Node2D (`is_2d`) or Node3D in the configured sync space, as it is
+/// copied to an entity whose `Transform` is `current`. `None` if the node has been freed
+pub fn read_synced_transform(
+    reference: &mut GodotNodeHandle,
+    current: &BevyTransform,
+    is_2d: bool,
+    config: &GodotTransformConfig,
+) -> Option<BevyTransform> {
+    let global = config.sync_space == TransformSyncSpace::Global;
+    if is_2d {
+        // Compared with what the current `Transform` writes, so a flipped or tilted one isn't
+        // overwritten by an equivalent decomposition of the same matrix
+        read_godot_transform_2d(reference, global)
+            .map(|transform| transform.to_bevy_transform_with(current, config.non_z_rotation))
+    } else {
+        read_godot_transform_3d(reference, global).map(IntoBevyTransform::to_bevy_transform)
+    }
+}
 
-        if has_3d_updates {
-            let _span =
-                tracing::info_span!("raw_ffi_call_3d", entities = instance_ids_3d.len()).entered();
+/// Reads the local or global transform of a Node2D (`is_2d`) or Node3D, `None` if the node has
+/// been freed
+fn read_godot_transform(
+    reference: &mut GodotNodeHandle,
+    is_2d: bool,
+    global: bool,
+) -> Option<BevyTransform> {
+    if is_2d {
+        read_godot_transform_2d(reference, global).map(IntoBevyTransform::to_bevy_transform)
+    } else {
+        read_godot_transform_3d(reference, global).map(IntoBevyTransform::to_bevy_transform)
+    }
+}
 
-            // Convert to packed arrays
-            let instance_ids_packed =
-                godot::prelude::PackedInt64Array::from(instance_ids_3d.as_slice());
-            let positions_packed =
-                godot::prelude::PackedVector3Array::from(positions_3d.as_slice());
-            let rotations_packed =
-                godot::prelude::PackedVector3Array::from(rotations_3d.as_slice());
-            let scales_packed = godot::prelude::PackedVector3Array::from(scales_3d.as_slice());
+fn read_godot_transform_2d(
+    reference: &mut GodotNodeHandle,
+    global: bool,
+) -> Option<GodotTransform2D> {
+    let node = reference.try_get::<Node2D>()?;
+    Some(if global {
+        node.get_global_transform()
+    } else {
+        node.get_transform()
+    })
+}
 
-            batch_singleton.call(
-                "bulk_update_transforms_3d",
-                &[
-                    instance_ids_packed.to_variant(),
-                    positions_packed.to_variant(),
-                    rotations_packed.to_variant(),
-                    scales_packed.to_variant(),
-                ],
-            );
-        }
-        if has_2d_updates {
-            let _span =
-                tracing::info_span!("raw_ffi_call_2d", entities = instance_ids_2d.len()).entered();
+fn read_godot_transform_3d(
+    reference: &mut GodotNodeHandle,
+    global: bool,
+) -> Option<GodotTransform3D> {
+    let node = reference.try_get::<Node3D>()?;
+    Some(if global {
+        node.get_global_transform()
+    } else {
+        node.get_transform()
+    })
+}
 
-            // Convert to packed arrays
-            let instance_ids_packed =
-                godot::prelude::PackedInt64Array::from(instance_ids_2d.as_slice());
-            let positions_packed =
-                godot::prelude::PackedVector2Array::from(positions_2d.as_slice());
-            let rotations_packed =
-                godot::prelude::PackedFloat32Array::from(rotations_2d.as_slice());
-            let scales_packed = godot::prelude::PackedVector2Array::from(scales_2d.as_slice());
+/// Copies the global transform of every node into its entity's `GlobalTransform`
+#[main_thread_system]
+#[tracing::instrument]
+pub fn mirror_godot_global_transforms(
+    mut entities: Query<(
+        &mut GlobalTransform,
+        &mut GodotNodeHandle,
+        AnyOf<(&Node2DMarker, &Node3DMarker)>,
+    )>,
+) {
+    for (mut global_transform, mut reference, (node2d, _)) in entities.iter_mut() {
+        let Some(transform) = read_godot_transform(&mut reference, node2d.is_some(), true) else {
+            continue;
+        };
 
-            batch_singleton.call(
-                "bulk_update_transforms_2d",
-                &[
-                    instance_ids_packed.to_variant(),
-                    positions_packed.to_variant(),
-                    rotations_packed.to_variant(),
-                    scales_packed.to_variant(),
-                ],
-            );
+        // Only write if actually different - avoids triggering change detection
+        let new_global_transform = GlobalTransform::from(transform);
+        if *global_transform != new_global_transform {
+            *global_transform = new_global_transform;
         }
     }
 }
 
-fn post_update_godot_transforms_individual(
+#[main_thread_system]
+#[tracing::instrument]
+pub fn post_update_godot_transforms(
+    config: Res<GodotTransformConfig>,
     change_tick: SystemChangeTick,
     mut entities: Query<
         (
@@ -231,12 +272,25 @@ fn post_update_godot_transforms_individual(
             &mut GodotNodeHandle,
             &TransformSyncMetadata,
             AnyOf<(&Node2DMarker, &Node3DMarker)>,
+            Option<&TransformSync>,
+        ),
+        (
+            Changed<BevyTransform>,
+            Without<TransformInterpolation>,
+            Without<AffineTransform2D>,
         ),
-        Changed<BevyTransform>,
     >,
 ) {
-    // Original individual FFI approach
-    for (transform_ref, mut reference, metadata, (node2d, node3d)) in entities.iter_mut() {
+    let global = config.sync_space == TransformSyncSpace::Global;
+
+    for (transform_ref, mut reference, metadata, (node2d, _), sync) in entities.iter_mut() {
+        if !sync
+            .map_or(config.sync_mode, |sync| sync.0)
+            .writes_to_godot()
+        {
+            continue;
+        }
+
         // Check if we have sync information for this entity
         if let Some(sync_tick) = metadata.last_sync_tick
             && !transform_ref
@@ -247,14 +301,74 @@ fn post_update_godot_transforms_individual(
             continue;
         }
 
-        if node2d.is_some() {
-            let _span = tracing::info_span!("individual_ffi_call_2d").entered();
-            let mut obj = reference.get::<Node2D>();
-            obj.set_transform(transform_ref.to_godot_transform_2d());
-        } else if node3d.is_some() {
-            let _span = tracing::info_span!("individual_ffi_call_3d").entered();
-            let mut obj = reference.get::<Node3D>();
-            obj.set_transform(transform_ref.to_godot_transform());
+        write_godot_transform(
+            &mut reference,
+            &transform_ref,
+            node2d.is_some(),
+            global,
+            config.non_z_rotation,
+        );
+    }
+}
+
+/// Sets the local or global transform of a Node2D (`is_2d`) or Node3D. Nodes that have been
+/// freed are skipped
+pub fn write_godot_transform(
+    reference: &mut GodotNodeHandle,
+    transform: &BevyTransform,
+    is_2d: bool,
+    global: bool,
+    non_z_rotation: NonZRotationPolicy,
+) {
+    if is_2d {
+        let Some(mut node) = reference.try_get::<Node2D>() else {
+            return;
+        };
+        let transform_2d = transform.to_godot_transform_2d_with(non_z_rotation);
+        if global {
+            node.set_global_transform(transform_2d);
+        } else {
+            node.set_transform(transform_2d);
+        }
+    } else {
+        let Some(mut node) = reference.try_get::<Node3D>() else {
+            return;
+        };
+        if global {
+            node.set_global_transform(transform.to_godot_transform());
+        } else {
+            node.set_transform(transform.to_godot_transform());
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::watchers::transform_watcher::report_transform_changed;
+    use std::sync::mpsc::channel;
+
+    #[test]
+    fn test_notifier_reports_select_mirroring_entities() {
+        let mut node_index = GodotNodeIndex::default();
+        let moved = Entity::from_raw(1);
+        let still = Entity::from_raw(2);
+        node_index.insert(InstanceId::from_i64(10), moved);
+        node_index.insert(InstanceId::from_i64(20), still);
+
+        // What a notifier does when its parent moves, the moved node has no script of its own
+        let (sender, receiver) = channel();
+        let reader = TransformChangedReader(receiver);
+        report_transform_changed(Some(&sender), InstanceId::from_i64(10));
+        report_transform_changed(Some(&sender), InstanceId::from_i64(10));
+        // Nodes that aren't mirrored are ignored
+        report_transform_changed(Some(&sender), InstanceId::from_i64(30));
+
+        assert_eq!(
+            reported_entities(Some(&reader), &node_index),
+            HashSet::from([moved])
+        );
+        // Reports are drained
+        assert!(reported_entities(Some(&reader), &node_index).is_empty());
+    }
+}
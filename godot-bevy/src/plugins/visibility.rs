@@ -0,0 +1,249 @@
+//! Sync between [`GodotVisibility`] and the `visible` property of `CanvasItem` and `Node3D`
+//! nodes.
+//!
+//! Godot only has a visible/hidden flag, which hides the node's children as well, so the states
+//! are mapped as follows:
+//! - `GodotVisibility::Hidden` is written as `visible = false`
+//! - `GodotVisibility::Inherited` and `GodotVisibility::Visible` are written as `visible = true`.
+//!   Godot has no way to show a node whose parent is hidden, so `Visible` behaves like `Inherited`
+//! - Reading `visible = false` gives `Hidden`, reading `visible = true` keeps `Visible` and
+//!   turns `Hidden` into `Inherited`
+
+use bevy::{
+    app::{App, Plugin, PostUpdate, PreUpdate},
+    ecs::{
+        change_detection::{DetectChanges, Ref},
+        component::{Component, Tick},
+        query::{AnyOf, Changed},
+        resource::Resource,
+        schedule::IntoScheduleConfigs,
+        system::{Query, Res, SystemChangeTick},
+    },
+};
+use godot::classes::{CanvasItem, Node3D};
+
+use crate::interop::GodotNodeHandle;
+use crate::interop::node_markers::{CanvasItemMarker, Node3DMarker};
+use crate::plugins::core::{AppSceneTreeExt, GodotSyncSet};
+use crate::prelude::main_thread_system;
+
+/// Whether the node of an entity is shown, synced with its `visible` property.
+///
+/// Mirrors Bevy's `Visibility`, which in Bevy 0.16 is part of `bevy_render`. Godot does the
+/// rendering, so godot-bevy has its own component rather than depending on Bevy's renderer.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GodotVisibility {
+    /// Shown if the parent node is shown
+    #[default]
+    Inherited,
+    Hidden,
+    /// Behaves like `Inherited`, Godot can't show a node whose parent is hidden
+    Visible,
+}
+
+/// Visibility synchronization modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VisibilitySyncMode {
+    /// No visibility syncing, `GodotVisibility` is only set from the node when the entity is
+    /// spawned
+    Disabled,
+    /// One-way sync: ECS → Godot only
+    #[default]
+    OneWay,
+    /// Two-way sync: ECS ↔ Godot, for nodes that are also shown and hidden from GDScript or
+    /// animations
+    TwoWay,
+}
+
+/// Configuration resource for visibility syncing behavior
+#[derive(Default, Resource, Debug, Clone)]
+pub struct GodotVisibilityConfig {
+    pub sync_mode: VisibilitySyncMode,
+}
+
+impl GodotVisibilityConfig {
+    /// Disable visibility syncing
+    pub fn disabled() -> Self {
+        Self {
+            sync_mode: VisibilitySyncMode::Disabled,
+        }
+    }
+
+    /// Enable one-way sync (ECS → Godot) - default behavior
+    pub fn one_way() -> Self {
+        Self {
+            sync_mode: VisibilitySyncMode::OneWay,
+        }
+    }
+
+    /// Enable two-way sync (ECS ↔ Godot)
+    pub fn two_way() -> Self {
+        Self {
+            sync_mode: VisibilitySyncMode::TwoWay,
+        }
+    }
+}
+
+/// Metadata component to tell Godot-originated `GodotVisibility` changes apart from Bevy ones
+#[derive(Component, Default)]
+pub struct VisibilitySyncMetadata {
+    pub last_sync_tick: Option<Tick>,
+}
+
+/// Inserts [`GodotVisibility`] on `CanvasItem` and `Node3D` entities, initialized from the node,
+/// and keeps it in sync with the node's `visible` property.
+///
+/// Not part of [`GodotDefaultPlugins`](super::GodotDefaultPlugins), and requires the
+/// `visibility_sync` feature.
+#[derive(Default)]
+pub struct GodotVisibilitySyncPlugin {
+    pub sync_mode: VisibilitySyncMode,
+}
+
+impl Plugin for GodotVisibilitySyncPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_scene_tree_component_with_init::<GodotVisibility, _>(|entity, node| {
+            let mut node = node.clone();
+            let visible = if let Some(canvas_item) = node.try_get::<CanvasItem>() {
+                canvas_item.is_visible()
+            } else if let Some(node3d) = node.try_get::<Node3D>() {
+                node3d.is_visible()
+            } else {
+                // Only nodes with a `visible` property get a `GodotVisibility`
+                return;
+            };
+
+            entity.insert((
+                visibility_from_godot(visible, GodotVisibility::Inherited),
+                VisibilitySyncMetadata::default(),
+            ));
+        })
+        .insert_resource(GodotVisibilityConfig {
+            sync_mode: self.sync_mode,
+        })
+        .add_systems(
+            PreUpdate,
+            read_visibility_from_godot
+                .run_if(visibility_sync_twoway_enabled)
+                .in_set(GodotSyncSet::ReadProperties),
+        )
+        .add_systems(
+            PostUpdate,
+            write_visibility_to_godot
+                .run_if(visibility_sync_enabled)
+                .in_set(GodotSyncSet::WriteProperties),
+        );
+    }
+}
+
+fn visibility_sync_enabled(config: Res<GodotVisibilityConfig>) -> bool {
+    config.sync_mode != VisibilitySyncMode::Disabled
+}
+
+fn visibility_sync_twoway_enabled(config: Res<GodotVisibilityConfig>) -> bool {
+    config.sync_mode == VisibilitySyncMode::TwoWay
+}
+
+/// The `GodotVisibility` matching a node's `visible` property, given the entity's current one
+fn visibility_from_godot(visible: bool, current: GodotVisibility) -> GodotVisibility {
+    match (visible, current) {
+        (false, _) => GodotVisibility::Hidden,
+        (true, GodotVisibility::Hidden) => GodotVisibility::Inherited,
+        (true, current) => current,
+    }
+}
+
+#[main_thread_system]
+fn read_visibility_from_godot(
+    mut entities: Query<(
+        &mut GodotVisibility,
+        &GodotNodeHandle,
+        &mut VisibilitySyncMetadata,
+        AnyOf<(&CanvasItemMarker, &Node3DMarker)>,
+    )>,
+) {
+    for (mut visibility, handle, mut metadata, (canvas_item, node3d)) in entities.iter_mut() {
+        let mut handle = handle.clone();
+        // Nodes freed before their `NodeRemoved` event is processed are skipped
+        let visible = if canvas_item.is_some()
+            && let Some(canvas_item) = handle.try_get::<CanvasItem>()
+        {
+            canvas_item.is_visible()
+        } else if node3d.is_some()
+            && let Some(node3d) = handle.try_get::<Node3D>()
+        {
+            node3d.is_visible()
+        } else {
+            continue;
+        };
+
+        // Only write if actually different - avoids triggering change detection
+        let new_visibility = visibility_from_godot(visible, *visibility);
+        if *visibility != new_visibility {
+            *visibility = new_visibility;
+            metadata.last_sync_tick = Some(visibility.last_changed());
+        }
+    }
+}
+
+#[main_thread_system]
+fn write_visibility_to_godot(
+    change_tick: SystemChangeTick,
+    entities: Query<
+        (
+            Ref<GodotVisibility>,
+            &GodotNodeHandle,
+            Option<&VisibilitySyncMetadata>,
+            AnyOf<(&CanvasItemMarker, &Node3DMarker)>,
+        ),
+        Changed<GodotVisibility>,
+    >,
+) {
+    for (visibility, handle, metadata, (canvas_item, node3d)) in entities.iter() {
+        // Skip changes that were synced from Godot
+        if let Some(sync_tick) = metadata.and_then(|metadata| metadata.last_sync_tick)
+            && !visibility
+                .last_changed()
+                .is_newer_than(sync_tick, change_tick.this_run())
+        {
+            continue;
+        }
+
+        let visible = *visibility != GodotVisibility::Hidden;
+        let mut handle = handle.clone();
+        if canvas_item.is_some()
+            && let Some(mut canvas_item) = handle.try_get::<CanvasItem>()
+        {
+            canvas_item.set_visible(visible);
+        } else if node3d.is_some()
+            && let Some(mut node3d) = handle.try_get::<Node3D>()
+        {
+            node3d.set_visible(visible);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visibility_from_godot() {
+        assert_eq!(
+            visibility_from_godot(false, GodotVisibility::Visible),
+            GodotVisibility::Hidden
+        );
+        assert_eq!(
+            visibility_from_godot(true, GodotVisibility::Hidden),
+            GodotVisibility::Inherited
+        );
+        assert_eq!(
+            visibility_from_godot(true, GodotVisibility::Visible),
+            GodotVisibility::Visible
+        );
+        assert_eq!(
+            visibility_from_godot(true, GodotVisibility::Inherited),
+            GodotVisibility::Inherited
+        );
+    }
+}
@@ -3,6 +3,11 @@ pub use crate::interop::*;
 pub use crate::node_tree_view::NodeTreeView;
 #[cfg(feature = "godot_bevy_log")]
 pub use crate::plugins::godot_bevy_logger::GodotBevyLogPlugin;
+#[cfg(feature = "visibility_sync")]
+pub use crate::plugins::visibility::{
+    GodotVisibility, GodotVisibilityConfig, GodotVisibilitySyncPlugin, VisibilitySyncMetadata,
+    VisibilitySyncMode,
+};
 pub use crate::plugins::{
     // Signals
     GodotCorePlugins,
@@ -18,25 +23,41 @@ pub use crate::plugins::{
         AREA_ENTERED, AREA_EXITED, BODY_ENTERED, BODY_EXITED, COLLISION_START_SIGNALS,
         CollisionEvent, CollisionEventType, Collisions, GodotCollisionsPlugin,
     },
-    core::{FindEntityByNameExt, MainThreadMarker, PhysicsDelta, PhysicsUpdate},
+    core::{
+        AppSceneTreeExt, FindEntityByNameExt, GodotSyncSet, MainThreadMarker, PhysicsDelta,
+        PhysicsUpdate,
+    },
+    godot_commands::{
+        GodotCommandArgs, GodotCommandTarget, GodotCommands, GodotCommandsPlugin, GodotNodeCommands,
+    },
     // Collisions
     input::{
         ActionInput, BevyInputBridgePlugin, GodotInputEventPlugin, KeyboardInput, MouseButtonInput,
         MouseMotion,
     },
     // Core functionality
-    packed_scene::{GodotPackedScenePlugin, GodotScene},
+    packed_scene::{
+        GodotNodeSpawn, GodotNodeSpawnError, GodotNodeSpawnFailed, GodotPackedScenePlugin,
+        GodotScene, GodotScenePool, GodotSceneReady, GodotSceneSpawnConfig, GodotSceneSpawnError,
+        GodotSceneSpawnFailed, PooledSceneInstance, ReturnToPoolExt, SceneInstanceMembers,
+        SpawnNode,
+    },
+    property_sync::{AppPropertySyncExt, PropertySyncDirection, PropertySyncMetadata},
     // Input
     scene_tree::{
-        AutoSyncBundleRegistry, GodotSceneTreePlugin, Groups, SceneTreeConfig, SceneTreeRef,
+        AppGroupsExt, AutoSyncBundleRegistry, GodotGroup, GodotNodeIndex, GodotSceneTreePlugin,
+        GodotScriptClass, Groups, InGroup, NodeDespawnPolicy, NodeFilterRule, NodeReparented,
+        SceneTreeConfig, SceneTreeFilter, SceneTreeRef,
     },
     signals::{
         GodotSignal, GodotSignalArgument, GodotSignals, GodotSignalsPlugin, connect_godot_signal,
     },
     // Scene tree
     transforms::{
-        GodotTransformConfig, GodotTransformSyncPlugin, GodotTransformSyncPluginExt,
-        TransformSyncMetadata, TransformSyncMode, add_transform_sync_systems,
+        AffineTransform2D, GodotTransformConfig, GodotTransformSyncPlugin,
+        GodotTransformSyncPluginExt, NonZRotationPolicy, TransformInterpolation, TransformReadMode,
+        TransformSync, TransformSyncMetadata, TransformSyncMode, TransformSyncSpace,
+        add_transform_sync_systems,
     },
 };
 pub use bevy::prelude as bevy_prelude;
@@ -1,3 +1,4 @@
 pub mod collision_watcher;
 pub mod input_watcher;
 pub mod scene_tree_watcher;
+pub mod transform_watcher;
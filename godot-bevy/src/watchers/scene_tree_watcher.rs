@@ -6,6 +6,7 @@ use std::sync::mpsc::Sender;
 use crate::{
     interop::GodotNodeHandle,
     plugins::scene_tree::{SceneTreeEvent, SceneTreeEventType},
+    watchers::transform_watcher::{TRANSFORM_NOTIFIER_NAME, is_transform_notifier},
 };
 
 #[derive(GodotClass)]
@@ -13,6 +14,7 @@ use crate::{
 pub struct SceneTreeWatcher {
     base: Base<Node>,
     pub notification_channel: Option<Sender<SceneTreeEvent>>,
+    transform_notifier_name: StringName,
 }
 
 #[godot_api]
@@ -21,6 +23,7 @@ impl INode for SceneTreeWatcher {
         Self {
             base,
             notification_channel: None,
+            transform_notifier_name: StringName::from(TRANSFORM_NOTIFIER_NAME),
         }
     }
 }
@@ -29,6 +32,11 @@ impl INode for SceneTreeWatcher {
 impl SceneTreeWatcher {
     #[func]
     pub fn scene_tree_event(&self, node: Gd<Node>, event_type: SceneTreeEventType) {
+        // godot-bevy's own transform notifiers only exist to watch their parent
+        if is_transform_notifier(&node, &self.transform_notifier_name) {
+            return;
+        }
+
         if let Some(channel) = self.notification_channel.as_ref() {
             let _ = channel.send(SceneTreeEvent {
                 node: GodotNodeHandle::from_instance_id(node.instance_id()),
@@ -0,0 +1,226 @@
+use godot::builtin::{StringName, Transform2D, Transform3D};
+use godot::classes::node::InternalMode;
+use godot::classes::notify::{CanvasItemNotification, Node3DNotification};
+use godot::classes::{INode2D, INode3D, Node, Node2D, Node3D};
+use godot::obj::{Gd, InstanceId};
+use godot::prelude::*;
+use std::sync::mpsc::Sender;
+
+/// Name of the internal child reporting the transform changes of a node, see
+/// [`set_transform_notifier`]
+pub const TRANSFORM_NOTIFIER_NAME: &str = "BevyTransformNotifier";
+
+/// Collects the nodes whose transform changed, for
+/// [`TransformReadMode::OnTransformChanged`](crate::plugins::transforms::TransformReadMode).
+///
+/// Nodes that sync from Godot report their changes on their own, through an internal
+/// [`TransformNotifier2D`]/[`TransformNotifier3D`] child. Scripts can also report a node from
+/// GDScript, e.g. after changing a transform that isn't otherwise watched:
+///
+/// ```gdscript
+/// $"/root/BevyAppSingleton/TransformWatcher".transform_changed(self)
+/// ```
+#[derive(GodotClass)]
+#[class(base=Node)]
+pub struct TransformWatcher {
+    base: Base<Node>,
+    pub notification_channel: Option<Sender<InstanceId>>,
+}
+
+#[godot_api]
+impl INode for TransformWatcher {
+    fn init(base: Base<Node>) -> Self {
+        Self {
+            base,
+            notification_channel: None,
+        }
+    }
+}
+
+#[godot_api]
+impl TransformWatcher {
+    #[func]
+    pub fn transform_changed(&self, node: Gd<Node>) {
+        report_transform_changed(self.notification_channel.as_ref(), node.instance_id());
+    }
+}
+
+/// Internal child of a Node2D, reporting the parent to the transform watcher whenever it moves.
+///
+/// Godot only sends transform notifications to the node itself, which would need a script on
+/// every watched node to receive them. A child's global transform follows its parent's, so the
+/// notifier gets `NOTIFICATION_TRANSFORM_CHANGED` whenever the parent moves instead. It is added
+/// as an internal child, so `get_children()` and `get_child_count()` don't list it unless asked to
+/// include internal nodes, and it isn't mirrored to Bevy.
+///
+/// The notification also comes when an ancestor moves. With `local_only` the parent is only
+/// reported if its local transform changed, so moving a node doesn't re-read all its descendants
+/// when syncing in [`TransformSyncSpace::Local`](crate::plugins::transforms::TransformSyncSpace).
+#[derive(GodotClass)]
+#[class(base=Node2D)]
+pub struct TransformNotifier2D {
+    base: Base<Node2D>,
+    pub notification_channel: Option<Sender<InstanceId>>,
+    local_only: bool,
+    last_local_transform: Option<Transform2D>,
+}
+
+#[godot_api]
+impl INode2D for TransformNotifier2D {
+    fn init(base: Base<Node2D>) -> Self {
+        Self {
+            base,
+            notification_channel: None,
+            local_only: false,
+            last_local_transform: None,
+        }
+    }
+
+    fn ready(&mut self) {
+        self.base_mut().set_notify_transform(true);
+    }
+
+    fn on_notification(&mut self, what: CanvasItemNotification) {
+        if what != CanvasItemNotification::TRANSFORM_CHANGED {
+            return;
+        }
+        let Some(parent) = self.base().get_parent() else {
+            return;
+        };
+
+        if self.local_only
+            && let Ok(parent) = parent.clone().try_cast::<Node2D>()
+        {
+            let transform = parent.get_transform();
+            if self.last_local_transform.replace(transform) == Some(transform) {
+                return;
+            }
+        }
+        report_transform_changed(self.notification_channel.as_ref(), parent.instance_id());
+    }
+}
+
+impl TransformNotifier2D {
+    fn set_local_only(&mut self, local_only: bool) {
+        self.local_only = local_only;
+        self.last_local_transform = None;
+    }
+}
+
+/// Internal child of a Node3D, reporting the parent to the transform watcher whenever it moves,
+/// see [`TransformNotifier2D`]
+#[derive(GodotClass)]
+#[class(base=Node3D)]
+pub struct TransformNotifier3D {
+    base: Base<Node3D>,
+    pub notification_channel: Option<Sender<InstanceId>>,
+    local_only: bool,
+    last_local_transform: Option<Transform3D>,
+}
+
+#[godot_api]
+impl INode3D for TransformNotifier3D {
+    fn init(base: Base<Node3D>) -> Self {
+        Self {
+            base,
+            notification_channel: None,
+            local_only: false,
+            last_local_transform: None,
+        }
+    }
+
+    fn ready(&mut self) {
+        self.base_mut().set_notify_transform(true);
+    }
+
+    fn on_notification(&mut self, what: Node3DNotification) {
+        if what != Node3DNotification::TRANSFORM_CHANGED {
+            return;
+        }
+        let Some(parent) = self.base().get_parent() else {
+            return;
+        };
+
+        if self.local_only
+            && let Ok(parent) = parent.clone().try_cast::<Node3D>()
+        {
+            let transform = parent.get_transform();
+            if self.last_local_transform.replace(transform) == Some(transform) {
+                return;
+            }
+        }
+        report_transform_changed(self.notification_channel.as_ref(), parent.instance_id());
+    }
+}
+
+impl TransformNotifier3D {
+    fn set_local_only(&mut self, local_only: bool) {
+        self.local_only = local_only;
+        self.last_local_transform = None;
+    }
+}
+
+/// Adds an internal [`TransformNotifier2D`] (`is_2d`) or [`TransformNotifier3D`] under `node`
+/// when `enabled` and it has none yet, or frees it when not `enabled`. `local_only` notifiers
+/// ignore moves of the node's ancestors
+pub(crate) fn set_transform_notifier(
+    mut node: Gd<Node>,
+    is_2d: bool,
+    enabled: bool,
+    local_only: bool,
+    channel: &Sender<InstanceId>,
+) {
+    match (node.get_node_or_null(TRANSFORM_NOTIFIER_NAME), enabled) {
+        (None, true) => {
+            let mut notifier = if is_2d {
+                let mut notifier = TransformNotifier2D::new_alloc();
+                notifier.bind_mut().notification_channel = Some(channel.clone());
+                notifier.bind_mut().set_local_only(local_only);
+                notifier.upcast::<Node>()
+            } else {
+                let mut notifier = TransformNotifier3D::new_alloc();
+                notifier.bind_mut().notification_channel = Some(channel.clone());
+                notifier.bind_mut().set_local_only(local_only);
+                notifier.upcast::<Node>()
+            };
+            notifier.set_name(TRANSFORM_NOTIFIER_NAME);
+            node.add_child_ex(&notifier)
+                .internal(InternalMode::BACK)
+                .done();
+
+            // The node may have moved while nothing was watching it
+            report_transform_changed(Some(channel), node.instance_id());
+        }
+        (Some(notifier), true) => {
+            // The sync space may have changed
+            if let Ok(mut notifier) = notifier.clone().try_cast::<TransformNotifier2D>() {
+                notifier.bind_mut().set_local_only(local_only);
+            } else if let Ok(mut notifier) = notifier.try_cast::<TransformNotifier3D>() {
+                notifier.bind_mut().set_local_only(local_only);
+            }
+        }
+        (Some(mut notifier), false) => {
+            node.remove_child(&notifier);
+            notifier.queue_free();
+        }
+        (None, false) => {}
+    }
+}
+
+/// Whether `node` is one of the internal transform notifiers, which aren't mirrored to Bevy.
+/// `notifier_name` is [`TRANSFORM_NOTIFIER_NAME`], so other nodes are told apart by comparing
+/// names before trying any cast
+pub(crate) fn is_transform_notifier(node: &Gd<Node>, notifier_name: &StringName) -> bool {
+    node.get_name() == *notifier_name
+        && (node.clone().try_cast::<TransformNotifier2D>().is_ok()
+            || node.clone().try_cast::<TransformNotifier3D>().is_ok())
+}
+
+pub(crate) fn report_transform_changed(
+    channel: Option<&Sender<InstanceId>>,
+    instance_id: InstanceId,
+) {
+    if let Some(channel) = channel {
+        let _ = channel.send(instance_id);
+    }
+}
@@ -6,7 +6,7 @@ This script:
 1. Runs `godot --dump-extension-api` to generate extension_api.json
 2. Parses all Node-derived types from the API
 3. Generates comprehensive node marker components
-4. Generates complete type checking functions
+4. Generates the class name to marker and typed handle table used for type checking
 5. Updates the scene tree plugin to use generated code
 
 Usage: python scripts/generate_godot_types.py
@@ -14,11 +14,56 @@ Usage: python scripts/generate_godot_types.py
 
 import json
 import os
+import re
 import subprocess
 import sys
 from pathlib import Path
 from collections import defaultdict
 
+# Node classes without Rust bindings in a default godot-rust build: experimental classes need the
+# `experimental-godot-api` feature, and OpenXR classes are missing on some platforms. They still
+# get marker components, only no TypedNodeHandle.
+EXPERIMENTAL_CLASSES = {
+    'GraphEdit', 'GraphElement', 'GraphFrame', 'GraphNode',
+    'NavigationAgent2D', 'NavigationAgent3D', 'NavigationLink2D', 'NavigationLink3D',
+    'NavigationObstacle2D', 'NavigationObstacle3D', 'NavigationRegion2D', 'NavigationRegion3D',
+    'Parallax2D', 'XRBodyModifier3D', 'XRFaceModifier3D',
+}
+
+
+def has_rust_binding(class_name):
+    return class_name not in EXPERIMENTAL_CLASSES and not class_name.startswith("OpenXR")
+
+
+def rust_class_name(class_name):
+    """Rust struct name of a Godot class, following godot-rust's PascalCase conversion (heck)"""
+    words = []
+    init, mode = 0, None
+    for i, c in enumerate(class_name):
+        if i + 1 == len(class_name):
+            words.append(class_name[init:])
+            break
+
+        next_c = class_name[i + 1]
+        next_mode = "lower" if c.islower() else "upper" if c.isupper() else mode
+        if next_mode == "lower" and next_c.isupper():
+            # Boundary after a lowercase letter (or digit following one), e.g. `Node2|D`
+            words.append(class_name[init:i + 1])
+            init, mode = i + 1, None
+        elif mode == "upper" and c.isupper() and next_c.islower():
+            # Boundary before the last letter of an acronym, e.g. `HTTP|Request`
+            words.append(class_name[init:i])
+            init, mode = i, None
+        else:
+            mode = next_mode
+
+    name = "".join(word[:1].upper() + word[1:].lower() for word in words if word)
+    return (name.replace("GdExtension", "GDExtension")
+                .replace("GdNative", "GDNative")
+                .replace("GdScript", "GDScript")
+                .replace("Vsync", "VSync"))
+
+
 class GodotTypeGenerator:
     def __init__(self):
         self.project_root = Path(__file__).parent.parent
@@ -110,7 +155,7 @@ class GodotTypeGenerator:
         content = '''use bevy::ecs::component::Component;
 
 /// Marker components for Godot node types.
-/// These enable type-safe ECS queries like: Query<&GodotNodeHandle, With<Sprite2DMarker>>
+/// These enable type-safe ECS queries like: `Query<&GodotNodeHandle, With<Sprite2DMarker>>`
 ///
 /// 🤖 This file is automatically generated by scripts/generate_godot_types.py
 /// To regenerate: python scripts/generate_godot_types.py
@@ -131,238 +176,159 @@ pub struct NodeMarker;
 
         print(f"✅ Generated {len(node_types)} node markers")
 
-    def categorize_types_by_hierarchy(self, node_types, parent_map):
-        """Categorize node types by their inheritance hierarchy"""
-
-        def is_descendant_of(node_type, ancestor):
-            current = node_type
-            while current in parent_map:
-                current = parent_map[current]
-                if current == ancestor:
-                    return True
-            return False
-
-        categories = {
-            "3d": [],
-            "2d": [],
-            "control": [],
-            "universal": []
-        }
-
-        for node_type in node_types:
-            if is_descendant_of(node_type, "Node3D"):
-                categories["3d"].append(node_type)
-            elif is_descendant_of(node_type, "Node2D"):
-                categories["2d"].append(node_type)
-            elif is_descendant_of(node_type, "Control"):
-                categories["control"].append(node_type)
-            elif parent_map.get(node_type) == "Node":
-                categories["universal"].append(node_type)
-
-        return categories
-
     def generate_type_checking_code(self, node_types, parent_map):
-        """Generate the complete type checking implementation"""
+        """Generate the class-name-driven type checking implementation"""
         print("🔍 Generating type checking code...")
 
-        # Filter out invalid Godot classes first to avoid unnecessary work
-        valid_types = self.filter_valid_godot_classes(node_types)
+        def ancestor_chain(node_type):
+            # Base classes first, ending with the class itself
+            chain = [node_type]
+            while chain[-1] != "Node":
+                chain.append(parent_map[chain[-1]])
+            return list(reversed(chain))
+
+        classes = ["Node"] + node_types
 
-        # Categorize only the valid types
-        categories = self.categorize_types_by_hierarchy(valid_types, parent_map)
+        def marker_types(node_type):
+            # Excluded base classes (e.g. `EditorPlugin`) have no marker component
+            return [t for t in ancestor_chain(node_type) if t in classes]
 
         content = f'''// 🤖 This file is automatically generated by scripts/generate_godot_types.py
 // To regenerate: python scripts/generate_godot_types.py
 
-use bevy::ecs::system::EntityCommands;
-use crate::interop::{{GodotNodeHandle, node_markers::*}};
+use std::{{cell::RefCell, collections::HashMap}};
 
-/// Adds appropriate marker components to an entity based on the Godot node type.
-/// This function is automatically generated and handles all {len(valid_types)} Godot node types.
+use crate::interop::{{GodotNodeHandle, TypedNodeHandle, node_markers::*}};
+use bevy::ecs::{{
+    component::Component, error::ignore, system::EntityCommands, world::EntityWorldMut,
+}};
+use godot::{{
+    classes::{{self as gd, ClassDb, Node}},
+    obj::InstanceId,
+}};
+
+/// The engine class whose marker components and typed handles were added to this entity.
 ///
-/// Godot's hierarchy: Node -> {{Node3D, CanvasItem -> {{Node2D, Control}}, Others}}
-/// We check the major branches: 3D, 2D, Control (UI), and Universal (direct Node children)
-pub fn add_comprehensive_node_type_markers(
-    entity_commands: &mut EntityCommands,
-    node: &mut GodotNodeHandle,
-) {{
-    // All nodes inherit from Node, so add this first
-    entity_commands.insert(NodeMarker);
-
-    // Check the major hierarchy branches to minimize FFI calls
-    if node.try_get::<godot::classes::Node3D>().is_some() {{
-        entity_commands.insert(Node3DMarker);
-        check_3d_node_types_comprehensive(entity_commands, node);
-    }} else if node.try_get::<godot::classes::Node2D>().is_some() {{
-        entity_commands.insert(Node2DMarker);
-        entity_commands.insert(CanvasItemMarker); // Node2D inherits from CanvasItem
-        check_2d_node_types_comprehensive(entity_commands, node);
-    }} else if node.try_get::<godot::classes::Control>().is_some() {{
-        entity_commands.insert(ControlMarker);
-        entity_commands.insert(CanvasItemMarker); // Control inherits from CanvasItem
-        check_control_node_types_comprehensive(entity_commands, node);
-    }}
+/// Used to remove exactly those components again when the node leaves the scene tree.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeTypeMarkers {{
+    pub class: &'static str,
+}}
 
-    // Check node types that inherit directly from Node
-    check_universal_node_types_comprehensive(entity_commands, node);
+thread_local! {{
+    /// Nearest engine base class of classes missing from the generated table (GDExtension
+    /// classes, engine classes newer than the table), looked up through ClassDB once per class
+    static RESOLVED_CLASSES: RefCell<HashMap<String, &'static str>> = RefCell::new(HashMap::new());
 }}
 
-pub fn remove_comprehensive_node_type_markers(
+/// Adds marker components and [`TypedNodeHandle`]s to an entity based on the Godot node type.
+///
+/// The node's class is read with a single `get_class()` call and the markers and typed handles
+/// for the class and all of its base classes are inserted as one bundle. The inheritance chains of the
+/// {len(node_types)} Godot node types come from a table generated from Godot's extension API;
+/// any other class (e.g. a GDExtension class) gets the markers of its nearest engine base class.
+pub fn add_comprehensive_node_type_markers(
     entity_commands: &mut EntityCommands,
     node: &mut GodotNodeHandle,
 ) {{
-    // All nodes inherit from Node, so remove this first
-    entity_commands.remove::<NodeMarker>();
-
-    entity_commands.remove::<Node3DMarker>();
-    remove_3d_node_types_comprehensive(entity_commands, node);
-
-    entity_commands.remove::<Node2DMarker>();
-    entity_commands.remove::<CanvasItemMarker>(); // Node2D inherits from CanvasItem
-    remove_2d_node_types_comprehensive(entity_commands, node);
-
-    entity_commands.remove::<ControlMarker>();
-    remove_control_node_types_comprehensive(entity_commands, node);
-
-    remove_universal_node_types_comprehensive(entity_commands, node);
+    let class = node.get::<Node>().get_class().to_string();
+    insert_node_type_markers(
+        entity_commands,
+        resolve_node_class(&class),
+        node.instance_id(),
+    );
 }}
 
-'''
-
-        # Generate specific checking functions
-        content += self._generate_hierarchy_function_comprehensive("3d", categories["3d"])
-        content += self._generate_hierarchy_function_comprehensive("2d", categories["2d"])
-        content += self._generate_hierarchy_function_comprehensive("control", categories["control"])
-        content += self._generate_universal_function_comprehensive(categories["universal"])
-
-        with open(self.type_checking_file, "w") as f:
-            f.write(content)
-
-        print(f"✅ Generated type checking for {len(valid_types)} types")
-
-    def filter_valid_godot_classes(self, node_types):
-        """Filter out Godot classes that don't exist or aren't available"""
-        # Known classes that don't exist in current Godot version or aren't available
-        excluded_classes = {
-            # CSG classes (require special module)
-            'CSGBox3D', 'CSGCombiner3D', 'CSGCylinder3D', 'CSGMesh3D', 'CSGPolygon3D',
-            'CSGPrimitive3D', 'CSGShape3D', 'CSGSphere3D', 'CSGTorus3D',
-            # Editor classes
-            'GridMapEditorPlugin', 'ScriptCreateDialog', 'FileSystemDock',
-            'OpenXRBindingModifierEditor', 'OpenXRInteractionProfileEditor',
-            'OpenXRInteractionProfileEditorBase',
-            # XR classes that might not be available
-            'XRAnchor3D', 'XRBodyModifier3D', 'XRCamera3D', 'XRController3D',
-            'XRFaceModifier3D', 'XRHandModifier3D', 'XRNode3D', 'XROrigin3D',
-            # OpenXR classes
-            'OpenXRCompositionLayer', 'OpenXRCompositionLayerCylinder',
-            'OpenXRCompositionLayerEquirect', 'OpenXRCompositionLayerQuad',
-            'OpenXRHand', 'OpenXRVisibilityMask',
-            # Classes that might not be available in all builds
-            'VoxelGI', 'LightmapGI', 'FogVolume', 'WorldEnvironment',
-            # Navigation classes (might be module-specific)
-            'NavigationAgent2D', 'NavigationAgent3D', 'NavigationLink2D',
-            'NavigationLink3D', 'NavigationObstacle2D', 'NavigationObstacle3D',
-            'NavigationRegion2D', 'NavigationRegion3D',
-            # Other problematic classes
-            'StatusIndicator',
-            # Graph classes (not available in all Godot builds)
-            'GraphEdit', 'GraphElement', 'GraphFrame', 'GraphNode',
-            # Parallax2D is in extension API but not in current Rust bindings
-            'Parallax2D',
-        }
-
-        return [t for t in node_types if t not in excluded_classes]
-
-    def fix_godot_class_name_for_rust(self, class_name):
-        """Fix Godot class names to match the actual Rust bindings"""
-        # Map class names from extension API to actual Rust struct names
-        name_fixes = {
-            'CPUParticles2D': 'CpuParticles2D',
-            'CPUParticles3D': 'CpuParticles3D',
-            'GPUParticles2D': 'GpuParticles2D',
-            'GPUParticles3D': 'GpuParticles3D',
-            'GPUParticlesAttractor3D': 'GpuParticlesAttractor3D',
-            'GPUParticlesAttractorBox3D': 'GpuParticlesAttractorBox3D',
-            'GPUParticlesAttractorSphere3D': 'GpuParticlesAttractorSphere3D',
-            'GPUParticlesAttractorVectorField3D': 'GpuParticlesAttractorVectorField3D',
-            'GPUParticlesCollision3D': 'GpuParticlesCollision3D',
-            'GPUParticlesCollisionBox3D': 'GpuParticlesCollisionBox3D',
-            'GPUParticlesCollisionHeightField3D': 'GpuParticlesCollisionHeightField3D',
-            'GPUParticlesCollisionSDF3D': 'GpuParticlesCollisionSdf3d',
-            'GPUParticlesCollisionSphere3D': 'GpuParticlesCollisionSphere3D',
-            'HTTPRequest': 'HttpRequest',
-            'SkeletonIK3D': 'SkeletonIk3d',
-            'Generic6DOFJoint3D': 'Generic6DofJoint3D',
-        }
-
-        return name_fixes.get(class_name, class_name)
-
-    def _generate_hierarchy_function_comprehensive(self, name, types):
-        """Generate a hierarchy-specific type checking function"""
-        content = f'''fn check_{name}_node_types_comprehensive(
-    entity_commands: &mut EntityCommands,
-    node: &mut GodotNodeHandle,
-) {{
-'''
+/// Removes the marker components and typed handles added by [`add_comprehensive_node_type_markers`].
+pub fn remove_comprehensive_node_type_markers(entity_commands: &mut EntityCommands) {{
+    entity_commands.queue_handled(
+        |mut entity: EntityWorldMut| {{
+            if let Some(markers) = entity.take::<NodeTypeMarkers>() {{
+                remove_node_type_markers(&mut entity, markers.class);
+            }}
+        }},
+        ignore,
+    );
+}}
 
-        for node_type in sorted(types):
-            rust_class_name = self.fix_godot_class_name_for_rust(node_type)
-            content += f'''    if node.try_get::<godot::classes::{rust_class_name}>().is_some() {{
-        entity_commands.insert({node_type}Marker);
+/// Maps a Godot class name to the engine class whose markers it gets
+fn resolve_node_class(class: &str) -> &'static str {{
+    if let Some(class) = node_class(class) {{
+        return class;
     }}
-'''
 
-        content += "}\n\n"
-
-        content += f'''fn remove_{name}_node_types_comprehensive(
-    entity_commands: &mut EntityCommands,
-    node: &mut GodotNodeHandle,
-) {{
-    entity_commands
+    RESOLVED_CLASSES.with_borrow_mut(|resolved| {{
+        *resolved.entry(class.to_owned()).or_insert_with(|| {{
+            let class_db = ClassDb::singleton();
+            let mut parent = class_db.get_parent_class(class);
+            while parent.len() > 0 {{
+                if let Some(class) = node_class(&parent.to_string()) {{
+                    return class;
+                }}
+                parent = class_db.get_parent_class(&parent);
+            }}
+            "Node"
+        }})
+    }})
+}}
 
+fn node_class(class: &str) -> Option<&'static str> {{
+    let class = match class {{
 '''
 
-        for node_type in sorted(types):
-            rust_class_name = self.fix_godot_class_name_for_rust(node_type)
-            content += f'''        .remove::<{node_type}Marker>()
-'''
+        for node_type in classes:
+            content += f'        "{node_type}" => "{node_type}",\n'
 
-        content += ";}\n\n"
-        return content
+        content += '''        _ => return None,
+    };
+    Some(class)
+}
 
-    def _generate_universal_function_comprehensive(self, types):
-        """Generate the universal types checking function"""
-        content = '''fn check_universal_node_types_comprehensive(
+fn insert_node_type_markers(
     entity_commands: &mut EntityCommands,
-    node: &mut GodotNodeHandle,
+    class: &'static str,
+    instance_id: InstanceId,
 ) {
+    let markers = NodeTypeMarkers { class };
+    match class {
 '''
 
-        for node_type in sorted(types):
-            rust_class_name = self.fix_godot_class_name_for_rust(node_type)
-            content += f'''    if node.try_get::<godot::classes::{rust_class_name}>().is_some() {{
-        entity_commands.insert({node_type}Marker);
-    }}
-'''
+        def typed_handles(node_type):
+            return [f"gd::{rust_class_name(t)}" for t in ancestor_chain(node_type) if has_rust_binding(t)]
 
-        content += "}\n"
+        for node_type in node_types:
+            markers = ", ".join(f"{t}Marker" for t in marker_types(node_type))
+            handles = ", ".join(
+                f"TypedNodeHandle::<{t}>::new_unchecked(instance_id)" for t in typed_handles(node_type)
+            )
+            content += f'        "{node_type}" => entity_commands.insert((markers, {markers}, ({handles},))),\n'
+
+        content += '''        _ => entity_commands.insert((
+            markers,
+            NodeMarker,
+            TypedNodeHandle::<gd::Node>::new_unchecked(instance_id),
+        )),
+    };
+}
+
+fn remove_node_type_markers(entity: &mut EntityWorldMut, class: &str) {
+    match class {
+'''
 
-        content += '''fn remove_universal_node_types_comprehensive(
-    entity_commands: &mut EntityCommands,
-    node: &mut GodotNodeHandle,
-) {
-    entity_commands
+        for node_type in node_types:
+            markers = ", ".join(f"{t}Marker" for t in marker_types(node_type))
+            handles = ", ".join(f"TypedNodeHandle<{t}>" for t in typed_handles(node_type))
+            content += f'        "{node_type}" => entity.remove::<({markers}, ({handles},))>(),\n'
 
+        content += '''        _ => entity.remove::<(NodeMarker, TypedNodeHandle<gd::Node>)>(),
+    };
+}
 '''
 
-        for node_type in sorted(types):
-            rust_class_name = self.fix_godot_class_name_for_rust(node_type)
-            content += f'''        .remove::<{node_type}Marker>()
-'''
+        with open(self.type_checking_file, "w") as f:
+            f.write(content)
 
-        content += ";}\n"
-        return content
+        print(f"✅ Generated type checking for {len(node_types)} types")
 
     def verify_plugin_integration(self):
         """Verify that the plugin is set up to use the generated code"""
@@ -404,7 +370,7 @@ pub fn remove_comprehensive_node_type_markers(
 
 Generated:
   • {len(node_types)} node marker components
-  • Complete type checking functions
+  • Class name to marker table
 
 Files generated:
   • {self.node_markers_file.relative_to(self.project_root)}